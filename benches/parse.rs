@@ -0,0 +1,169 @@
+//! Benchmarks parsing a corpus of a few thousand distinct words, with an emphasis on formatives
+//! carrying slot V and slot VII affixes.
+//!
+//! No attested corpus ships with this crate, so the bulk of the corpus is generated: formatives
+//! are built by varying their root, stem, specification, case, Ca, and affixes, romanized, and
+//! deduplicated, so that each word exercises its own combination of code paths instead of the
+//! same handful of words being parsed over and over.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::collections::BTreeSet;
+use tnil::{
+    affix::{AffixList, PlainAffix, RegularAffix},
+    ca,
+    category::{AffixDegree, AffixType, Case, Specification, Stem},
+    prelude::*,
+    word::{formative::root::ShortcutCheckedFormativeRoot, CheckedFormative, UncheckedFormative},
+};
+
+/// A hand-picked set of formatives, referentials, and adjuncts, included in the generated corpus.
+const CORPUS: &[&str] = &[
+    "hliosulţe",
+    "ašflaleče",
+    "aesmlal",
+    "holřäksa",
+    "açbala",
+    "ırburučpaızya",
+    "second",
+    "changed",
+    "alasa",
+    "nomic",
+    "moved",
+    "slot",
+    "psalaekpa",
+    "psakpaevv",
+    "oëtil",
+    "watteteihnáu",
+    "rrata",
+    "lo",
+    "lawe",
+    "ahňaxeltüa",
+    "luxar",
+    "er",
+    "eru",
+];
+
+/// Generates a corpus of a few thousand distinct, valid words.
+fn generate_corpus() -> Vec<String> {
+    let affix = |cs: &str, degree| RegularAffix::Plain(PlainAffix::new(cs, AffixType::T1, degree));
+
+    let roots = ["l", "rr", "kš", "pšt", "ţr", "mm"];
+    let cases = [0, 17, 44, 67].map(|index| Case::ALL_ITEMS[index]);
+    let cas = [ca!(), ca!(PRX), ca!(MSS, G), ca!(COA, DPX, RPV)];
+
+    // Pairs of slot V and slot VII affixes.
+    let affix_lists = [
+        (vec![], vec![]),
+        (vec![], vec![affix("c", AffixDegree::D1)]),
+        (
+            vec![affix("c", AffixDegree::D1)],
+            vec![affix("c", AffixDegree::D1), affix("lk", AffixDegree::D7)],
+        ),
+    ];
+
+    let mut words: BTreeSet<String> = CORPUS.iter().map(|word| word.to_string()).collect();
+
+    for root in roots {
+        for stem in Stem::ALL_ITEMS {
+            for specification in Specification::ALL_ITEMS {
+                for vc in cases {
+                    for ca in cas {
+                        for (slot_v_affixes, slot_vii_affixes) in &affix_lists {
+                            let formative = UncheckedFormative {
+                                root: ShortcutCheckedFormativeRoot::new_normal(root),
+                                stem,
+                                specification,
+                                vc,
+                                ca,
+                                slot_v_affixes: AffixList::Normal(slot_v_affixes.clone()),
+                                slot_vii_affixes: AffixList::Normal(slot_vii_affixes.clone()),
+                                ..Default::default()
+                            };
+
+                            let Some(checked): Option<CheckedFormative> =
+                                formative.try_as_specific()
+                            else {
+                                continue;
+                            };
+
+                            words.insert(checked.to_romanized());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    words
+        .into_iter()
+        .filter(|word| Word::parse_str(word, FromTokenFlags::NONE).is_ok())
+        .collect()
+}
+
+fn parse_corpus(c: &mut Criterion) {
+    let corpus = generate_corpus();
+    assert!(
+        corpus.len() >= 2000,
+        "only generated {} words",
+        corpus.len()
+    );
+
+    c.bench_function("parse corpus", |b| {
+        b.iter(|| {
+            for word in &corpus {
+                let _ = black_box(Word::parse_str(black_box(word), FromTokenFlags::NONE));
+            }
+        })
+    });
+
+    let lists: Vec<TokenList> = corpus.iter().map(|word| word.parse().unwrap()).collect();
+
+    c.bench_function("parse corpus as formatives from tokens", |b| {
+        b.iter(|| {
+            for list in &lists {
+                let _ = black_box(word::ShortcutCheckedFormative::parse(
+                    &mut list.stream(),
+                    FromTokenFlags::NONE,
+                ));
+            }
+        })
+    });
+}
+
+fn consonant_form_lookup(c: &mut Criterion) {
+    use std::collections::HashSet;
+    use tnil::romanize::token::{ConsonantForm, OwnedConsonantForm, Token};
+
+    let forms: Vec<String> = CORPUS
+        .iter()
+        .flat_map(|word| word.parse::<TokenList>().unwrap().tokens)
+        .filter_map(|token| match token {
+            Token::C(form) => Some(form.0),
+            _ => None,
+        })
+        .collect();
+
+    let set: HashSet<OwnedConsonantForm> = forms
+        .iter()
+        .map(|form| OwnedConsonantForm(form.clone()))
+        .collect();
+
+    c.bench_function("look up consonant forms by borrowed key", |b| {
+        b.iter(|| {
+            for form in &forms {
+                black_box(set.contains(ConsonantForm::new(black_box(form))));
+            }
+        })
+    });
+
+    c.bench_function("look up consonant forms by owned key", |b| {
+        b.iter(|| {
+            for form in &forms {
+                black_box(set.contains(&OwnedConsonantForm(black_box(form).clone())));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, parse_corpus, consonant_form_lookup);
+criterion_main!(benches);
@@ -1,12 +1,25 @@
 use super::{AppositiveReferentialAffix, RegularAffix};
 use crate::{
-    category::{VowelFormDegree, VowelFormSequence},
+    category::{AffixualReferentList, AppositiveCase, VowelFormDegree, VowelFormSequence},
     gloss::{Gloss, GlossFlags},
     romanize::{
         stream::ParseError,
-        token::{OwnedConsonantForm, VowelForm},
+        token::{ConsonantForm, VowelForm},
     },
 };
+use std::{error::Error, fmt, ops::Deref};
+
+/// An error returned when affixes are added to an [`AffixList`] which cannot hold them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AffixListError;
+
+impl fmt::Display for AffixListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an appositive referential affix cannot be followed by other affixes")
+    }
+}
+
+impl Error for AffixListError {}
 
 /// A list of affixes in a formative slot.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -19,10 +32,31 @@ pub enum AffixList {
 }
 
 impl AffixList {
+    /// Creates an [`AffixList`] holding a single appositive referential affix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tnil::affix::AffixList;
+    /// # use tnil::category::AppositiveCase;
+    /// # use tnil::word::UncheckedFormative;
+    /// let formative: UncheckedFormative = "maliólk".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     formative.slot_vii_affixes,
+    ///     AffixList::appositive_referential("lk".parse().unwrap(), AppositiveCase::GEN),
+    /// );
+    /// ```
+    pub fn appositive_referential(referents: AffixualReferentList, case: AppositiveCase) -> Self {
+        Self::AppositiveReferential(AppositiveReferentialAffix::new(referents, case))
+    }
+
     /// Creates an [`AffixList`] from a slice of VxCs pairs. If the slice contains a single affix
     /// and the affix is a type-3 affix, it will be interpreted as an appositive referential.
     /// Otherwise, the affix list will be interpreted normally.
-    pub fn from_vxcs_slice(forms: &[(VowelForm, OwnedConsonantForm)]) -> Result<Self, ParseError> {
+    pub fn from_vxcs_slice<T: Deref<Target = ConsonantForm>>(
+        forms: &[(VowelForm, T)],
+    ) -> Result<Self, ParseError> {
         'a: {
             if forms.len() == 1 {
                 let first = &forms[0];
@@ -49,15 +83,15 @@ impl AffixList {
                         AppositiveReferentialAffix {
                             case: match first.0.degree {
                                 VowelFormDegree::D0 => break 'a,
-                                VowelFormDegree::D1 => crate::category::AppositiveCase::POS,
-                                VowelFormDegree::D2 => crate::category::AppositiveCase::PRP,
-                                VowelFormDegree::D3 => crate::category::AppositiveCase::GEN,
-                                VowelFormDegree::D4 => crate::category::AppositiveCase::ATT,
-                                VowelFormDegree::D5 => crate::category::AppositiveCase::PDC,
-                                VowelFormDegree::D6 => crate::category::AppositiveCase::ITP,
-                                VowelFormDegree::D7 => crate::category::AppositiveCase::OGN,
-                                VowelFormDegree::D8 => crate::category::AppositiveCase::IDP,
-                                VowelFormDegree::D9 => crate::category::AppositiveCase::PAR,
+                                VowelFormDegree::D1 => AppositiveCase::POS,
+                                VowelFormDegree::D2 => AppositiveCase::PRP,
+                                VowelFormDegree::D3 => AppositiveCase::GEN,
+                                VowelFormDegree::D4 => AppositiveCase::ATT,
+                                VowelFormDegree::D5 => AppositiveCase::PDC,
+                                VowelFormDegree::D6 => AppositiveCase::ITP,
+                                VowelFormDegree::D7 => AppositiveCase::OGN,
+                                VowelFormDegree::D8 => AppositiveCase::IDP,
+                                VowelFormDegree::D9 => AppositiveCase::PAR,
                             },
                             referents: first.1.parse()?,
                         },
@@ -66,7 +100,7 @@ impl AffixList {
             }
         }
 
-        let mut items = Vec::new();
+        let mut items = Vec::with_capacity(forms.len());
 
         for (vx, cs) in forms {
             items.push(RegularAffix::from_vxcs(*vx, &**cs)?);
@@ -82,6 +116,45 @@ impl AffixList {
             Self::AppositiveReferential(_) => 1,
         }
     }
+
+    /// Checks if this list contains no affixes.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Normal(items) => items.is_empty(),
+            Self::AppositiveReferential(_) => false,
+        }
+    }
+
+    /// Appends an affix to this list, returning an [`AffixListError`] if this list is an
+    /// appositive referential, which must be the only affix in its slot.
+    pub fn push(&mut self, affix: RegularAffix) -> Result<(), AffixListError> {
+        match self {
+            Self::Normal(items) => {
+                items.push(affix);
+                Ok(())
+            }
+            Self::AppositiveReferential(_) => Err(AffixListError),
+        }
+    }
+
+    /// Appends several affixes to this list, returning an [`AffixListError`] if this list is an
+    /// appositive referential and `affixes` is not empty. Extending an appositive referential with
+    /// no affixes succeeds and leaves it unchanged.
+    pub fn extend(
+        &mut self,
+        affixes: impl IntoIterator<Item = RegularAffix>,
+    ) -> Result<(), AffixListError> {
+        match self {
+            Self::Normal(items) => {
+                items.extend(affixes);
+                Ok(())
+            }
+            Self::AppositiveReferential(_) => match affixes.into_iter().next() {
+                Some(_) => Err(AffixListError),
+                None => Ok(()),
+            },
+        }
+    }
 }
 
 impl Default for AffixList {
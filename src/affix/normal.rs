@@ -6,12 +6,15 @@ use super::{
 };
 use crate::{
     category::{
-        AffixDegree, AffixType, Ca, Case, CaseAccessorMode, ThematicCase, VowelFormDegree,
-        VowelFormSequence,
+        AffixDegree, AffixType, AffixualReferentList, Ca, Case, CaseAccessorMode, ThematicCase,
+        VowelFormDegree, VowelFormSequence,
     },
     gloss::{Gloss, GlossFlags},
-    prelude::token::Token,
-    romanize::{stream::ParseError, token::VowelForm, traits::IntoVxCs},
+    romanize::{
+        stream::ParseError,
+        token::{NumeralForm, OwnedConsonantForm, Token, VowelForm},
+        traits::IntoVxCs,
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -37,7 +40,77 @@ pub enum RegularAffix {
 }
 
 impl RegularAffix {
-    /// Parses a [`RegularAffix`] from a VxCs pair.
+    /// Constructs a case-stacking affix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tnil::affix::{AffixList, RegularAffix};
+    /// # use tnil::category::Case;
+    /// # use tnil::word::UncheckedFormative;
+    /// let formative: UncheckedFormative = "malolwa".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     formative.slot_vii_affixes,
+    ///     AffixList::Normal(vec![RegularAffix::case_stacking(Case::ERG)]),
+    /// );
+    /// ```
+    pub fn case_stacking(case: Case) -> Self {
+        Self::CaseStacking(CaseStackingAffix::new(case))
+    }
+
+    /// Constructs a case-accessor affix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tnil::affix::{AffixList, RegularAffix};
+    /// # use tnil::category::{AffixType, Case, CaseAccessorMode};
+    /// # use tnil::word::UncheckedFormative;
+    /// let formative: UncheckedFormative = "malošwa".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     formative.slot_vii_affixes,
+    ///     AffixList::Normal(vec![RegularAffix::case_accessor(
+    ///         Case::ERG,
+    ///         AffixType::T1,
+    ///         CaseAccessorMode::Inverse,
+    ///     )]),
+    /// );
+    /// ```
+    pub fn case_accessor(case: Case, r#type: AffixType, mode: CaseAccessorMode) -> Self {
+        Self::CaseAccessor(CaseAccessorAffix::new(case, mode, r#type))
+    }
+
+    /// Constructs a Ca-stacking affix.
+    pub fn ca_stacking(ca: Ca) -> Self {
+        Self::Ca(CaStackingAffix::new(ca))
+    }
+
+    /// Constructs a thematic referential affix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tnil::affix::{AffixList, RegularAffix};
+    /// # use tnil::category::ThematicCase;
+    /// # use tnil::word::UncheckedFormative;
+    /// let formative: UncheckedFormative = "maloélk".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     formative.slot_vii_affixes,
+    ///     AffixList::Normal(vec![RegularAffix::referential(
+    ///         "lk".parse().unwrap(),
+    ///         ThematicCase::ERG,
+    ///     )]),
+    /// );
+    /// ```
+    pub fn referential(referents: AffixualReferentList, case: ThematicCase) -> Self {
+        Self::Referential(ThematicReferentialAffix::new(referents, case))
+    }
+
+    /// Parses a [`RegularAffix`] from a VxCs pair. A Cs form made only of digits is parsed as a
+    /// numeric affix.
     pub fn from_vxcs(vx: VowelForm, cs: &str) -> Result<Self, ParseError> {
         if matches!(
             vx,
@@ -47,18 +120,16 @@ impl RegularAffix {
                 degree: VowelFormDegree::D0
             }
         ) {
-            return Ok(RegularAffix::Ca(CaStackingAffix {
-                ca: Ca::from_ungeminated_string(cs).ok_or(ParseError::ExpectedCa)?,
-            }));
+            return Ok(RegularAffix::ca_stacking(
+                Ca::from_ungeminated_string(cs).ok_or(ParseError::ExpectedCa)?,
+            ));
         }
 
         match cs {
             "lw" | "ly" => {
                 let mut vx = vx;
                 vx.has_glottal_stop = cs == "ly";
-                return Ok(RegularAffix::CaseStacking(CaseStackingAffix {
-                    case: Case::from_vc(vx)?,
-                }));
+                return Ok(RegularAffix::case_stacking(Case::from_vc(vx)?));
             }
 
             "sw" | "zw" | "čw" | "šw" | "žw" | "jw" | "sy" | "zy" | "čy" | "šy" | "žy" | "jy" =>
@@ -79,11 +150,7 @@ impl RegularAffix {
                     AffixType::T3
                 };
 
-                return Ok(RegularAffix::CaseAccessor(CaseAccessorAffix {
-                    case,
-                    mode,
-                    r#type,
-                }));
+                return Ok(RegularAffix::case_accessor(case, r#type, mode));
             }
 
             _ => {}
@@ -130,12 +197,46 @@ impl RegularAffix {
             VowelFormDegree::D9 => AffixDegree::D9,
         };
 
+        if !cs.is_empty() && cs.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Ok(RegularAffix::Numeric(NumericAffix {
+                form: NumeralForm {
+                    integer_part: cs.parse().map_err(|_| ParseError::SourceNumeralInvalid)?,
+                },
+                r#type,
+                degree,
+            }));
+        }
+
         Ok(RegularAffix::Plain(PlainAffix {
             cs: cs.to_owned(),
             r#type,
             degree,
         }))
     }
+
+    /// Converts this affix into a VxCs pair. This is the inverse of [`RegularAffix::from_vxcs`].
+    ///
+    /// Numeric affixes have their numeral written in the Cs form. Case-stacking and case-accessor
+    /// affixes show whether their case has a glottal stop through their Cs form, so their Vx form
+    /// never has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tnil::affix::RegularAffix;
+    /// # use tnil::category::Case;
+    /// let affix = RegularAffix::case_stacking(Case::PRN);
+    /// let (vx, cs) = affix.to_vxcs();
+    /// assert_eq!(cs.0, "ly");
+    /// assert_eq!(RegularAffix::from_vxcs(vx, &cs), Ok(affix));
+    /// ```
+    pub fn to_vxcs(&self) -> (VowelForm, OwnedConsonantForm) {
+        match self.into_vx_cs() {
+            (vx, Token::C(cs)) => (vx, cs),
+            (vx, Token::N(form)) => (vx, OwnedConsonantForm(form.integer_part.to_string())),
+            _ => unreachable!("affixes are only written with consonant forms and numerals"),
+        }
+    }
 }
 
 impl Gloss for RegularAffix {
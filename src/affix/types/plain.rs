@@ -1,5 +1,6 @@
 use crate::{
     category::{AffixDegree, AffixType},
+    gloss::escape_if_requested,
     prelude::{
         token::{OwnedConsonantForm, Token, VowelForm},
         Gloss, GlossFlags, GlossStatic,
@@ -34,20 +35,28 @@ impl PlainAffix {
 impl Gloss for PlainAffix {
     // TODO: Use affix data
     fn gloss(&self, flags: GlossFlags) -> String {
-        if flags.matches(GlossFlags::FORMAT_MARKDOWN) {
+        let mut output = if flags.matches(GlossFlags::FORMAT_MARKDOWN) {
             let mut output = "**".to_owned();
-            output += &self.cs; // We assume the Cs form doesn't need escaping.
+            // We assume the Cs form doesn't need Markdown escaping.
+            output += &escape_if_requested(&self.cs, flags);
             output += "**/";
-            output += self.degree.gloss_static(GlossFlags::NONE);
-            output += self.r#type.gloss_static(GlossFlags::NONE);
             output
         } else {
-            let mut output = self.cs.to_owned();
+            let mut output = escape_if_requested(&self.cs, flags).into_owned();
             output += "/";
-            output += self.degree.gloss_static(GlossFlags::NONE);
-            output += self.r#type.gloss_static(GlossFlags::NONE);
-            return output;
+            output
+        };
+
+        output += self.degree.gloss_static(GlossFlags::NONE);
+        output += self.r#type.gloss_static(GlossFlags::NONE);
+
+        if flags.matches(GlossFlags::SHOW_AFFIX_CS) {
+            output += "[";
+            output += &escape_if_requested(&self.cs, flags);
+            output += "]";
         }
+
+        output
     }
 }
 
@@ -0,0 +1,72 @@
+//! Provides a single entry point which parses a word and reports everything this crate knows
+//! about it.
+
+use crate::{
+    prelude::{word::NormalReferential, *},
+    script::buf::CharacterBuf,
+};
+
+/// A breakdown of a single word, as returned by [`analyze`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Analysis {
+    /// The parsed word.
+    pub word: Word,
+
+    /// The gloss of the word.
+    pub gloss: String,
+
+    /// The word, converted back into its normalized romanization.
+    pub normalized_romanization: String,
+
+    /// The word in script, or [`None`] if script conversion is not yet supported for its kind.
+    ///
+    /// Formatives, normal referentials, and numeric adjuncts can currently be converted.
+    pub script: Option<CharacterBuf>,
+}
+
+impl Analysis {
+    /// Gets the kind of the analyzed word.
+    pub fn kind(&self) -> WordKind {
+        self.word.kind()
+    }
+}
+
+/// Converts a word into script, returning [`None`] if its kind has no script conversion yet.
+fn word_to_script(word: &Word) -> Option<CharacterBuf> {
+    match word {
+        Word::Formative(value) => Some(value.into_script(IntoScriptFlags::NONE)),
+        Word::Referential(value) => {
+            let value: NormalReferential = value.clone().try_as_specific()?;
+            Some(value.into_script(IntoScriptFlags::NONE))
+        }
+        Word::Numeric(value) => Some(value.form.into_script(IntoScriptFlags::NONE)),
+        _ => None,
+    }
+}
+
+/// Parses `source` as a single word and returns its gloss, normalized romanization, and script.
+///
+/// `flags` are used when glossing the word. The word is parsed, romanized, and converted into
+/// script using default flags.
+///
+/// # Examples
+///
+/// ```
+/// # use tnil::{analyze, prelude::*};
+/// let analysis = analyze("malá", GlossFlags::NONE).unwrap();
+///
+/// assert_eq!(analysis.kind(), WordKind::Formative);
+/// assert_eq!(analysis.gloss, "S1-m-OBS");
+/// assert_eq!(analysis.normalized_romanization, "mal");
+/// assert!(analysis.script.is_some());
+/// ```
+pub fn analyze(source: &str, flags: GlossFlags) -> Result<Analysis, ParseError> {
+    let word: Word = source.parse()?;
+
+    Ok(Analysis {
+        gloss: word.gloss(flags),
+        normalized_romanization: word.to_string_with(IntoTokensFlags::NONE),
+        script: word_to_script(&word),
+        word,
+    })
+}
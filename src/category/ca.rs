@@ -94,25 +94,71 @@ fn ca_to_string(ca: Ca, geminate_fn: impl Fn(String) -> String) -> String {
 }
 
 impl Ca {
-    /// Applies allomorphs to an ungeminated Ca string.
+    /// The allomorphic substitutions applied anywhere in an ungeminated Ca form, in the order they
+    /// are applied. Each pair is written as `(original, substitution)`.
+    pub const ALLOMORPHS: [(&'static str, &'static str); 12] = [
+        ("pp", "mp"),
+        ("tt", "nt"),
+        ("kk", "nk"),
+        ("ll", "pļ"),
+        ("pb", "mb"),
+        ("kg", "ng"),
+        ("çy", "nd"),
+        ("rr", "ns"),
+        ("rř", "nš"),
+        ("řr", "ňs"),
+        ("řř", "ňš"),
+        ("ngn", "ňn"),
+    ];
+
+    /// The allomorphic substitutions applied after [`Ca::ALLOMORPHS`], but only to the portion of
+    /// an ungeminated Ca form following its first letter.
+    pub const NON_INITIAL_ALLOMORPHS: [(&'static str, &'static str); 5] = [
+        ("gm", "x"),
+        ("gn", "ň"),
+        ("çx", "xw"),
+        ("bm", "v"),
+        ("bn", "ḑ"),
+    ];
+
+    /// The allomorphic substitutions applied last, after [`Ca::NON_INITIAL_ALLOMORPHS`].
+    pub const FINAL_ALLOMORPHS: [(&'static str, &'static str); 2] = [("fv", "vw"), ("ţḑ", "ḑy")];
+
+    /// The substitutions used to geminate Ca forms which cannot be geminated by doubling one of
+    /// their consonants. Only the first matching substitution is applied. Each pair is written as
+    /// `(ungeminated, geminated)`.
+    pub const GEMINATE_SUBSTITUTIONS: [(&'static str, &'static str); 18] = [
+        ("pt", "bbḑ"),
+        ("pk", "bbv"),
+        ("kt", "ggḑ"),
+        ("kp", "ggv"),
+        ("tk", "ḑvv"),
+        ("tp", "ddv"),
+        ("pm", "vvm"),
+        ("pn", "vvn"),
+        ("km", "xxm"),
+        ("kn", "xxn"),
+        ("tm", "ḑḑm"),
+        ("tn", "ḑḑn"),
+        ("bm", "mmw"),
+        ("bn", "mml"),
+        ("gm", "ňňw"),
+        ("gn", "ňňl"),
+        ("dm", "nnw"),
+        ("dn", "nnl"),
+    ];
+
+    /// Applies allomorphs to an ungeminated Ca string, using the substitutions in
+    /// [`Ca::ALLOMORPHS`], [`Ca::NON_INITIAL_ALLOMORPHS`], and [`Ca::FINAL_ALLOMORPHS`].
     pub fn allomorph(ca: &str) -> String {
         if ca.len() <= 1 {
             return ca.to_owned();
         }
 
-        let ca = ca
-            .replace("pp", "mp")
-            .replace("tt", "nt")
-            .replace("kk", "nk")
-            .replace("ll", "pļ")
-            .replace("pb", "mb")
-            .replace("kg", "ng")
-            .replace("çy", "nd")
-            .replace("rr", "ns")
-            .replace("rř", "nš")
-            .replace("řr", "ňs")
-            .replace("řř", "ňš")
-            .replace("ngn", "ňn");
+        let mut ca = ca.to_owned();
+        for (original, substitution) in Ca::ALLOMORPHS {
+            ca = ca.replace(original, substitution);
+        }
 
         let mut chars = ca.chars();
 
@@ -120,19 +166,19 @@ impl Ca {
             return String::new();
         };
 
-        let rest: String = chars.collect();
-
-        let rest = rest
-            .replace("gm", "x")
-            .replace("gn", "ň")
-            .replace("çx", "xw")
-            .replace("bm", "v")
-            .replace("bn", "ḑ");
+        let mut rest: String = chars.collect();
+        for (original, substitution) in Ca::NON_INITIAL_ALLOMORPHS {
+            rest = rest.replace(original, substitution);
+        }
 
         let mut output = first.to_string();
         output += &rest;
 
-        output.replace("fv", "vw").replace("ţḑ", "ḑy")
+        for (original, substitution) in Ca::FINAL_ALLOMORPHS {
+            output = output.replace(original, substitution);
+        }
+
+        output
     }
 
     /// Unapplies allomorphs on an ungeminated Ca string.
@@ -270,35 +316,12 @@ impl Ca {
             _ => {}
         }
 
-        macro_rules! substitute {
-            (match ca { $($ungeminate:literal => $geminate:literal,)+ }) => {
-                $(if ca.contains($ungeminate) {
-                    return Some(ca.replace($ungeminate, $geminate));
-                })+
-            };
+        for (ungeminated, geminated) in Ca::GEMINATE_SUBSTITUTIONS {
+            if ca.contains(ungeminated) {
+                return Some(ca.replace(ungeminated, geminated));
+            }
         }
 
-        substitute!(match ca {
-            "pt" => "bbḑ",
-            "pk" => "bbv",
-            "kt" => "ggḑ",
-            "kp" => "ggv",
-            "tk" => "ḑvv",
-            "tp" => "ddv",
-            "pm" => "vvm",
-            "pm" => "vvn",
-            "km" => "xxm",
-            "kn" => "xxn",
-            "tm" => "ḑḑm",
-            "tn" => "ḑḑn",
-            "bm" => "mmw",
-            "bn" => "mml",
-            "gm" => "ňňw",
-            "gn" => "ňňl",
-            "dm" => "nnw",
-            "dn" => "nnl",
-        });
-
         None
     }
 
@@ -307,14 +330,17 @@ impl Ca {
     pub fn try_geminate(ca: &str) -> Option<String> {
         let mut prefix = 'l';
 
-        if ca.starts_with(|char| {
-            if matches!(char, 'l' | 'r' | 'ř') {
-                prefix = char;
-                true
-            } else {
-                false
-            }
-        }) {
+        // A lone l, r, or ř is geminated by doubling it, like any other single consonant.
+        if ca.chars().nth(1).is_some()
+            && ca.starts_with(|char| {
+                if matches!(char, 'l' | 'r' | 'ř') {
+                    prefix = char;
+                    true
+                } else {
+                    false
+                }
+            })
+        {
             match Ca::try_geminate_ignoring_lrř(&ca[prefix.len_utf8()..]) {
                 Some(mut value) => {
                     value.insert(0, prefix);
@@ -354,35 +380,12 @@ impl Ca {
     /// Ungeminates a geminated Ca form, returning an [`Option`] indicating whether anything was
     /// removed.
     pub fn ungeminate(ca: &str) -> Option<String> {
-        macro_rules! substitute {
-            (match ca { $($geminate:literal => $ungeminate:literal,)+ }) => {
-                $(if ca.contains($geminate) {
-                    return Some(ca.replace($geminate, $ungeminate));
-                })+
-            };
+        for (ungeminated, geminated) in Ca::GEMINATE_SUBSTITUTIONS {
+            if ca.contains(geminated) {
+                return Some(ca.replace(geminated, ungeminated));
+            }
         }
 
-        substitute!(match ca {
-            "bbḑ" => "pt",
-            "bbv" => "pk",
-            "ggḑ" => "kt",
-            "ggv" => "kp",
-            "ḑvv" => "tk",
-            "ddv" => "tp",
-            "vvm" => "pm",
-            "vvn" => "pm",
-            "xxm" => "km",
-            "xxn" => "kn",
-            "ḑḑm" => "tm",
-            "ḑḑn" => "tn",
-            "mmw" => "bm",
-            "mml" => "bn",
-            "ňňw" => "gm",
-            "ňňl" => "gn",
-            "nnw" => "dm",
-            "nnl" => "dn",
-        });
-
         let mut output = String::with_capacity(ca.len() + 4);
         let mut chars = ca.chars();
         let Some(mut last_char) = chars.next() else {
@@ -419,7 +422,12 @@ impl Ca {
         Ca::geminate(&self.to_ungeminated_string())
     }
 
-    /// Creates a possibly-geminated Ca string.
+    /// Creates a possibly-geminated Ca string, as used in formatives and referentials.
+    ///
+    /// The Ca is first built from its component consonants. The allomorphic substitutions in
+    /// [`Ca::ALLOMORPHS`], [`Ca::NON_INITIAL_ALLOMORPHS`], and [`Ca::FINAL_ALLOMORPHS`] are then
+    /// applied in that order. If `is_geminate` is `true`, the result is geminated by doubling the
+    /// appropriate consonant or by applying one of the [`Ca::GEMINATE_SUBSTITUTIONS`].
     pub fn to_string(self, is_geminate: bool) -> String {
         if is_geminate {
             self.to_geminated_string()
@@ -642,4 +650,66 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn canonical_forms() {
+        for (ca, ungeminated, geminated) in [
+            (ca!(), "l", "ll"),
+            (ca!(RPV), "tļ", "ttļ"),
+            (ca!(G), "r", "rr"),
+            (ca!(N), "v", "vv"),
+            (ca!(A), "j", "jj"),
+            (ca!(ASO), "nļ", "nnļ"),
+            (ca!(DPX), "s", "ss"),
+            (ca!(PRX), "d", "dd"),
+            (ca!(ICP, N), "gw", "ggw"),
+            (ca!(MSS, RPV), "tl", "ttl"),
+            (ca!(MSS, PRX), "nt", "nnt"),
+            (ca!(MSC, ICP), "nk", "nnk"),
+            (ca!(MSS, ICP), "tk", "ḑvv"),
+            (ca!(MSF, ICP), "pk", "bbv"),
+            (ca!(MSS, PRX, M, RPV), "ntl", "nntl"),
+            (ca!(COA, MSS), "rt", "rtt"),
+        ] {
+            assert_eq!(ca.to_string(false), ungeminated, "{ca:?}");
+            assert_eq!(ca.to_string(true), geminated, "{ca:?}");
+        }
+    }
+
+    #[test]
+    fn builders() {
+        assert!(ca!().is_default());
+        assert!(!ca!(RPV).is_default());
+
+        assert_eq!(ca!().with_essence(Essence::RPV), ca!(RPV));
+        assert_eq!(ca!(G).with_perspective(Perspective::M), ca!());
+        assert_eq!(
+            ca!(COA, MSS)
+                .with_affiliation(Affiliation::VAR)
+                .with_configuration(Configuration::DFC)
+                .with_extension(Extension::PRX),
+            ca!(VAR, DFC, PRX),
+        );
+    }
+
+    #[test]
+    fn gloss_order() {
+        // Components are glossed in slot order: affiliation, configuration, extension,
+        // perspective, and essence.
+        for (ca, short, long) in [
+            (ca!(), "", "CSL.UPX.DEL.M.NRM"),
+            (ca!(ASO, PRX), "ASO.PRX", "ASO.UPX.PRX.M.NRM"),
+            (
+                ca!(VAR, MDC, ICP, N, RPV),
+                "VAR.MDC.ICP.N.RPV",
+                "VAR.MDC.ICP.N.RPV",
+            ),
+            (ca!(COA, DFF, GRA), "COA.DFF.GRA", "COA.DFF.GRA.M.NRM"),
+            (ca!(MSS, ATV, A), "MSS.ATV.A", "CSL.MSS.ATV.A.NRM"),
+            (ca!(ASO, DPL, G, RPV), "ASO.DPL.G.RPV", "ASO.UPX.DPL.G.RPV"),
+        ] {
+            assert_eq!(ca.gloss(GlossFlags::NONE), short, "{ca:?}");
+            assert_eq!(ca.gloss(GlossFlags::SHOW_DEFAULTS), long, "{ca:?}");
+        }
+    }
 }
@@ -26,8 +26,21 @@ pub struct SimilarityAndSeparability {
 }
 
 impl SimilarityAndSeparability {
+    /// All items in this category.
+    pub const ALL_ITEMS: [Self; 9] = [
+        Self::new(Similarity::S, Separability::S),
+        Self::new(Similarity::S, Separability::C),
+        Self::new(Similarity::S, Separability::F),
+        Self::new(Similarity::D, Separability::S),
+        Self::new(Similarity::D, Separability::C),
+        Self::new(Similarity::D, Separability::F),
+        Self::new(Similarity::F, Separability::S),
+        Self::new(Similarity::F, Separability::C),
+        Self::new(Similarity::F, Separability::F),
+    ];
+
     /// Constructs a new [`SimilarityAndSeparability`] from a [`Similarity`] and [`Separability`].
-    pub fn new(similarity: Similarity, separability: Separability) -> Self {
+    pub const fn new(similarity: Similarity, separability: Separability) -> Self {
         Self {
             similarity,
             separability,
@@ -117,6 +130,10 @@ impl Category for SimilarityAndSeparability {
             },
         }
     }
+
+    fn all_variants() -> &'static [Self] {
+        &Self::ALL_ITEMS
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -131,6 +148,19 @@ pub struct DestructuredConfiguration {
 }
 
 impl DestructuredConfiguration {
+    /// All items in this category, in the same order as [`Configuration::ALL_ITEMS`].
+    pub const ALL_ITEMS: [Self; Configuration::ALL_ITEMS.len()] = {
+        let mut items = [Configuration::UPX.destructure(); Configuration::ALL_ITEMS.len()];
+        let mut index = 0;
+
+        while index < items.len() {
+            items[index] = Configuration::ALL_ITEMS[index].destructure();
+            index += 1;
+        }
+
+        items
+    };
+
     /// Constructs a new [`DestructuredConfiguration`] from a [`Plexity`] and
     /// [`Option<SimilarityAndSeparability>`].
     pub fn new(
@@ -158,6 +188,10 @@ impl Category for DestructuredConfiguration {
     fn name(self) -> &'static str {
         Configuration::from(self).name()
     }
+
+    fn all_variants() -> &'static [Self] {
+        &Self::ALL_ITEMS
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -179,6 +213,47 @@ pub struct Ca {
     pub essence: Essence,
 }
 
+impl Ca {
+    /// Returns a copy of `self` with its affiliation replaced by `affiliation`.
+    pub const fn with_affiliation(self, affiliation: Affiliation) -> Self {
+        Self {
+            affiliation,
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its configuration replaced by `configuration`.
+    pub const fn with_configuration(self, configuration: Configuration) -> Self {
+        Self {
+            configuration,
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its extension replaced by `extension`.
+    pub const fn with_extension(self, extension: Extension) -> Self {
+        Self { extension, ..self }
+    }
+
+    /// Returns a copy of `self` with its perspective replaced by `perspective`.
+    pub const fn with_perspective(self, perspective: Perspective) -> Self {
+        Self {
+            perspective,
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its essence replaced by `essence`.
+    pub const fn with_essence(self, essence: Essence) -> Self {
+        Self { essence, ..self }
+    }
+
+    /// Checks whether `self` is the default Ca (CSL.UPX.DEL.M.NRM).
+    pub fn is_default(self) -> bool {
+        self == Self::default()
+    }
+}
+
 impl Gloss for Ca {
     fn gloss(&self, flags: GlossFlags) -> String {
         if flags.matches(GlossFlags::SHOW_DEFAULTS) {
@@ -255,26 +330,72 @@ pub enum Vn {
     /// A variant containing an [`Effect`].
     Effect(Effect),
 
-    /// A variant containing a [`Level`].
+    /// A variant containing a relative [`Level`], which compares against the other party of the
+    /// comparison. This is the only kind of level a Vn form can show.
     Level(Level),
 
+    /// A variant containing an absolute [`Level`], which compares against the scale of all
+    /// possible values instead.
+    ///
+    /// No Vn form shows an absolute level. A formative with one romanizes with the default MNO
+    /// valence as its Vn and an `řž` affix of Type 2 with the level's degree at the end of slot
+    /// VII, which is the form script uses as well. If slot VII holds an appositive referential
+    /// affix, no affix can be added, and the level romanizes as relative instead. Parsing the
+    /// romanization yields the affix, which
+    /// [`UncheckedFormative::affix_to_vn`][crate::word::UncheckedFormative::affix_to_vn] moves
+    /// back into the Vn.
+    AbsoluteLevel(Level),
+
     /// A variant containing an [`Aspect`].
     Aspect(Aspect),
 }
 
 impl Vn {
     /// Converts this [`Vn`] into a [`NonAspectualVn`], returning an [`Err<Aspect>`] if it fails.
+    ///
+    /// An absolute level becomes the relative level with the same value, as that is the closest
+    /// level a Vn form can show.
     pub const fn as_non_aspectual_vn(self) -> Result<NonAspectualVn, Aspect> {
         match self {
             Self::Valence(value) => Ok(NonAspectualVn::Valence(value)),
             Self::Phase(value) => Ok(NonAspectualVn::Phase(value)),
             Self::Effect(value) => Ok(NonAspectualVn::Effect(value)),
-            Self::Level(value) => Ok(NonAspectualVn::Level(value)),
+            Self::Level(value) | Self::AbsoluteLevel(value) => Ok(NonAspectualVn::Level(value)),
             Self::Aspect(value) => Err(value),
         }
     }
 }
 
+/// Glosses an absolute level as its abbreviation followed by a subscript `ₐ`, or with the word
+/// "absolute" before its name if `flags` contains `GlossFlags::LONG`.
+const fn gloss_absolute_level(level: Level, flags: GlossFlags) -> &'static str {
+    if flags.matches(GlossFlags::LONG) {
+        match level {
+            Level::MIN => "absolute minimal",
+            Level::SBE => "absolute subequative",
+            Level::IFR => "absolute inferior",
+            Level::DFC => "absolute deficient",
+            Level::EQU => "absolute equative",
+            Level::SUR => "absolute surpassive",
+            Level::SPL => "absolute superlative",
+            Level::SPQ => "absolute superequative",
+            Level::MAX => "absolute maximal",
+        }
+    } else {
+        match level {
+            Level::MIN => "MINₐ",
+            Level::SBE => "SBEₐ",
+            Level::IFR => "IFRₐ",
+            Level::DFC => "DFCₐ",
+            Level::EQU => "EQUₐ",
+            Level::SUR => "SURₐ",
+            Level::SPL => "SPLₐ",
+            Level::SPQ => "SPQₐ",
+            Level::MAX => "MAXₐ",
+        }
+    }
+}
+
 impl Default for Vn {
     fn default() -> Self {
         Self::Valence(Valence::MNO)
@@ -288,6 +409,7 @@ impl GlossStatic for Vn {
             Self::Phase(value) => value.gloss_static(flags),
             Self::Effect(value) => value.gloss_static(flags),
             Self::Level(value) => value.gloss_static(flags),
+            Self::AbsoluteLevel(value) => gloss_absolute_level(*value, flags),
             Self::Aspect(value) => value.gloss_static(flags),
         }
     }
@@ -475,14 +597,35 @@ pub struct ReferentList<PerspectiveType> {
     pub perspective: PerspectiveType,
 }
 
+impl<PerspectiveType> ReferentList<PerspectiveType> {
+    /// Gets the abbreviation of each referent's target (such as `1m` or `pa`), in the same order
+    /// they appear in this list's gloss. Referent effects and the perspective are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tnil::category::NormalReferentList;
+    /// let referents: NormalReferentList = "lkç".parse().unwrap();
+    /// assert_eq!(referents.referent_abbrs(), ["1m", "pa"]);
+    /// ```
+    pub fn referent_abbrs(&self) -> Vec<&'static str> {
+        self.referents
+            .iter()
+            .map(|referent| referent.target.abbr())
+            .collect()
+    }
+}
+
 impl<PerspectiveType> Gloss for ReferentList<PerspectiveType>
 where
     PerspectiveType: Default + GlossStatic + PartialEq,
 {
     fn gloss(&self, flags: GlossFlags) -> String {
-        let needs_brackets = self.referents.len() != 1
-            || self.perspective != PerspectiveType::default()
-            || flags.matches(GlossFlags::SHOW_DEFAULTS);
+        let shows_perspective = self.perspective != PerspectiveType::default()
+            || flags.matches(GlossFlags::SHOW_DEFAULTS)
+            || flags.matches(GlossFlags::EXPLICIT_MONADIC);
+
+        let needs_brackets = self.referents.len() != 1 || shows_perspective;
 
         let mut output = String::new();
 
@@ -502,9 +645,7 @@ where
             output += &referent.gloss(flags);
         }
 
-        if self.perspective != PerspectiveType::default()
-            || flags.matches(GlossFlags::SHOW_DEFAULTS)
-        {
+        if shows_perspective {
             if !is_first_segment {
                 output += "+";
             }
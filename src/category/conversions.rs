@@ -3,12 +3,13 @@
 //! conversions are also implemented as `From` and `Into` implementations.
 
 use super::{
-    Affiliation, AffixDegree, AffixType, AppositiveCase, ArbitraryMoodOrCaseScope, Aspect, Bias,
-    Ca, Case, CaseScope, Configuration, DatalessRelation, DestructuredConfiguration, Effect,
-    Essence, Extension, Illocution, IllocutionOrValidation, Level, Mood, MoodOrCaseScope,
-    NominalMode, NonAspectualVn, NonDefaultCaseScope, NonDefaultMood, NormalCaShortcut,
-    Perspective, Phase, Plexity, ReferentialAffixPerspective, ReferentialCaShortcut, Sanction,
-    Separability, Similarity, SimilarityAndSeparability, ThematicCase, Valence, Validation, Vn,
+    Affiliation, AffixDegree, AffixShortcut, AffixType, AppositiveCase, ArbitraryMoodOrCaseScope,
+    Aspect, Bias, Ca, Case, CaseScope, Configuration, Context, DatalessRelation,
+    DestructuredConfiguration, Effect, Essence, Extension, Function, Illocution,
+    IllocutionOrValidation, Level, Mood, MoodOrCaseScope, NominalMode, NonAspectualVn,
+    NonDefaultCaseScope, NonDefaultMood, NormalCaShortcut, Perspective, Phase, Plexity,
+    ReferentialAffixPerspective, ReferentialCaShortcut, RegisterType, Sanction, Separability,
+    Similarity, SimilarityAndSeparability, Specification, ThematicCase, Valence, Validation, Vn,
     VowelFormDegree, VowelFormSequence,
 };
 use crate::{
@@ -289,6 +290,19 @@ impl Configuration {
             },
         }
     }
+
+    /// Builds a [`Configuration`] from its [`Plexity`] and its [`Similarity`] and [`Separability`],
+    /// which are [`None`] for UPX and DPX. This is the inverse of [`Configuration::destructure`].
+    pub const fn from_parts(
+        plexity: Plexity,
+        similarity_and_separability: Option<SimilarityAndSeparability>,
+    ) -> Self {
+        DestructuredConfiguration {
+            plexity,
+            similarity_and_separability,
+        }
+        .restructure()
+    }
 }
 
 impl From<Configuration> for DestructuredConfiguration {
@@ -535,16 +549,27 @@ impl Vn {
         matches!(self, Self::Effect(_))
     }
 
-    /// Returns `true` if this [`Vn`] contains a [`Level`], otherwise returns `false`.
+    /// Returns `true` if this [`Vn`] contains a relative [`Level`], otherwise returns `false`.
     pub const fn is_level(self) -> bool {
         matches!(self, Self::Level(_))
     }
 
+    /// Returns `true` if this [`Vn`] contains an absolute [`Level`], otherwise returns `false`.
+    pub const fn is_absolute_level(self) -> bool {
+        matches!(self, Self::AbsoluteLevel(_))
+    }
+
     /// Returns `true` if this [`Vn`] contains a [`Aspect`], otherwise returns `false`.
     pub const fn is_aspect(self) -> bool {
         matches!(self, Self::Aspect(_))
     }
 
+    /// Returns `true` if this [`Vn`] is the default MNO valence, which is left out of glosses and
+    /// script unless defaults are requested.
+    pub const fn is_default(self) -> bool {
+        matches!(self, Self::Valence(Valence::MNO))
+    }
+
     /// Returns [`Some`] if this [`Vn`] contains a [`Valence`], otherwise returns [`None`].
     pub const fn as_valence(self) -> Option<Valence> {
         match self {
@@ -569,7 +594,7 @@ impl Vn {
         }
     }
 
-    /// Returns [`Some`] if this [`Vn`] contains a [`Level`], otherwise returns [`None`].
+    /// Returns [`Some`] if this [`Vn`] contains a relative [`Level`], otherwise returns [`None`].
     pub const fn as_level(self) -> Option<Level> {
         match self {
             Self::Level(value) => Some(value),
@@ -577,6 +602,15 @@ impl Vn {
         }
     }
 
+    /// Returns [`Some`] if this [`Vn`] contains an absolute [`Level`], otherwise returns
+    /// [`None`].
+    pub const fn as_absolute_level(self) -> Option<Level> {
+        match self {
+            Self::AbsoluteLevel(value) => Some(value),
+            _ => None,
+        }
+    }
+
     /// Returns [`Some`] if this [`Vn`] contains an [`Aspect`], otherwise returns [`None`].
     pub const fn as_aspect(self) -> Option<Aspect> {
         match self {
@@ -1314,6 +1348,15 @@ impl TryAsSpecific<NonDefaultCaseScope> for ArbitraryMoodOrCaseScope {
     }
 }
 
+impl From<MoodOrCaseScope> for ArbitraryMoodOrCaseScope {
+    fn from(value: MoodOrCaseScope) -> Self {
+        match value {
+            MoodOrCaseScope::Mood(value) => value.as_general(),
+            MoodOrCaseScope::CaseScope(value) => value.as_general(),
+        }
+    }
+}
+
 impl From<AffixDegree> for VowelFormDegree {
     fn from(value: AffixDegree) -> Self {
         match value {
@@ -1348,6 +1391,81 @@ impl From<VowelFormDegree> for AffixDegree {
     }
 }
 
+impl AffixDegree {
+    /// Constructs an affix degree from its number, returning [`None`] if it is greater than 9.
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::D0),
+            1 => Some(Self::D1),
+            2 => Some(Self::D2),
+            3 => Some(Self::D3),
+            4 => Some(Self::D4),
+            5 => Some(Self::D5),
+            6 => Some(Self::D6),
+            7 => Some(Self::D7),
+            8 => Some(Self::D8),
+            9 => Some(Self::D9),
+            _ => None,
+        }
+    }
+
+    /// Gets the number of this affix degree.
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Gets the next-higher degree of this affix, staying at D9 if `self` is already D9.
+    ///
+    /// Degree 0 is not part of the D1–D9 intensity scale that this walks. Instead, it typically
+    /// represents the absence or negation of whatever the affix's scale describes. Because of
+    /// this, D0 is returned unchanged instead of being stepped into D1.
+    pub const fn saturating_succ(self) -> Self {
+        match self {
+            Self::D0 => Self::D0,
+            Self::D9 => Self::D9,
+            _ => match Self::from_u8(self as u8 + 1) {
+                Some(value) => value,
+                None => unreachable!(),
+            },
+        }
+    }
+
+    /// Gets the next-lower degree of this affix, staying at D1 if `self` is already D1.
+    ///
+    /// As with [`AffixDegree::saturating_succ`], D0 is not part of the intensity scale, so D1 is
+    /// not stepped into D0 and D0 itself is returned unchanged.
+    pub const fn saturating_pred(self) -> Self {
+        match self {
+            Self::D0 => Self::D0,
+            Self::D1 => Self::D1,
+            _ => match Self::from_u8(self as u8 - 1) {
+                Some(value) => value,
+                None => unreachable!(),
+            },
+        }
+    }
+}
+
+impl AffixType {
+    /// Gets the next [`AffixType`] in the order T1, T2, T3, wrapping around from T3 to T1.
+    pub const fn next(self) -> Self {
+        match self {
+            Self::T1 => Self::T2,
+            Self::T2 => Self::T3,
+            Self::T3 => Self::T1,
+        }
+    }
+
+    /// Gets the previous [`AffixType`] in the order T1, T2, T3, wrapping around from T1 to T3.
+    pub const fn prev(self) -> Self {
+        match self {
+            Self::T1 => Self::T3,
+            Self::T2 => Self::T1,
+            Self::T3 => Self::T2,
+        }
+    }
+}
+
 impl From<AffixType> for VowelFormSequence {
     fn from(value: AffixType) -> Self {
         match value {
@@ -1370,6 +1488,57 @@ impl Display for ParseBiasError {
 
 impl Error for ParseBiasError {}
 
+/// An error returned when an integer is out of range for a [`VowelFormDegree`] or
+/// [`VowelFormSequence`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VowelFormOutOfRangeError;
+
+impl Display for VowelFormOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("integer is out of range for a vowel form degree or sequence")
+    }
+}
+
+impl Error for VowelFormOutOfRangeError {}
+
+impl TryFrom<u8> for VowelFormDegree {
+    type Error = VowelFormOutOfRangeError;
+
+    /// Converts a degree from 0 to 9 into a [`VowelFormDegree`]. This is the inverse of
+    /// `degree as u8`.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::D0),
+            1 => Ok(Self::D1),
+            2 => Ok(Self::D2),
+            3 => Ok(Self::D3),
+            4 => Ok(Self::D4),
+            5 => Ok(Self::D5),
+            6 => Ok(Self::D6),
+            7 => Ok(Self::D7),
+            8 => Ok(Self::D8),
+            9 => Ok(Self::D9),
+            _ => Err(VowelFormOutOfRangeError),
+        }
+    }
+}
+
+impl TryFrom<u8> for VowelFormSequence {
+    type Error = VowelFormOutOfRangeError;
+
+    /// Converts a sequence from 1 to 4 into a [`VowelFormSequence`], so that 1 is
+    /// [`VowelFormSequence::S1`]. This is the inverse of `sequence as u8 + 1`.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::S1),
+            2 => Ok(Self::S2),
+            3 => Ok(Self::S3),
+            4 => Ok(Self::S4),
+            _ => Err(VowelFormOutOfRangeError),
+        }
+    }
+}
+
 impl FromStr for Bias {
     type Err = ParseBiasError;
 
@@ -1512,6 +1681,13 @@ impl Bias {
 
 impl Case {
     /// Constructs a case from its associated variant, returning [`None`] upon failure.
+    ///
+    /// A variant is `36 * g + 9 * s + d`, where `g` is 1 if the case's Vc form has a glottal stop
+    /// and 0 otherwise, `s` is its zero-indexed vowel form sequence, and `d` is its zero-indexed
+    /// vowel form degree. Only 68 of the 72 variants this produces are cases: each of the four
+    /// glottal stop groups has eight cases instead of nine, and the last case of each group uses
+    /// the degree 9 vowel form, leaving degree 8 unused. Variants 43, 52, 61, and 70 are those
+    /// unused forms, and are not cases. Variants 72 and above are out of range.
     pub const fn from_variant(value: u8) -> Option<Self> {
         match value {
             0 => Some(Self::THM),
@@ -1586,6 +1762,13 @@ impl Case {
         }
     }
 
+    /// Checks whether `value` is the variant of some case. This is `false` for the unused variants
+    /// 43, 52, 61, and 70, as well as anything greater than 71. See [`Case::from_variant`] for
+    /// details.
+    pub const fn is_valid_variant(value: u8) -> bool {
+        Self::from_variant(value).is_some()
+    }
+
     /// Constructs a case from a [`VowelForm`].
     pub fn from_vc(vc: VowelForm) -> Result<Self, ParseError> {
         if vc.degree == VowelFormDegree::D0 {
@@ -1596,10 +1779,7 @@ impl Case {
         let sequence = 9 * (vc.sequence as u8);
         let degree = (vc.degree as u8) - 1;
         let value = shift + sequence + degree;
-        Case::from_variant(value).ok_or_else(|| {
-            eprintln!("hello");
-            ParseError::ExpectedVc
-        })
+        Case::from_variant(value).ok_or(ParseError::ExpectedVc)
     }
 
     /// Converts into an [`IllocutionOrValidation`] with the same vowel form.
@@ -1626,6 +1806,19 @@ impl Case {
             _ => None,
         }
     }
+
+    /// Converts into an [`IllocutionOrValidation`] with the same vowel form, using USP for cases
+    /// with no Vk equivalent.
+    ///
+    /// A verbal formative whose Vc isn't a valid Vk is read as having ASR illocution with an
+    /// unspecified validation, which is USP. Glossing and script generation both use this, so they
+    /// agree on such formatives.
+    pub const fn as_vk_or_usp(self) -> IllocutionOrValidation {
+        match self.as_vk() {
+            Some(ivl) => ivl,
+            None => IllocutionOrValidation::USP,
+        }
+    }
 }
 
 impl IllocutionOrValidation {
@@ -1977,6 +2170,221 @@ impl Aspect {
     pub const CS_FORM_4: &'static str = "mj";
 }
 
+macro_rules! affix_degrees {
+    ($($ty:ident { $($variant:ident = $degree:ident,)+ },)+) => {
+        $(impl $ty {
+            /// Gets the degree of the affix which represents this value in slot VII. The Cs form of
+            /// that affix is this type's `CS_FORM`.
+            pub const fn to_affix_degree(self) -> AffixDegree {
+                match self {
+                    $(Self::$variant => AffixDegree::$degree,)+
+                }
+            }
+
+            /// Gets the value represented by the affix with this type's `CS_FORM` and the given
+            /// degree. Returns [`None`] for degree 0, which has no corresponding value.
+            pub const fn from_affix_degree(degree: AffixDegree) -> Option<Self> {
+                match degree {
+                    $(AffixDegree::$degree => Some(Self::$variant),)+
+                    AffixDegree::D0 => None,
+                }
+            }
+        })+
+    };
+}
+
+affix_degrees!(
+    Valence {
+        MNO = D1,
+        PRL = D2,
+        CRO = D3,
+        RCP = D4,
+        CPL = D5,
+        DUP = D6,
+        DEM = D7,
+        CNG = D8,
+        PTI = D9,
+    },
+    Phase {
+        PUN = D1,
+        ITR = D2,
+        REP = D3,
+        ITM = D4,
+        RCT = D5,
+        FRE = D6,
+        FRG = D7,
+        VAC = D8,
+        FLC = D9,
+    },
+    Effect {
+        BEN1 = D1,
+        BEN2 = D2,
+        BEN3 = D3,
+        BENSELF = D4,
+        UNK = D5,
+        DETSELF = D6,
+        DET3 = D7,
+        DET2 = D8,
+        DET1 = D9,
+    },
+    Level {
+        MIN = D1,
+        SBE = D2,
+        IFR = D3,
+        DFC = D4,
+        EQU = D5,
+        SUR = D6,
+        SPL = D7,
+        SPQ = D8,
+        MAX = D9,
+    },
+);
+
+impl Aspect {
+    /// Gets the Cs form of the affix which represents this [`Aspect`] in slot VII. This is one of
+    /// [`Aspect::CS_FORM_1`] through [`Aspect::CS_FORM_4`].
+    const fn affix_cs_form(self) -> &'static str {
+        match self {
+            Self::RTR
+            | Self::PRS
+            | Self::HAB
+            | Self::PRG
+            | Self::IMM
+            | Self::PCS
+            | Self::REG
+            | Self::SMM
+            | Self::ATP => Self::CS_FORM_1,
+            Self::RSM
+            | Self::CSS
+            | Self::PAU
+            | Self::RGR
+            | Self::PCL
+            | Self::CNT
+            | Self::ICS
+            | Self::EXP
+            | Self::IRP => Self::CS_FORM_2,
+            Self::PMP
+            | Self::CLM
+            | Self::DLT
+            | Self::TMP
+            | Self::XPD
+            | Self::LIM
+            | Self::EPD
+            | Self::PTC
+            | Self::PPR => Self::CS_FORM_3,
+            Self::DCL
+            | Self::CCL
+            | Self::CUL
+            | Self::IMD
+            | Self::TRD
+            | Self::TNS
+            | Self::ITC
+            | Self::MTV
+            | Self::SQN => Self::CS_FORM_4,
+        }
+    }
+
+    /// Gets the degree of the affix which represents this [`Aspect`] in slot VII. The Cs form of
+    /// that affix is given by [`Aspect::affix_cs_form`].
+    const fn affix_degree(self) -> AffixDegree {
+        match self {
+            Self::RTR => AffixDegree::D1,
+            Self::PRS => AffixDegree::D2,
+            Self::HAB => AffixDegree::D3,
+            Self::PRG => AffixDegree::D4,
+            Self::IMM => AffixDegree::D5,
+            Self::PCS => AffixDegree::D6,
+            Self::REG => AffixDegree::D7,
+            Self::SMM => AffixDegree::D8,
+            Self::ATP => AffixDegree::D9,
+
+            Self::RSM => AffixDegree::D1,
+            Self::CSS => AffixDegree::D2,
+            Self::PAU => AffixDegree::D3,
+            Self::RGR => AffixDegree::D4,
+            Self::PCL => AffixDegree::D5,
+            Self::CNT => AffixDegree::D6,
+            Self::ICS => AffixDegree::D7,
+            Self::EXP => AffixDegree::D8,
+            Self::IRP => AffixDegree::D9,
+
+            Self::PMP => AffixDegree::D1,
+            Self::CLM => AffixDegree::D2,
+            Self::DLT => AffixDegree::D3,
+            Self::TMP => AffixDegree::D4,
+            Self::XPD => AffixDegree::D5,
+            Self::LIM => AffixDegree::D6,
+            Self::EPD => AffixDegree::D7,
+            Self::PTC => AffixDegree::D8,
+            Self::PPR => AffixDegree::D9,
+
+            Self::DCL => AffixDegree::D1,
+            Self::CCL => AffixDegree::D2,
+            Self::CUL => AffixDegree::D3,
+            Self::IMD => AffixDegree::D4,
+            Self::TRD => AffixDegree::D5,
+            Self::TNS => AffixDegree::D6,
+            Self::ITC => AffixDegree::D7,
+            Self::MTV => AffixDegree::D8,
+            Self::SQN => AffixDegree::D9,
+        }
+    }
+
+    /// Gets the Cs form and degree of the affix which represents this [`Aspect`] in slot VII. The
+    /// Cs form is one of [`Aspect::CS_FORM_1`] through [`Aspect::CS_FORM_4`].
+    pub const fn to_cs_and_degree(self) -> (&'static str, AffixDegree) {
+        (self.affix_cs_form(), self.affix_degree())
+    }
+
+    /// Gets the [`Aspect`] represented by an affix with the given Cs form and degree. This is the
+    /// inverse of [`Aspect::to_cs_and_degree`]. Returns [`None`] if the Cs form isn't one of
+    /// [`Aspect::CS_FORM_1`] through [`Aspect::CS_FORM_4`] or if the degree is 0.
+    pub fn from_cs_and_degree(cs: &str, degree: AffixDegree) -> Option<Self> {
+        match (cs, degree) {
+            (Self::CS_FORM_1, AffixDegree::D1) => Some(Self::RTR),
+            (Self::CS_FORM_1, AffixDegree::D2) => Some(Self::PRS),
+            (Self::CS_FORM_1, AffixDegree::D3) => Some(Self::HAB),
+            (Self::CS_FORM_1, AffixDegree::D4) => Some(Self::PRG),
+            (Self::CS_FORM_1, AffixDegree::D5) => Some(Self::IMM),
+            (Self::CS_FORM_1, AffixDegree::D6) => Some(Self::PCS),
+            (Self::CS_FORM_1, AffixDegree::D7) => Some(Self::REG),
+            (Self::CS_FORM_1, AffixDegree::D8) => Some(Self::SMM),
+            (Self::CS_FORM_1, AffixDegree::D9) => Some(Self::ATP),
+
+            (Self::CS_FORM_2, AffixDegree::D1) => Some(Self::RSM),
+            (Self::CS_FORM_2, AffixDegree::D2) => Some(Self::CSS),
+            (Self::CS_FORM_2, AffixDegree::D3) => Some(Self::PAU),
+            (Self::CS_FORM_2, AffixDegree::D4) => Some(Self::RGR),
+            (Self::CS_FORM_2, AffixDegree::D5) => Some(Self::PCL),
+            (Self::CS_FORM_2, AffixDegree::D6) => Some(Self::CNT),
+            (Self::CS_FORM_2, AffixDegree::D7) => Some(Self::ICS),
+            (Self::CS_FORM_2, AffixDegree::D8) => Some(Self::EXP),
+            (Self::CS_FORM_2, AffixDegree::D9) => Some(Self::IRP),
+
+            (Self::CS_FORM_3, AffixDegree::D1) => Some(Self::PMP),
+            (Self::CS_FORM_3, AffixDegree::D2) => Some(Self::CLM),
+            (Self::CS_FORM_3, AffixDegree::D3) => Some(Self::DLT),
+            (Self::CS_FORM_3, AffixDegree::D4) => Some(Self::TMP),
+            (Self::CS_FORM_3, AffixDegree::D5) => Some(Self::XPD),
+            (Self::CS_FORM_3, AffixDegree::D6) => Some(Self::LIM),
+            (Self::CS_FORM_3, AffixDegree::D7) => Some(Self::EPD),
+            (Self::CS_FORM_3, AffixDegree::D8) => Some(Self::PTC),
+            (Self::CS_FORM_3, AffixDegree::D9) => Some(Self::PPR),
+
+            (Self::CS_FORM_4, AffixDegree::D1) => Some(Self::DCL),
+            (Self::CS_FORM_4, AffixDegree::D2) => Some(Self::CCL),
+            (Self::CS_FORM_4, AffixDegree::D3) => Some(Self::CUL),
+            (Self::CS_FORM_4, AffixDegree::D4) => Some(Self::IMD),
+            (Self::CS_FORM_4, AffixDegree::D5) => Some(Self::TRD),
+            (Self::CS_FORM_4, AffixDegree::D6) => Some(Self::TNS),
+            (Self::CS_FORM_4, AffixDegree::D7) => Some(Self::ITC),
+            (Self::CS_FORM_4, AffixDegree::D8) => Some(Self::MTV),
+            (Self::CS_FORM_4, AffixDegree::D9) => Some(Self::SQN),
+            _ => None,
+        }
+    }
+}
+
 impl AsGeneral<AffixSlot> for NormalAffixSlot {
     fn as_general(self) -> AffixSlot {
         match self {
@@ -2001,3 +2409,496 @@ impl TryAsSpecific<NormalAffixSlot> for AffixSlot {
         }
     }
 }
+
+impl Specification {
+    /// Gets the next [`Specification`] in the order BSC, CTE, CSV, OBJ, wrapping around from OBJ
+    /// to BSC.
+    pub const fn next(self) -> Self {
+        match self {
+            Self::BSC => Self::CTE,
+            Self::CTE => Self::CSV,
+            Self::CSV => Self::OBJ,
+            Self::OBJ => Self::BSC,
+        }
+    }
+
+    /// Gets the previous [`Specification`] in the order BSC, CTE, CSV, OBJ, wrapping around from
+    /// BSC to OBJ.
+    pub const fn prev(self) -> Self {
+        match self {
+            Self::BSC => Self::OBJ,
+            Self::CTE => Self::BSC,
+            Self::CSV => Self::CTE,
+            Self::OBJ => Self::CSV,
+        }
+    }
+
+    /// Gets the [`Specification`] marked by the degree of a Vr form, returning [`None`] if the
+    /// degree isn't used in Vr forms (that is, if it is D0 or D5).
+    ///
+    /// Degrees 1-4 mark the specifications of stative formatives in order, and degrees 6-9 mark
+    /// the specifications of dynamic formatives in reverse order.
+    pub const fn from_vr_degree(degree: VowelFormDegree) -> Option<Self> {
+        match degree {
+            VowelFormDegree::D1 | VowelFormDegree::D9 => Some(Self::BSC),
+            VowelFormDegree::D2 | VowelFormDegree::D8 => Some(Self::CTE),
+            VowelFormDegree::D3 | VowelFormDegree::D7 => Some(Self::CSV),
+            VowelFormDegree::D4 | VowelFormDegree::D6 => Some(Self::OBJ),
+            VowelFormDegree::D0 | VowelFormDegree::D5 => None,
+        }
+    }
+}
+
+impl Function {
+    /// Gets the [`Function`] marked by the degree of a Vr form, returning [`None`] if the degree
+    /// isn't used in Vr forms (that is, if it is D0 or D5).
+    ///
+    /// Degrees 1-4 mark stative formatives, and degrees 6-9 mark dynamic formatives.
+    pub const fn from_vr_degree(degree: VowelFormDegree) -> Option<Self> {
+        match degree {
+            VowelFormDegree::D1
+            | VowelFormDegree::D2
+            | VowelFormDegree::D3
+            | VowelFormDegree::D4 => Some(Self::STA),
+            VowelFormDegree::D6
+            | VowelFormDegree::D7
+            | VowelFormDegree::D8
+            | VowelFormDegree::D9 => Some(Self::DYN),
+            VowelFormDegree::D0 | VowelFormDegree::D5 => None,
+        }
+    }
+}
+
+impl Context {
+    /// Gets the [`Context`] marked by the series of a Vr form.
+    pub const fn from_vr_sequence(sequence: VowelFormSequence) -> Self {
+        match sequence {
+            VowelFormSequence::S1 => Self::EXS,
+            VowelFormSequence::S2 => Self::FNC,
+            VowelFormSequence::S3 => Self::RPS,
+            VowelFormSequence::S4 => Self::AMG,
+        }
+    }
+}
+
+impl AffixShortcut {
+    /// Gets the [`AffixShortcut`] marked by the series of the Vv form of a normal or numeric
+    /// formative without a Ca shortcut.
+    pub const fn from_vv_sequence(sequence: VowelFormSequence) -> Self {
+        match sequence {
+            VowelFormSequence::S1 => Self::None,
+            VowelFormSequence::S2 => Self::NEG4,
+            VowelFormSequence::S3 => Self::DCD4,
+            VowelFormSequence::S4 => Self::DCD5,
+        }
+    }
+
+    /// Gets the series of the Vv form which marks this [`AffixShortcut`]. This is the inverse of
+    /// [`AffixShortcut::from_vv_sequence`].
+    pub const fn to_vv_sequence(self) -> VowelFormSequence {
+        match self {
+            Self::None => VowelFormSequence::S1,
+            Self::NEG4 => VowelFormSequence::S2,
+            Self::DCD4 => VowelFormSequence::S3,
+            Self::DCD5 => VowelFormSequence::S4,
+        }
+    }
+}
+
+impl RegisterType {
+    /// Returns `true` if this [`RegisterType`] opens a register, otherwise returns `false`.
+    pub const fn is_start(self) -> bool {
+        matches!(
+            self,
+            Self::DSV | Self::PNT | Self::SPF | Self::EXM | Self::CGT
+        )
+    }
+
+    /// Returns `true` if this [`RegisterType`] closes a register, otherwise returns `false`. This
+    /// includes both the paired `_END` registers and the general [`RegisterType::END`].
+    pub const fn is_end(self) -> bool {
+        !self.is_start()
+    }
+
+    /// Gets the register closed by this [`RegisterType`], returning [`None`] if this is a start
+    /// register or the general [`RegisterType::END`], which closes whichever register is open.
+    pub const fn closes(self) -> Option<Self> {
+        match self {
+            Self::DSV_END => Some(Self::DSV),
+            Self::PNT_END => Some(Self::PNT),
+            Self::SPF_END => Some(Self::SPF),
+            Self::EXM_END => Some(Self::EXM),
+            Self::CGT_END => Some(Self::CGT),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        category::Category,
+        gloss::{GlossFlags, GlossStatic},
+    };
+    use std::{env, process::Command};
+
+    #[test]
+    fn all_variants() {
+        fn check<T: Category + Copy + PartialEq + std::fmt::Debug + 'static>(expected: &[T]) {
+            assert_eq!(T::all_variants(), expected);
+
+            for (index, item) in expected.iter().enumerate() {
+                assert!(expected[..index]
+                    .iter()
+                    .all(|other| other.abbr() != item.abbr()));
+            }
+        }
+
+        check(&Case::ALL_ITEMS);
+        check(&AffixShortcut::ALL_ITEMS);
+        check(&NormalCaShortcut::ALL_ITEMS);
+        check(&ArbitraryMoodOrCaseScope::ALL_ITEMS);
+        check(&SimilarityAndSeparability::ALL_ITEMS);
+        check(&DestructuredConfiguration::ALL_ITEMS);
+
+        assert_eq!(AffixShortcut::NEG4.abbr(), "NEG/4");
+        assert_eq!(NormalCaShortcut::G_RPV.abbr(), "G/RPV");
+        assert_eq!(ArbitraryMoodOrCaseScope::SUB_CCA.abbr(), "SUB_CCA");
+        assert_eq!(
+            ArbitraryMoodOrCaseScope::SUB_CCA.gloss_static(GlossFlags::NONE),
+            "SUB/CCA"
+        );
+
+        for config in DestructuredConfiguration::ALL_ITEMS {
+            assert_eq!(
+                DestructuredConfiguration::from(Configuration::from(config)),
+                config
+            );
+        }
+    }
+
+    #[test]
+    fn bias_round_trips() {
+        for bias in Bias::ALL_ITEMS {
+            assert_eq!(bias.as_cb().parse(), Ok(bias));
+            assert_eq!(
+                Bias::ALL_ITEMS
+                    .iter()
+                    .filter(|other| other.name() == bias.name())
+                    .count(),
+                1
+            );
+        }
+
+        assert_eq!(Bias::all_variants(), Bias::ALL_ITEMS);
+        assert_eq!(Bias::ACC.name(), "Accidental");
+        assert_eq!("".parse::<Bias>(), Err(ParseBiasError));
+    }
+
+    #[test]
+    fn invalid_vc_prints_nothing() {
+        // Degree 8 of series 1 with a glottal stop would be case 43, which doesn't exist.
+        let invalid_vc = VowelForm {
+            has_glottal_stop: true,
+            sequence: VowelFormSequence::S1,
+            degree: VowelFormDegree::D8,
+        };
+
+        if env::var_os("TNIL_INVALID_VC_CHILD").is_some() {
+            assert_eq!(Case::from_vc(invalid_vc), Err(ParseError::ExpectedVc));
+            return;
+        }
+
+        // Output printed by tests is captured, so we rerun this test in a child process with
+        // capturing disabled and check that nothing was written to stderr.
+        let name =
+            module_path!().split_once("::").unwrap().1.to_owned() + "::invalid_vc_prints_nothing";
+
+        let output = Command::new(env::current_exe().unwrap())
+            .args(["--exact", &name, "--nocapture", "--test-threads=1"])
+            .env("TNIL_INVALID_VC_CHILD", "1")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+    }
+
+    #[test]
+    fn specification_cycle() {
+        for specification in Specification::ALL_ITEMS {
+            assert_eq!(specification.next().prev(), specification);
+        }
+
+        assert_eq!(Specification::OBJ.next(), Specification::BSC);
+        assert_eq!(Specification::BSC.prev(), Specification::OBJ);
+
+        assert_eq!(
+            Specification::from_vr_degree(VowelFormDegree::D7),
+            Some(Specification::CSV),
+        );
+        assert_eq!(Specification::from_vr_degree(VowelFormDegree::D5), None);
+    }
+
+    #[test]
+    fn vr_decoding() {
+        use VowelFormDegree as D;
+
+        for degree in [D::D1, D::D2, D::D3, D::D4] {
+            assert_eq!(Function::from_vr_degree(degree), Some(Function::STA));
+        }
+
+        for degree in [D::D6, D::D7, D::D8, D::D9] {
+            assert_eq!(Function::from_vr_degree(degree), Some(Function::DYN));
+        }
+
+        for degree in [D::D0, D::D5] {
+            assert_eq!(Function::from_vr_degree(degree), None);
+            assert_eq!(Specification::from_vr_degree(degree), None);
+        }
+
+        for (degree, specification) in [
+            (D::D1, Specification::BSC),
+            (D::D2, Specification::CTE),
+            (D::D3, Specification::CSV),
+            (D::D4, Specification::OBJ),
+            (D::D6, Specification::OBJ),
+            (D::D7, Specification::CSV),
+            (D::D8, Specification::CTE),
+            (D::D9, Specification::BSC),
+        ] {
+            assert_eq!(Specification::from_vr_degree(degree), Some(specification));
+        }
+
+        for (sequence, context) in [
+            (VowelFormSequence::S1, Context::EXS),
+            (VowelFormSequence::S2, Context::FNC),
+            (VowelFormSequence::S3, Context::RPS),
+            (VowelFormSequence::S4, Context::AMG),
+        ] {
+            assert_eq!(Context::from_vr_sequence(sequence), context);
+        }
+    }
+
+    #[test]
+    fn affix_shortcut_vv_sequences() {
+        for (shortcut, sequence) in AffixShortcut::ALL_ITEMS
+            .into_iter()
+            .zip(VowelFormSequence::ALL_ITEMS)
+        {
+            assert_eq!(AffixShortcut::from_vv_sequence(sequence), shortcut);
+            assert_eq!(shortcut.to_vv_sequence(), sequence);
+        }
+
+        assert_eq!(
+            AffixShortcut::default().to_vv_sequence(),
+            VowelFormSequence::S1
+        );
+    }
+
+    #[test]
+    fn case_variants() {
+        use super::Case;
+
+        let invalid: Vec<u8> = (0..=u8::MAX)
+            .filter(|&value| !Case::is_valid_variant(value))
+            .take_while(|&value| value < 72)
+            .collect();
+
+        assert_eq!(invalid, [43, 52, 61, 70]);
+        assert!((72..=u8::MAX).all(|value| Case::from_variant(value).is_none()));
+
+        assert_eq!(Case::from_variant(69), Some(Case::ELP));
+        assert_eq!(Case::from_variant(71), Some(Case::PLM));
+
+        for case in Case::ALL_ITEMS {
+            assert_eq!(Case::from_variant(case as u8), Some(case));
+        }
+    }
+
+    #[test]
+    fn vowel_form_integers() {
+        use super::{VowelFormDegree, VowelFormOutOfRangeError, VowelFormSequence};
+
+        for degree in VowelFormDegree::ALL_ITEMS {
+            assert_eq!(VowelFormDegree::try_from(degree as u8), Ok(degree));
+        }
+
+        for sequence in VowelFormSequence::ALL_ITEMS {
+            assert_eq!(
+                VowelFormSequence::try_from(sequence as u8 + 1),
+                Ok(sequence)
+            );
+        }
+
+        for value in 0..=u8::MAX {
+            if let Ok(degree) = VowelFormDegree::try_from(value) {
+                assert_eq!(degree as u8, value);
+            } else {
+                assert!(value > 9);
+            }
+
+            if let Ok(sequence) = VowelFormSequence::try_from(value) {
+                assert_eq!(sequence as u8 + 1, value);
+            } else {
+                assert!(value == 0 || value > 4);
+            }
+        }
+
+        assert_eq!(VowelFormDegree::try_from(10), Err(VowelFormOutOfRangeError));
+        assert_eq!(
+            VowelFormSequence::try_from(0),
+            Err(VowelFormOutOfRangeError)
+        );
+    }
+
+    #[test]
+    fn vn_affix_degrees() {
+        macro_rules! check {
+            ($($ty:ident),+) => {
+                $(
+                    for value in $ty::ALL_ITEMS {
+                        assert_eq!($ty::from_affix_degree(value.to_affix_degree()), Some(value));
+                    }
+
+                    for degree in AffixDegree::ALL_ITEMS {
+                        match $ty::from_affix_degree(degree) {
+                            Some(value) => assert_eq!(value.to_affix_degree(), degree),
+                            None => assert_eq!(degree, AffixDegree::D0),
+                        }
+                    }
+                )+
+            };
+        }
+
+        check!(Valence, Phase, Effect, Level);
+
+        let mut pairs = Vec::new();
+
+        for aspect in Aspect::ALL_ITEMS {
+            let (cs, degree) = aspect.to_cs_and_degree();
+            assert_eq!(Aspect::from_cs_and_degree(cs, degree), Some(aspect));
+            assert!(!pairs.contains(&(cs, degree)), "{aspect:?}");
+            pairs.push((cs, degree));
+        }
+
+        assert_eq!(pairs.len(), 36);
+
+        for cs in [
+            Aspect::CS_FORM_1,
+            Aspect::CS_FORM_2,
+            Aspect::CS_FORM_3,
+            Aspect::CS_FORM_4,
+        ] {
+            for degree in AffixDegree::ALL_ITEMS {
+                match Aspect::from_cs_and_degree(cs, degree) {
+                    Some(aspect) => assert_eq!(aspect.to_cs_and_degree(), (cs, degree)),
+                    None => assert_eq!(degree, AffixDegree::D0),
+                }
+            }
+        }
+
+        assert_eq!(
+            Aspect::from_cs_and_degree(Valence::CS_FORM, AffixDegree::D1),
+            None
+        );
+    }
+
+    #[test]
+    fn cast_matches_specificity_traits() {
+        use crate::specificity::Cast;
+
+        for mood in Mood::ALL_ITEMS {
+            let general: ArbitraryMoodOrCaseScope = mood.cast();
+            assert_eq!(general, mood.as_general());
+            assert_eq!(general.cast::<Mood, _>(), mood);
+            assert_eq!(
+                mood.cast::<Option<NonDefaultMood>, _>(),
+                mood.try_as_specific()
+            );
+            assert_eq!(
+                mood.cast::<Option<MoodOrCaseScope>, _>(),
+                Some(MoodOrCaseScope::Mood(mood))
+            );
+        }
+
+        for case_scope in CaseScope::ALL_ITEMS {
+            let general: MoodOrCaseScope = case_scope.cast();
+            assert_eq!(general.cast::<Option<CaseScope>, _>(), Some(case_scope));
+            assert_eq!(general.cast::<Option<Mood>, _>(), None);
+        }
+    }
+
+    #[test]
+    fn affix_type_cycling() {
+        use crate::{
+            affix::PlainAffix,
+            gloss::{Gloss, GlossFlags},
+        };
+
+        for r#type in AffixType::ALL_ITEMS {
+            assert_eq!(r#type.next().prev(), r#type);
+            assert_eq!(r#type.next().next().next(), r#type);
+        }
+
+        assert_eq!(AffixType::T1.next(), AffixType::T2);
+        assert_eq!(AffixType::T3.next(), AffixType::T1);
+        assert_eq!(AffixType::T1.prev(), AffixType::T3);
+
+        for (r#type, gloss) in [
+            (AffixType::T1, "rl/2₁"),
+            (AffixType::T2, "rl/2₂"),
+            (AffixType::T3, "rl/2₃"),
+        ] {
+            let affix = PlainAffix::new("rl", r#type, AffixDegree::D2);
+            assert_eq!(affix.gloss(GlossFlags::NONE), gloss);
+            assert_eq!(affix.gloss(GlossFlags::LONG), gloss);
+        }
+    }
+
+    #[test]
+    fn configuration_parts() {
+        let mut configurations = Vec::new();
+
+        for plexity in Plexity::ALL_ITEMS {
+            configurations.push(Configuration::from_parts(plexity, None));
+
+            for similarity in Similarity::ALL_ITEMS {
+                for separability in Separability::ALL_ITEMS {
+                    let parts = SimilarityAndSeparability {
+                        similarity,
+                        separability,
+                    };
+                    let configuration = Configuration::from_parts(plexity, Some(parts));
+
+                    assert_eq!(configuration.plexity(), plexity);
+                    assert_eq!(configuration.similarity(), Some(similarity));
+                    assert_eq!(configuration.separability(), Some(separability));
+                    assert_eq!(configuration.abbr()[1..2], *similarity.abbr());
+                    assert_eq!(configuration.abbr()[2..], *separability.abbr());
+
+                    configurations.push(configuration);
+                }
+            }
+        }
+
+        assert_eq!(configurations, Configuration::ALL_ITEMS);
+
+        for configuration in Configuration::ALL_ITEMS {
+            let DestructuredConfiguration {
+                plexity,
+                similarity_and_separability,
+            } = configuration.destructure();
+
+            assert_eq!(
+                Configuration::from_parts(plexity, similarity_and_separability),
+                configuration
+            );
+        }
+
+        assert_eq!(Plexity::D.name(), "Duplex");
+        assert_eq!(Similarity::F.name(), "Fuzzy");
+        assert_eq!(Separability::C.abbr(), "C");
+    }
+}
@@ -15,19 +15,73 @@ macro_rules! count_tts {
     ($($tts:tt)*) => {<[()]>::len(&[$(replace_expr!($tts ())),*])};
 }
 
+/// Implements [`FromStr`][std::str::FromStr] for a category by matching against the
+/// abbreviations of its items, and generates a test asserting that every item round-trips.
+macro_rules! from_str_via_abbr {
+    ($enum_name:ident, @no_from_str) => {};
+
+    ($enum_name:ident) => {
+        impl ::std::str::FromStr for $enum_name {
+            type Err = $crate::category::ParseCategoryError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::ALL_ITEMS
+                    .into_iter()
+                    .find(|item| item.abbr() == s)
+                    .ok_or($crate::category::ParseCategoryError)
+            }
+        }
+
+        paste! {
+            #[cfg(test)]
+            #[test]
+            #[allow(non_snake_case)]
+            fn [<$enum_name _from_str>]() {
+                for item in $enum_name::ALL_ITEMS {
+                    assert_eq!(item.abbr().parse(), Ok(item));
+                }
+
+                assert_eq!(
+                    "".parse::<$enum_name>(),
+                    Err($crate::category::ParseCategoryError),
+                );
+            }
+        }
+    };
+}
+
+/// Picks the documentation of an enum variant: the hand-written override if one was given, or the
+/// generated default otherwise.
+macro_rules! variant_doc {
+    ($default:expr) => {
+        $default
+    };
+
+    ($default:expr, $doc:expr) => {
+        $doc
+    };
+}
+
 macro_rules! item {
     (
         $enum_name:ident,
-        $(($variant:ident $(= $value:literal)?, $abbr:expr, $name:expr, $short_gloss:expr, $long_gloss:expr),)+
+        $($(#[doc = $doc:literal])? ($variant:ident $(= $value:literal)?, $name:expr),)+
     ) => {
         paste! {
             #[repr(u8)]
+            #[allow(non_camel_case_types)]
             #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
             #[doc = "Represents a " $enum_name " value."]
             pub enum $enum_name {
                 #[default]
                 $(
-                    #[doc = "The " $variant " (" $name ") " $enum_name "."]
+                    #[doc = variant_doc!(
+                        concat!(
+                            "The ", stringify!($variant), " (", $name, ") ",
+                            stringify!($enum_name), "."
+                        )
+                        $(, $doc)?
+                    )]
                     $variant $(= $value)?,
                 )+
             }
@@ -37,15 +91,22 @@ macro_rules! item {
     (
         #no_default,
         $enum_name:ident,
-        $(($variant:ident $(= $value:literal)?, $abbr:expr, $name:expr, $short_gloss:expr, $long_gloss:expr),)+
+        $($(#[doc = $doc:literal])? ($variant:ident $(= $value:literal)?, $name:expr),)+
     ) => {
         paste! {
             #[repr(u8)]
+            #[allow(non_camel_case_types)]
             #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
             #[doc = "Represents a " $enum_name " value."]
             pub enum $enum_name {
                 $(
-                    #[doc = "The " $variant " (" $name ") " $enum_name "."]
+                    #[doc = variant_doc!(
+                        concat!(
+                            "The ", stringify!($variant), " (", $name, ") ",
+                            stringify!($enum_name), "."
+                        )
+                        $(, $doc)?
+                    )]
                     $variant $(= $value)?,
                 )+
             }
@@ -53,18 +114,22 @@ macro_rules! item {
     };
 }
 
-/// Creates a category, represented as an enum name followed by tuples of `(EnumVariant,
-/// "abbreviation", "name of variant", "short gloss", "long gloss")`.
-macro_rules! custom_category {
+/// Creates a category without a gloss, represented as an enum name followed by tuples of
+/// `(EnumVariant, "abbreviation", "name of variant")`. This generates the enum itself, its
+/// `ALL_ITEMS` constant, and its [`Category`] and [`FromStr`][std::str::FromStr] implementations,
+/// so that they can never fall out of sync. A doc comment placed before a tuple replaces the
+/// generated documentation of that variant.
+macro_rules! unglossed_category {
     (
         $(#$flag:ident,)?
+        $(@$from_str_flag:ident,)?
         $enum_name:ident,
-        $(($variant:ident $(= $value:literal)?, $abbr:expr, $name:expr, $short_gloss:expr, $long_gloss:expr),)+
+        $($(#[doc = $doc:literal])? ($variant:ident $(= $value:literal)?, $abbr:expr, $name:expr),)+
     ) => {
         item!(
             $(#$flag,)?
             $enum_name,
-            $(($variant $(= $value)?, $abbr, $name, $short_gloss, $long_gloss),)+
+            $($(#[doc = $doc])? ($variant $(= $value)?, $name),)+
         );
 
         impl $enum_name {
@@ -72,20 +137,6 @@ macro_rules! custom_category {
             pub const ALL_ITEMS: [Self; count_tts!($($variant)+)] = [$(Self::$variant,)+];
         }
 
-        impl GlossStatic for $enum_name {
-            fn gloss_static(&self, flags: GlossFlags) -> &'static str {
-                if flags.matches(GlossFlags::LONG) {
-                    match self {
-                        $(Self::$variant => $long_gloss,)+
-                    }
-                } else {
-                    match self {
-                        $(Self::$variant => $short_gloss,)+
-                    }
-                }
-            }
-        }
-
         impl Category for $enum_name {
             fn abbr(self) -> &'static str {
                 match self {
@@ -98,6 +149,44 @@ macro_rules! custom_category {
                     $(Self::$variant => $name,)+
                 }
             }
+
+            fn all_variants() -> &'static [Self] {
+                &Self::ALL_ITEMS
+            }
+        }
+
+        from_str_via_abbr!($enum_name $(, @$from_str_flag)?);
+    };
+}
+
+/// Creates a category, represented as an enum name followed by tuples of `(EnumVariant,
+/// "abbreviation", "name of variant", "short gloss", "long gloss")`.
+macro_rules! custom_category {
+    (
+        $(#$flag:ident,)?
+        $(@$from_str_flag:ident,)?
+        $enum_name:ident,
+        $(($variant:ident $(= $value:literal)?, $abbr:expr, $name:expr, $short_gloss:expr, $long_gloss:expr),)+
+    ) => {
+        unglossed_category!(
+            $(#$flag,)?
+            $(@$from_str_flag,)?
+            $enum_name,
+            $(($variant $(= $value)?, $abbr, $name),)+
+        );
+
+        impl GlossStatic for $enum_name {
+            fn gloss_static(&self, flags: GlossFlags) -> &'static str {
+                if flags.matches(GlossFlags::LONG) {
+                    match self {
+                        $(Self::$variant => $long_gloss,)+
+                    }
+                } else {
+                    match self {
+                        $(Self::$variant => $short_gloss,)+
+                    }
+                }
+            }
         }
     };
 }
@@ -107,11 +196,13 @@ macro_rules! custom_category {
 macro_rules! custom_abbr_category {
     (
         $(#$flag:ident,)?
+        $(@$from_str_flag:ident,)?
         $enum_name:ident,
         $(($variant:ident $(= $value:literal)?, $abbr:expr, $name:expr, $long_gloss:expr),)+
     ) => {
         custom_category!(
             $(#$flag,)?
+            $(@$from_str_flag,)?
             $enum_name,
             $((
                 $variant $(= $value)?,
@@ -129,11 +220,13 @@ macro_rules! custom_abbr_category {
 macro_rules! category {
     (
         $(#$flag:ident,)?
+        $(@$from_str_flag:ident,)?
         $enum_name:ident,
         $(($variant:ident $(= $value:literal)?, $name:expr, $long_gloss:expr),)+
     ) => {
         custom_category!(
             $(#$flag,)?
+            $(@$from_str_flag,)?
             $enum_name,
             $((
                 $variant $(= $value)?,
@@ -195,7 +288,7 @@ category!(
     Context,
     (EXS, "Existential", "existential"),
     (FNC, "Functional", "functional"),
-    (RPS, "Represenational", "represenational"),
+    (RPS, "Representational", "representational"),
     (AMG, "Amalgamative", "amalgamative"),
 );
 
@@ -827,8 +920,10 @@ category!(
     (END, "End", "end"),
 );
 
+// `Bias` implements `FromStr` using its Cb forms instead of its abbreviations.
 category!(
     #no_default,
+    @no_from_str,
     Bias,
     (ACC, "Accidental", "accidental"),
     (ACH, "Archetypal", "archetypal"),
@@ -941,23 +1036,14 @@ pub use register::*;
 
 use super::Ca;
 
-/// Represents a AffixShortcut value.
-#[repr(u8)]
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub enum AffixShortcut {
-    #[default]
+unglossed_category!(
+    AffixShortcut,
     /// An absence of an affix shortcut.
-    None,
-
-    /// The NEG4 (NEG/4) AffixShortcut.
-    NEG4,
-
-    /// The DCD4 (DCD/4) AffixShortcut.
-    DCD4,
-
-    /// The DCD5 (DCD/5) AffixShortcut.
-    DCD5,
-}
+    (None, "None", "None"),
+    (NEG4, "NEG/4", "NEG/4"),
+    (DCD4, "DCD/4", "DCD/4"),
+    (DCD5, "DCD/5", "DCD/5"),
+);
 
 impl GlossStatic for AffixShortcut {
     fn gloss_static(&self, flags: GlossFlags) -> &'static str {
@@ -989,57 +1075,17 @@ impl GlossStatic for AffixShortcut {
     }
 }
 
-impl Category for AffixShortcut {
-    fn abbr(self) -> &'static str {
-        match self {
-            Self::None => "None",
-            Self::NEG4 => "NEG/4",
-            Self::DCD4 => "DCD/4",
-            Self::DCD5 => "DCD/5",
-        }
-    }
-
-    fn name(self) -> &'static str {
-        match self {
-            Self::None => "None",
-            Self::NEG4 => "NEG/4",
-            Self::DCD4 => "DCD/4",
-            Self::DCD5 => "DCD/5",
-        }
-    }
-}
-
-#[repr(u8)]
-/// Represents a CaShortcut value.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub enum NormalCaShortcut {
-    /// The Default (Default) CaShortcut.
-    #[default]
-    Default,
-
-    /// The PRX (Proximal) CaShortcut.
-    PRX,
-
-    /// The G (Agglomerative) CaShortcut.
-    G,
-
-    /// The RPV (Representative) CaShortcut.
-    RPV,
-
-    /// The N (Nomic) CaShortcut.
-    N,
-
-    /// The A (Abstract) CaShortcut.
-    A,
-
-    /// The G_RPV (Agglomerative + Representative) CaShortcut.
-    #[allow(non_camel_case_types)]
-    G_RPV,
-
-    /// The PRX_RPV (Proximal + Representative) CaShortcut.
-    #[allow(non_camel_case_types)]
-    PRX_RPV,
-}
+unglossed_category!(
+    NormalCaShortcut,
+    (Default, "Default", "Default"),
+    (PRX, "PRX", "Proximal"),
+    (G, "G", "Agglomerative"),
+    (RPV, "RPV", "Representative"),
+    (N, "N", "Nomic"),
+    (A, "A", "Abstract"),
+    (G_RPV, "G/RPV", "Agglomerative + Representative"),
+    (PRX_RPV, "PRX/RPV", "Proximal + Representative"),
+);
 
 // We could implement `GlossStatic` for `CaShortcut`, but it'd have to handle four separate cases
 // (long + default), and it's easier to just implement `Gloss` and leave it to `Ca` to do it
@@ -1051,45 +1097,11 @@ impl Gloss for NormalCaShortcut {
     }
 }
 
-impl Category for NormalCaShortcut {
-    fn abbr(self) -> &'static str {
-        match self {
-            Self::Default => "Default",
-            Self::PRX => "PRX",
-            Self::G => "G",
-            Self::RPV => "RPV",
-            Self::N => "N",
-            Self::A => "A",
-            Self::G_RPV => "G/RPV",
-            Self::PRX_RPV => "PRX/RPV",
-        }
-    }
-
-    fn name(self) -> &'static str {
-        match self {
-            Self::Default => "Default",
-            Self::PRX => "Proximal",
-            Self::G => "Agglomerative",
-            Self::RPV => "Representative",
-            Self::N => "Nomic",
-            Self::A => "Abstract",
-            Self::G_RPV => "Agglomerative + Representative",
-            Self::PRX_RPV => "Proximal + Representative",
-        }
-    }
-}
-
-#[repr(u8)]
-/// Represents a ReferentialCaShortcut value.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub enum ReferentialCaShortcut {
-    /// The Default (Default) ReferentialCaShortcut.
-    #[default]
-    Default,
-
-    /// The PRX (Proximal) ReferentialCaShortcut.
-    PRX,
-}
+unglossed_category!(
+    ReferentialCaShortcut,
+    (Default, "Default", "Default"),
+    (PRX, "PRX", "Proximal"),
+);
 
 // We could implement `GlossStatic` for `ReferentialCaShortcut`, but it'd have to handle four
 // separate cases (long + default), and it's easier to just implement `Gloss` and leave it to `Ca`
@@ -1102,85 +1114,48 @@ impl Gloss for ReferentialCaShortcut {
     }
 }
 
-impl Category for ReferentialCaShortcut {
-    fn abbr(self) -> &'static str {
-        match self {
-            Self::Default => "Default",
-            Self::PRX => "PRX",
-        }
-    }
-
-    fn name(self) -> &'static str {
-        match self {
-            Self::Default => "Default",
-            Self::PRX => "Proximal",
-        }
-    }
-}
-
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-/// Represents a ArbitraryMoodOrCaseScope value.
-pub enum ArbitraryMoodOrCaseScope {
-    #[default]
-    /// The FAC_CCN (Factual/Natural) ArbitraryMoodOrCaseScope.
-    FAC_CCN,
-    /// The SUB_CCA (Subjunctive/Antecedent) ArbitraryMoodOrCaseScope.
-    SUB_CCA,
-    /// The ASM_CCS (Assumptive/Subaltern) ArbitraryMoodOrCaseScope.
-    ASM_CCS,
-    /// The SPC_CCQ (Speculative/Qualifier) ArbitraryMoodOrCaseScope.
-    SPC_CCQ,
-    /// The COU_CCP (Counterfactive/Precedent) ArbitraryMoodOrCaseScope.
-    COU_CCP,
-    /// The HYP_CCV (Hypothetical/Successive) ArbitraryMoodOrCaseScope.
-    HYP_CCV,
-}
-
-impl GlossStatic for ArbitraryMoodOrCaseScope {
-    fn gloss_static(&self, flags: GlossFlags) -> &'static str {
-        if flags.matches(GlossFlags::LONG) {
-            match self {
-                Self::FAC_CCN => "factual/natural",
-                Self::SUB_CCA => "subjunctive/antecedent",
-                Self::ASM_CCS => "assumptive/subaltern",
-                Self::SPC_CCQ => "speculative/qualifier",
-                Self::COU_CCP => "counterfactive/precedent",
-                Self::HYP_CCV => "hypothetical/successive",
-            }
-        } else {
-            match self {
-                Self::FAC_CCN => "FAC/CCN",
-                Self::SUB_CCA => "SUB/CCA",
-                Self::ASM_CCS => "ASM/CCS",
-                Self::SPC_CCQ => "SPC/CCQ",
-                Self::COU_CCP => "COU/CCP",
-                Self::HYP_CCV => "HYP/CCV",
-            }
-        }
-    }
-}
-
-impl Category for ArbitraryMoodOrCaseScope {
-    fn abbr(self) -> &'static str {
-        match self {
-            Self::FAC_CCN => "FAC_CCN",
-            Self::SUB_CCA => "SUB_CCA",
-            Self::ASM_CCS => "ASM_CCS",
-            Self::SPC_CCQ => "SPC_CCQ",
-            Self::COU_CCP => "COU_CCP",
-            Self::HYP_CCV => "HYP_CCV",
-        }
-    }
-    fn name(self) -> &'static str {
-        match self {
-            Self::FAC_CCN => "Factual/Natural",
-            Self::SUB_CCA => "Subjunctive/Antecedent",
-            Self::ASM_CCS => "Assumptive/Subaltern",
-            Self::SPC_CCQ => "Speculative/Qualifier",
-            Self::COU_CCP => "Counterfactive/Precedent",
-            Self::HYP_CCV => "Hypothetical/Successive",
-        }
-    }
-}
+custom_category!(
+    ArbitraryMoodOrCaseScope,
+    (
+        FAC_CCN,
+        "FAC_CCN",
+        "Factual/Natural",
+        "FAC/CCN",
+        "factual/natural"
+    ),
+    (
+        SUB_CCA,
+        "SUB_CCA",
+        "Subjunctive/Antecedent",
+        "SUB/CCA",
+        "subjunctive/antecedent"
+    ),
+    (
+        ASM_CCS,
+        "ASM_CCS",
+        "Assumptive/Subaltern",
+        "ASM/CCS",
+        "assumptive/subaltern"
+    ),
+    (
+        SPC_CCQ,
+        "SPC_CCQ",
+        "Speculative/Qualifier",
+        "SPC/CCQ",
+        "speculative/qualifier"
+    ),
+    (
+        COU_CCP,
+        "COU_CCP",
+        "Counterfactive/Precedent",
+        "COU/CCP",
+        "counterfactive/precedent"
+    ),
+    (
+        HYP_CCV,
+        "HYP_CCV",
+        "Hypothetical/Successive",
+        "HYP/CCV",
+        "hypothetical/successive"
+    ),
+);
@@ -9,6 +9,8 @@ pub use complexes::*;
 pub use conversions::*;
 pub use items::*;
 
+use std::{error::Error, fmt};
+
 /// Marks types as grammatical categories.
 pub trait Category {
     /// Gets the abbreviation representing this item.
@@ -16,4 +18,21 @@ pub trait Category {
 
     /// Gets the full name of this item.
     fn name(self) -> &'static str;
+
+    /// Gets every item in this category, in declaration order.
+    fn all_variants() -> &'static [Self]
+    where
+        Self: Sized;
+}
+
+/// An error returned when a category cannot be parsed because its abbreviation is invalid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseCategoryError;
+
+impl fmt::Display for ParseCategoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a valid category abbreviation")
+    }
 }
+
+impl Error for ParseCategoryError {}
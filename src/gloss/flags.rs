@@ -2,7 +2,7 @@ use crate::macros::bitflags;
 
 /// A bit flag of options passed to glossing functions.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct GlossFlags(u8);
+pub struct GlossFlags(u16);
 
 impl GlossFlags {
     /// A [`GlossFlags`] instance with no flags active.
@@ -61,6 +61,67 @@ impl GlossFlags {
     ///
     /// If passed to `.gloss()`, this will replace roots with their values as specified in the lexicon-json repository, found at https://github.com/yuorb/lexicon-json.
     pub const USE_COMBINED_ROOT_DATA: Self = Self(1 << 5 | 1 << 6);
+
+    /// A [`GlossFlags`] instance with only the `no_relation_suffix` flag enabled.
+    ///
+    /// If passed to `.gloss()`, formatives will omit the `\UNF` and `\FRM` markers which are
+    /// normally placed at the end of their glosses, even if `SHOW_DEFAULTS` is also passed.
+    pub const NO_RELATION_SUFFIX: Self = Self(1 << 7);
+
+    /// A [`GlossFlags`] instance with only the `show_affix_cs` flag enabled.
+    ///
+    /// If passed to `.gloss()`, plain affixes will have their Cs forms appended to their glosses in
+    /// square brackets. For example, a degree 1 Type-2 affix with Cs form `r` is glossed as
+    /// `r/1₂[r]` instead of `r/1₂`. Plain affixes are currently glossed by their Cs forms anyway,
+    /// but the suffix keeps each form visible once they are glossed by their meanings. Numeric
+    /// affixes are unaffected, as their glosses already show the numbers they are written with.
+    pub const SHOW_AFFIX_CS: Self = Self(1 << 8);
+
+    /// A [`GlossFlags`] instance with only the `explicit_monadic` flag enabled.
+    ///
+    /// If passed to `.gloss()`, referent lists will always include their perspective and
+    /// referentials will always include their essence, even if they are the default monadic
+    /// perspective and normal essence. For example, glossing the word `la` with this flag returns
+    /// `"[1m+M]-NRM"`. Unlike `SHOW_DEFAULTS`, other default values are still elided.
+    pub const EXPLICIT_MONADIC: Self = Self(1 << 9);
+
+    /// A [`GlossFlags`] instance with only the `show_slots` flag enabled.
+    ///
+    /// If passed to `.gloss()`, formatives will wrap each non-empty slot in a label with its Roman
+    /// slot numeral instead of separating slots with dashes. For example, glossing the word
+    /// `hlamröé` with this flag returns `"[I:T1][II:S1][III:mr][IX:PCR]"`. Other words
+    /// are unaffected.
+    pub const SHOW_SLOTS: Self = Self(1 << 10);
+
+    /// A [`GlossFlags`] instance with only the `escape` flag enabled.
+    ///
+    /// If passed to `.gloss()`, free-form text taken from a word, such as the Cr form of a root or
+    /// the Cs form of an affix, is escaped with [`escape_gloss_text`][super::escape_gloss_text]
+    /// so that it can never be mistaken for the `-`, `.`, `\`, `/`, `+`, `:`, `[]`, `{}`, or `()`
+    /// which structure a gloss. A program splitting the gloss on those characters can then
+    /// recover each piece of text with [`unescape_gloss_text`][super::unescape_gloss_text].
+    /// Category abbreviations never contain escaped characters, so they are unaffected.
+    pub const ESCAPE: Self = Self(1 << 11);
+
+    /// A [`GlossFlags`] instance with only the `always_show_case` flag enabled.
+    ///
+    /// If passed to `.gloss()`, nominal formatives will always gloss their case, even if it is the
+    /// default THM case. For example, glossing the word `lala` with this flag returns `"S1-l-THM"`.
+    /// Verbal formatives always gloss their illocution and validation anyway, so every formative
+    /// gloss then has a slot IX. Unlike `SHOW_DEFAULTS`, other default values are still elided.
+    pub const ALWAYS_SHOW_CASE: Self = Self(1 << 12);
+
+    /// Returns the flags matching the gloss style of the New Ithkuil reference grammar.
+    ///
+    /// This sets `SHOW_DEFAULTS` and `NO_RELATION_SUFFIX`, so every category is glossed with its
+    /// short abbreviation, including default values, perspectives, and essences, but formatives
+    /// omit the `\UNF` and `\FRM` relation markers. For example, the word `la` is glossed as
+    /// `"[1m.NEU+M]-THM-NRM"`, and the word `malá` as
+    /// `"S1.PRC-m-STA.BSC.EXS-CSL.UPX.DEL.M.NRM-MNO.FAC-OBS"`. For the shortest glosses instead, use
+    /// [`GlossFlags::NONE`].
+    pub const fn reference_style() -> Self {
+        Self(Self::SHOW_DEFAULTS.0 | Self::NO_RELATION_SUFFIX.0)
+    }
 }
 
 bitflags!(GlossFlags);
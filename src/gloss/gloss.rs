@@ -90,3 +90,33 @@ impl<T: GlossStatic> Gloss for T {
         self.gloss_static_non_default(flags).to_owned()
     }
 }
+
+/// A single fragment of a gloss, as yielded by
+/// [`UncheckedFormative::gloss_iter`][crate::word::UncheckedFormative::gloss_iter].
+///
+/// Concatenating the `separator` and `text` of every token in order produces the full gloss, so a
+/// sequence of tokens may be collected directly into a [`String`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlossToken {
+    /// The slot this fragment glosses, as an uppercase Roman numeral (e.g. `"III"`).
+    pub slot: &'static str,
+
+    /// The separator written before this fragment, which is empty for the first fragment.
+    pub separator: &'static str,
+
+    /// The glossed contents of the slot.
+    pub text: String,
+}
+
+impl FromIterator<GlossToken> for String {
+    fn from_iter<I: IntoIterator<Item = GlossToken>>(iter: I) -> Self {
+        let mut output = String::new();
+
+        for token in iter {
+            output += token.separator;
+            output += &token.text;
+        }
+
+        output
+    }
+}
@@ -1,4 +1,8 @@
+use super::{GlossFlags, GlossStatic};
+use crate::category::*;
+use once_cell::sync::Lazy;
 use sealed::Sealed;
+use std::{borrow::Cow, collections::HashMap, hash::Hash};
 
 mod sealed {
     pub trait Sealed {}
@@ -39,3 +43,282 @@ impl GlossHelpers for String {
         *self += value;
     }
 }
+
+/// The characters escaped by [`escape_gloss_text`]: every character used to structure a gloss,
+/// plus `%`, which begins an escape sequence.
+const ESCAPED_CHARACTERS: &str = "%-.\\/+:[]{}()";
+
+/// Escapes free-form text so that it can be embedded in a gloss without being confused with the
+/// gloss's structure.
+///
+/// Each of `%`, `-`, `.`, `\`, `/`, `+`, `:`, `[`, `]`, `{`, `}`, `(`, and `)` is replaced by `%`
+/// followed by its ASCII code as two uppercase hexadecimal digits, so `-` becomes `%2D` and `%`
+/// becomes `%25`. Every other character is left as is. This is the scheme used by
+/// [`GlossFlags::ESCAPE`], and is reversed by [`unescape_gloss_text`].
+///
+/// # Examples
+///
+/// ```
+/// use tnil::gloss::{escape_gloss_text, unescape_gloss_text};
+///
+/// assert_eq!(escape_gloss_text("kš"), "kš");
+/// assert_eq!(escape_gloss_text("a-b.c%"), "a%2Db%2Ec%25");
+/// assert_eq!(unescape_gloss_text("a%2Db%2Ec%25").as_deref(), Some("a-b.c%"));
+/// ```
+pub fn escape_gloss_text(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        if ESCAPED_CHARACTERS.contains(char) {
+            output += &format!("%{:02X}", char as u32);
+        } else {
+            output.push(char);
+        }
+    }
+
+    output
+}
+
+/// Reverses [`escape_gloss_text`].
+///
+/// Returns [`None`] if `text` contains a `%` which is not followed by two hexadecimal digits
+/// encoding one of the characters escaped by [`escape_gloss_text`].
+pub fn unescape_gloss_text(text: &str) -> Option<String> {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(char) = chars.next() {
+        if char != '%' {
+            output.push(char);
+            continue;
+        }
+
+        let high = chars.next()?.to_digit(16)?;
+        let low = chars.next()?.to_digit(16)?;
+        let escaped = char::from_u32(high * 16 + low)?;
+
+        if !ESCAPED_CHARACTERS.contains(escaped) {
+            return None;
+        }
+
+        output.push(escaped);
+    }
+
+    Some(output)
+}
+
+/// Escapes `text` with [`escape_gloss_text`] if `flags` contains [`GlossFlags::ESCAPE`].
+pub(crate) fn escape_if_requested(text: &str, flags: GlossFlags) -> Cow<'_, str> {
+    if flags.matches(GlossFlags::ESCAPE) {
+        Cow::Owned(escape_gloss_text(text))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// The characters which separate the pieces of a gloss, as understood by [`normalize`].
+const SEPARATORS: &str = "-.\\/+:[]{}()‘’“” ";
+
+/// The pieces of a gloss which [`normalize`] recognizes as category items, each mapped to the
+/// item's short gloss. Pieces which would map to more than one short gloss are left out, as are
+/// glosses containing separators, which can't be a single piece of a gloss, and single characters,
+/// which are more likely to be roots than categories.
+struct CategoryPieces {
+    /// Abbreviations and short glosses, which only match with their exact case so that roots and
+    /// affixes which happen to spell one in lowercase, such as `prs` or `clm`, are left alone.
+    abbreviations: HashMap<&'static str, Option<&'static str>>,
+
+    /// Lowercased long glosses, which match regardless of case.
+    long_glosses: HashMap<String, Option<&'static str>>,
+}
+
+static CATEGORY_PIECES: Lazy<CategoryPieces> = Lazy::new(|| {
+    let mut abbreviations = HashMap::new();
+    let mut long_glosses = HashMap::new();
+
+    fn insert<K: Eq + Hash>(
+        pieces: &mut HashMap<K, Option<&'static str>>,
+        key: K,
+        short: &'static str,
+    ) {
+        pieces
+            .entry(key)
+            .and_modify(|value| {
+                if *value != Some(short) {
+                    *value = None;
+                }
+            })
+            .or_insert(Some(short));
+    }
+
+    fn is_piece(key: &str) -> bool {
+        key.chars().count() >= 2 && !key.contains(|char| SEPARATORS.contains(char))
+    }
+
+    macro_rules! add {
+        ($($category:ident,)+) => {
+            $(for item in <$category as Category>::all_variants() {
+                let short = item.gloss_static(GlossFlags::NONE);
+                let long = item.gloss_static(GlossFlags::LONG);
+
+                if !short.chars().any(char::is_alphabetic)
+                    || short.contains(|char| SEPARATORS.contains(char))
+                {
+                    continue;
+                }
+
+                for key in [item.abbr(), short] {
+                    if is_piece(key) {
+                        insert(&mut abbreviations, key, short);
+                    }
+                }
+
+                if is_piece(long) {
+                    insert(&mut long_glosses, long.to_lowercase(), short);
+                }
+            })+
+        };
+    }
+
+    add!(
+        NominalMode,
+        DatalessRelation,
+        Version,
+        Stem,
+        Function,
+        Specification,
+        Context,
+        AffixType,
+        AffixDegree,
+        VowelFormSequence,
+        VowelFormDegree,
+        HFormSequence,
+        HFormDegree,
+        Stress,
+        Affiliation,
+        Configuration,
+        Plexity,
+        Similarity,
+        Separability,
+        Extension,
+        Perspective,
+        ReferentialAffixPerspective,
+        Essence,
+        Valence,
+        Phase,
+        Effect,
+        Level,
+        Aspect,
+        Sanction,
+        Mood,
+        NonDefaultMood,
+        CaseScope,
+        NonDefaultCaseScope,
+        ReferentTarget,
+        ReferentEffect,
+        Illocution,
+        Validation,
+        IllocutionOrValidation,
+        ThematicCase,
+        AppositiveCase,
+        Case,
+        CaseAccessorMode,
+        ModularAdjunctMode,
+        AffixualAdjunctScope,
+        AffixualAdjunctMode,
+        ModularAdjunctScope,
+        SuppletiveAdjunctMode,
+        RegisterCategory,
+        Bias,
+        ShortcutType,
+        NormalAffixSlot,
+        AffixSlot,
+        RegisterType,
+        ArbitraryMoodOrCaseScope,
+    );
+
+    CategoryPieces {
+        abbreviations,
+        long_glosses,
+    }
+});
+
+/// Canonicalizes a gloss so that glosses which differ only in formatting compare equal.
+///
+/// The gloss is split into pieces at the characters which structure it (`-`, `.`, `\`, `/`, `+`,
+/// `:`, brackets, quotation marks, and spaces), and then:
+///
+/// - each piece which is the abbreviation or short gloss of a category item, with the same case, or
+///   its long gloss, ignoring case, is replaced by the item's short gloss, so `THM`, `thematic`,
+///   and `Thematic` all become `THM`. Abbreviations must match exactly, so roots and affixes which
+///   spell one in lowercase, such as the root `prs`, are left as is, as are pieces shared by items
+///   with different short glosses, such as `type_one`, and pieces shorter than two characters;
+/// - en dashes, em dashes, hyphens, and minus signs become `-`, and middle dots become `.`;
+/// - runs of whitespace become a single space, repeated `-` and `.` separators are collapsed,
+///   and leading or trailing whitespace, `-`, and `.` are removed.
+///
+/// Default values which one gloss shows and the other elides can't be recovered, so glosses
+/// should be generated with the same [`GlossFlags`] regarding defaults before being compared.
+///
+/// # Examples
+///
+/// ```
+/// use tnil::gloss::normalize;
+///
+/// assert_eq!(normalize("S1-l-Thematic"), "S1-l-THM");
+/// assert_eq!(normalize("stem_one–l—thematic"), "S1-l-THM");
+/// assert_eq!(normalize("S1-prs"), "S1-prs");
+/// assert_eq!(normalize(" S1--l..DPX "), normalize("S1-l.DPX"));
+/// ```
+pub fn normalize(gloss: &str) -> String {
+    let mut output = String::with_capacity(gloss.len());
+    let mut piece = String::new();
+
+    let flush = |output: &mut String, piece: &mut String| {
+        let short = match CATEGORY_PIECES.abbreviations.get(piece.as_str()) {
+            Some(short) => *short,
+            None => CATEGORY_PIECES
+                .long_glosses
+                .get(&piece.to_lowercase())
+                .copied()
+                .flatten(),
+        };
+
+        match short {
+            Some(short) => *output += short,
+            None => *output += piece,
+        }
+        piece.clear();
+    };
+
+    for char in gloss.trim().chars() {
+        let char = match char {
+            '–' | '—' | '‐' | '‑' | '−' => '-',
+            '·' | '•' => '.',
+            char if char.is_whitespace() => ' ',
+            char => char,
+        };
+
+        if !SEPARATORS.contains(char) {
+            piece.push(char);
+            continue;
+        }
+
+        flush(&mut output, &mut piece);
+
+        let is_collapsible = matches!(char, '-' | '.' | ' ');
+        if is_collapsible && (output.is_empty() || output.ends_with(char)) {
+            continue;
+        }
+
+        output.push(char);
+    }
+
+    flush(&mut output, &mut piece);
+
+    while output.ends_with(['-', '.', ' ']) {
+        output.pop();
+    }
+
+    output
+}
@@ -24,6 +24,7 @@
 #![deny(unsafe_code)]
 
 pub mod affix;
+pub mod analysis;
 pub mod category;
 pub mod gloss;
 mod macros;
@@ -32,3 +33,5 @@ pub mod romanize;
 pub mod script;
 pub mod specificity;
 pub mod word;
+
+pub use analysis::analyze;
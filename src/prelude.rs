@@ -15,6 +15,6 @@ pub use crate::{
         flags::IntoScriptFlags,
         traits::{IntoScript, ScriptRepr},
     },
-    specificity::{AsGeneral, AsSpecific, TryAsGeneral, TryAsSpecific},
-    word::{self, Word},
+    specificity::{AsGeneral, AsSpecific, Cast, CastInto, TryAsGeneral, TryAsSpecific},
+    word::{self, Word, WordKind},
 };
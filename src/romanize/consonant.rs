@@ -35,7 +35,7 @@ macro_rules! matches_any {
 ///
 /// This type is unsized, and thus can only be used behind some kind of pointer.
 #[repr(transparent)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Hash, PartialEq, Eq)]
 pub struct ConsonantForm(str);
 
 impl From<&str> for &ConsonantForm {
@@ -534,9 +534,48 @@ impl ConsonantForm {
             ),
 
             // 4.5. penta-consonantal conjuncts are only okay if they're geminated version of the tetra-consonantal conjuncts above
-            5 => ConsonantForm::new(&self.remove_geminates()).is_valid_word_final(),
+            5 if self.is_geminate() => {
+                ConsonantForm::new(&self.remove_geminates()).is_valid_word_final()
+            }
 
             _ => false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ConsonantForm, OwnedConsonantForm};
+    use std::{
+        borrow::Borrow,
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+    };
+
+    fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn borrowed_and_owned_forms_agree() {
+        let forms = ["", "l", "rr", "sml", "ţḑ", "ňš", "lw"];
+        let set: HashSet<OwnedConsonantForm> = forms
+            .iter()
+            .map(|form| OwnedConsonantForm(form.to_string()))
+            .collect();
+
+        for form in forms {
+            let owned = OwnedConsonantForm(form.to_owned());
+            let borrowed: &ConsonantForm = owned.borrow();
+
+            // `Borrow` requires that both forms hash and compare the same way.
+            assert_eq!(hash(&owned), hash(borrowed), "{form:?}");
+            assert_eq!(borrowed, ConsonantForm::new(form), "{form:?}");
+            assert!(set.contains(ConsonantForm::new(form)), "{form:?}");
+        }
+
+        assert!(!set.contains(ConsonantForm::new("ll")));
+    }
+}
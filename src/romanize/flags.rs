@@ -15,6 +15,22 @@ impl FromTokenFlags {
     /// If passed to `.parse()`, objects will let through many errors and parse words to the best of
     /// their intent, even if the word isn't actually valid.
     pub const PERMISSIVE: Self = Self(1 << 0);
+
+    /// A [`FromTokenFlags`] instance with only the `accept_alternates` flag enabled.
+    ///
+    /// If passed to `.parse_str()`, the source string will have alternate glottal stop notations
+    /// (such as \` or ʻ) replaced with apostrophes before it is tokenized. The output of
+    /// serialization functions is unaffected and always uses the canonical notation.
+    pub const ACCEPT_ALTERNATES: Self = Self(1 << 1);
+
+    /// A [`FromTokenFlags`] instance with only the `allow_bare_root` flag enabled.
+    ///
+    /// If passed to `.parse()`, formatives which end right after their Vr slot (such as `ma` or
+    /// `aima`, as often written in dictionaries) will be accepted instead of being rejected as
+    /// truncated. Such a formative is given a default Ca and a THM case (or an OBS validation if
+    /// it has ultimate stress), and is treated as nominal if it is monosyllabic. Words which parse
+    /// without this flag are parsed the same way with it.
+    pub const ALLOW_BARE_ROOT: Self = Self(1 << 2);
 }
 
 bitflags!(FromTokenFlags);
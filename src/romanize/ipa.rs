@@ -0,0 +1,179 @@
+//! Converts romanized words into broad IPA transcriptions.
+//!
+//! The transcription is phonemic rather than phonetic: each letter is mapped to its primary value
+//! as given in the New Ithkuil phonology, so allophones such as \[ɛ\] for ⟨e⟩ or \[ɔ\] for ⟨o⟩
+//! are not produced. Diphthongs are written with a non-syllabic diacritic on their second element,
+//! and the stressed syllable of a polysyllabic word is preceded by the IPA primary-stress mark ⟨ˈ⟩.
+
+use super::{token_list::TokenList, transform::tokens_to_string};
+use crate::category::Stress;
+
+/// A segment of a word.
+enum Segment {
+    /// A vowel or diphthong, which forms the nucleus of a syllable.
+    Nucleus(String),
+
+    /// A consonant.
+    Consonant(&'static str),
+
+    /// A character not in the romanization alphabet, such as a numeral digit.
+    Other(char),
+}
+
+/// Gets the IPA transcription of a consonant, or [`None`] if the character isn't a consonant.
+fn consonant_to_ipa(char: char) -> Option<&'static str> {
+    Some(match char {
+        'p' => "p",
+        'b' => "b",
+        't' => "t",
+        'd' => "d",
+        'k' => "k",
+        'g' => "g",
+        '\'' => "ʔ",
+        'f' => "f",
+        'v' => "v",
+        'ţ' => "θ",
+        'ḑ' => "ð",
+        's' => "s",
+        'z' => "z",
+        'š' => "ʃ",
+        'ž' => "ʒ",
+        'ç' => "ç",
+        'x' => "x",
+        'h' => "h",
+        'ļ' => "ɬ",
+        'c' => "t͡s",
+        'ẓ' => "d͡z",
+        'č' => "t͡ʃ",
+        'j' => "d͡ʒ",
+        'm' => "m",
+        'n' => "n",
+        'ň' => "ŋ",
+        'r' => "ɾ",
+        'ř' => "ʁ",
+        'l' => "l",
+        'w' => "w",
+        'y' => "j",
+        _ => return None,
+    })
+}
+
+/// Gets the IPA transcription of a vowel, or [`None`] if the character isn't a vowel.
+fn vowel_to_ipa(char: char) -> Option<&'static str> {
+    Some(match char {
+        'a' => "a",
+        'ä' => "æ",
+        'e' => "e",
+        'ë' => "ə",
+        'i' => "i",
+        'o' => "o",
+        'ö' => "ø",
+        'u' => "u",
+        'ü' => "y",
+        _ => return None,
+    })
+}
+
+/// Splits an unstressed romanized word into segments.
+fn segments(word: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut chars = word.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        if let Some(vowel) = vowel_to_ipa(char) {
+            let mut nucleus = vowel.to_owned();
+
+            // Diphthongs are formed by a, e, ë, i, o, or u followed by a different i or u.
+            if matches!(char, 'a' | 'e' | 'ë' | 'i' | 'o' | 'u') {
+                if let Some(next) = chars.next_if(|&next| matches!(next, 'i' | 'u') && next != char)
+                {
+                    nucleus.push(next);
+                    nucleus.push('\u{32F}');
+                }
+            }
+
+            segments.push(Segment::Nucleus(nucleus));
+        } else if let Some(consonant) = consonant_to_ipa(char) {
+            segments.push(Segment::Consonant(consonant));
+        } else {
+            segments.push(Segment::Other(char));
+        }
+    }
+
+    segments
+}
+
+/// Converts a [`TokenList`] into a broad IPA transcription.
+///
+/// If the list has no stress marked, penultimate stress is assumed. Monosyllabic words are not
+/// marked for stress.
+pub fn to_ipa(token_list: &TokenList) -> String {
+    let segments = segments(&tokens_to_string(&token_list.tokens));
+
+    let nuclei: Vec<usize> = segments
+        .iter()
+        .enumerate()
+        .filter(|(_, segment)| matches!(segment, Segment::Nucleus(_)))
+        .map(|(index, _)| index)
+        .collect();
+
+    let from_end = match token_list.stress.unwrap_or(Stress::Penultimate) {
+        Stress::Monosyllabic | Stress::Ultimate => 1,
+        Stress::Penultimate => 2,
+        Stress::Antepenultimate => 3,
+    };
+
+    // The stress mark goes before the onset of the stressed syllable, which is the last consonant
+    // preceding its nucleus, or the start of the word if it is the first syllable.
+    let stress_mark_index = if nuclei.len() > 1 {
+        let nucleus_index = nuclei.len().saturating_sub(from_end);
+        let nucleus = nuclei[nucleus_index];
+
+        Some(if nucleus_index == 0 {
+            0
+        } else if matches!(segments[nucleus - 1], Segment::Consonant(_)) {
+            nucleus - 1
+        } else {
+            nucleus
+        })
+    } else {
+        None
+    };
+
+    let mut output = String::new();
+
+    for (index, segment) in segments.iter().enumerate() {
+        if stress_mark_index == Some(index) {
+            output.push('ˈ');
+        }
+
+        match segment {
+            Segment::Nucleus(nucleus) => output += nucleus,
+            Segment::Consonant(consonant) => output += consonant,
+            Segment::Other(char) => output.push(*char),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_ipa;
+    use crate::romanize::token_list::TokenList;
+
+    fn check(word: &str, expected: &str) {
+        let list: TokenList = word.parse().expect("word should be valid");
+        assert_eq!(to_ipa(&list), expected, "source was {word:?}");
+    }
+
+    #[test]
+    fn ipa() {
+        check("malëuţřait", "maˈləu̯θʁai̯t");
+        check("wežřäl", "ˈweʒʁæl");
+        check("lalá", "laˈla");
+        check("ákšula", "ˈakʃula");
+        check("çaļļ", "çaɬɬ");
+        check("ẓaca'jiň", "d͡zaˈt͡saʔd͡ʒiŋ");
+    }
+}
@@ -2,6 +2,7 @@
 
 pub(self) mod consonant;
 pub mod flags;
+pub mod ipa;
 pub mod segment;
 pub mod stream;
 pub mod token;
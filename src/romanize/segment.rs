@@ -218,25 +218,10 @@ impl IntoVowelForm for Case {
 
         VowelForm {
             has_glottal_stop: value >= 36,
-            sequence: match value / 9 {
-                0 | 4 => VowelFormSequence::S1,
-                1 | 5 => VowelFormSequence::S2,
-                2 | 6 => VowelFormSequence::S3,
-                3 | 7 => VowelFormSequence::S4,
-                _ => unreachable!(),
-            },
-            degree: match value % 9 {
-                0 => VowelFormDegree::D1,
-                1 => VowelFormDegree::D2,
-                2 => VowelFormDegree::D3,
-                3 => VowelFormDegree::D4,
-                4 => VowelFormDegree::D5,
-                5 => VowelFormDegree::D6,
-                6 => VowelFormDegree::D7,
-                7 => VowelFormDegree::D8,
-                8 => VowelFormDegree::D9,
-                _ => unreachable!(),
-            },
+            sequence: VowelFormSequence::try_from(value % 36 / 9 + 1)
+                .expect("cases should have at most 72 variants"),
+            degree: VowelFormDegree::try_from(value % 9 + 1)
+                .expect("a value modulo 9 plus 1 should be a valid degree"),
         }
     }
 }
@@ -498,26 +483,16 @@ impl IntoVowelForm for NonAspectualVn {
     fn into_vowel_form(self) -> VowelForm {
         let (sequence, degree) = match self {
             NonAspectualVn::Valence(value) => (VowelFormSequence::S1, value as u8),
-            NonAspectualVn::Phase(value) => (VowelFormSequence::S1, value as u8),
-            NonAspectualVn::Effect(value) => (VowelFormSequence::S1, value as u8),
-            NonAspectualVn::Level(value) => (VowelFormSequence::S1, value as u8),
+            NonAspectualVn::Phase(value) => (VowelFormSequence::S2, value as u8),
+            NonAspectualVn::Effect(value) => (VowelFormSequence::S3, value as u8),
+            NonAspectualVn::Level(value) => (VowelFormSequence::S4, value as u8),
         };
 
         VowelForm {
             has_glottal_stop: false,
             sequence,
-            degree: match degree {
-                0 => VowelFormDegree::D1,
-                1 => VowelFormDegree::D2,
-                2 => VowelFormDegree::D3,
-                3 => VowelFormDegree::D4,
-                4 => VowelFormDegree::D5,
-                5 => VowelFormDegree::D6,
-                6 => VowelFormDegree::D7,
-                7 => VowelFormDegree::D8,
-                8 => VowelFormDegree::D9,
-                _ => unreachable!(),
-            },
+            degree: VowelFormDegree::try_from(degree + 1)
+                .expect("non-aspectual Vn categories should have at most 9 variants"),
         }
     }
 }
@@ -538,25 +513,10 @@ impl IntoVowelForm for Aspect {
 
         VowelForm {
             has_glottal_stop: false,
-            sequence: match value / 9 {
-                0 => VowelFormSequence::S1,
-                1 => VowelFormSequence::S2,
-                2 => VowelFormSequence::S3,
-                3 => VowelFormSequence::S4,
-                _ => unreachable!(),
-            },
-            degree: match value % 9 {
-                0 => VowelFormDegree::D1,
-                1 => VowelFormDegree::D2,
-                2 => VowelFormDegree::D3,
-                3 => VowelFormDegree::D4,
-                4 => VowelFormDegree::D5,
-                5 => VowelFormDegree::D6,
-                6 => VowelFormDegree::D7,
-                7 => VowelFormDegree::D8,
-                8 => VowelFormDegree::D9,
-                _ => unreachable!(),
-            },
+            sequence: VowelFormSequence::try_from(value / 9 + 1)
+                .expect("aspects should have at most 36 variants"),
+            degree: VowelFormDegree::try_from(value % 9 + 1)
+                .expect("a value modulo 9 plus 1 should be a valid degree"),
         }
     }
 }
@@ -689,6 +649,14 @@ impl FromTokens for Cm {
 }
 
 /// A VnCn pair.
+///
+/// Every Vn form is ambiguous between a non-aspectual Vn (a valence, phase, effect, or level) and
+/// an aspect. The adjacent Cn resolves the ambiguity: a Cn from the plain series (`h`, `hl`, `hr`,
+/// `hm`, `hn`, `hň`) marks the Vn as non-aspectual, and a Cn from the `w`/`y` series (`w`/`y`,
+/// `hw`, `hrw`, `hmw`, `hnw`, `hňw`) marks it as an aspect. Every combination of Vn and
+/// mood/case-scope is therefore representable, but the H-form sequence written for the Cn depends
+/// on whether the Vn is an aspect. Use [`VnCn::cn_form`] to get the Cn form that will actually be
+/// written.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct VnCn {
     /// The Vn of this pair.
@@ -698,6 +666,28 @@ pub struct VnCn {
     pub cn: ArbitraryMoodOrCaseScope,
 }
 
+impl VnCn {
+    /// Creates a new [`VnCn`] from a Vn and a mood/case-scope.
+    pub const fn new(vn: Vn, cn: ArbitraryMoodOrCaseScope) -> Self {
+        Self { vn, cn }
+    }
+
+    /// Gets the Cn form of this pair, which is marked as aspectual exactly when the Vn is an
+    /// aspect.
+    pub const fn cn_form(self) -> Cn {
+        Cn {
+            mcs: self.cn,
+            is_aspect: self.vn.is_aspect(),
+        }
+    }
+
+    /// Checks whether a parsed Cn form agrees with this pair, i.e. whether it has the same
+    /// mood/case-scope and marks the Vn as aspectual exactly when this pair's Vn is an aspect.
+    pub fn is_consistent_with(self, cn: Cn) -> bool {
+        cn == self.cn_form()
+    }
+}
+
 impl FromTokens for VnCn {
     fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
         let vn: VowelForm = stream.next().ok_or(ParseError::ExpectedVn)?;
@@ -718,22 +708,11 @@ impl FromTokens for VnCn {
 impl IntoTokens for VnCn {
     fn append_tokens_to(&self, list: &mut TokenList, _flags: IntoTokensFlags) {
         match self.vn.as_non_aspectual_vn() {
-            Ok(non_aspectual) => {
-                list.push(non_aspectual);
-
-                list.push(Cn {
-                    mcs: self.cn,
-                    is_aspect: false,
-                });
-            }
-            Err(aspect) => {
-                list.push(aspect);
-                list.push(Cn {
-                    mcs: self.cn,
-                    is_aspect: true,
-                });
-            }
+            Ok(non_aspectual) => list.push(non_aspectual),
+            Err(aspect) => list.push(aspect),
         }
+
+        list.push(self.cn_form());
     }
 }
 
@@ -24,6 +24,17 @@ pub struct TokenStream<'a> {
     pub(super) end: usize,
 }
 
+/// A saved position of a [`TokenStream`], created by [`TokenStream::checkpoint`] and restored by
+/// [`TokenStream::restore`].
+///
+/// A checkpoint only stores the cursor indices of its stream, so creating and restoring one never
+/// copies any tokens. A checkpoint should only be restored into the stream it was created from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    start: usize,
+    end: usize,
+}
+
 impl<'a> TokenStream<'a> {
     /// Checks if this [`TokenStream`] has no tokens left to parse.
     pub const fn is_done(&self) -> bool {
@@ -31,6 +42,11 @@ impl<'a> TokenStream<'a> {
     }
 
     /// Gets the stress of this [`TokenStream`].
+    ///
+    /// Stress is detected once, when a [`TokenList`][super::token_list::TokenList] is parsed from
+    /// a string, and copied into every stream created from it. This method only reads that stored
+    /// value and never rescans the tokens, so it is cheap to call repeatedly. Checkpoints,
+    /// truncation, and consuming tokens never change it.
     #[must_use]
     pub const fn stress(&self) -> Option<Stress> {
         self.stress
@@ -52,7 +68,7 @@ impl<'a> TokenStream<'a> {
     ///
     /// This method cannot be integrated into `.next()` due to issues with lifetimes.
     #[must_use]
-    pub fn next_cs(&mut self) -> Option<&ConsonantForm> {
+    pub fn next_cs(&mut self) -> Option<&'a ConsonantForm> {
         if self.is_done() {
             return None;
         }
@@ -81,7 +97,7 @@ impl<'a> TokenStream<'a> {
     ///
     /// This method cannot be integrated into `.next_back()` due to issues with lifetimes.
     #[must_use]
-    pub fn next_cs_back(&mut self) -> Option<&ConsonantForm> {
+    pub fn next_cs_back(&mut self) -> Option<&'a ConsonantForm> {
         if self.is_done() {
             return None;
         }
@@ -96,7 +112,7 @@ impl<'a> TokenStream<'a> {
 
     /// Returns the next token.
     #[must_use]
-    pub fn next_any(&mut self) -> Option<&Token> {
+    pub fn next_any(&mut self) -> Option<&'a Token> {
         if self.is_done() {
             return None;
         }
@@ -107,7 +123,7 @@ impl<'a> TokenStream<'a> {
 
     /// Returns the next token from the end.
     #[must_use]
-    pub fn next_back_any(&mut self) -> Option<&Token> {
+    pub fn next_back_any(&mut self) -> Option<&'a Token> {
         if self.is_done() {
             return None;
         }
@@ -118,7 +134,7 @@ impl<'a> TokenStream<'a> {
 
     /// Returns the next token without advancing the stream.
     #[must_use]
-    pub fn peek(&mut self) -> Option<&Token> {
+    pub fn peek(&mut self) -> Option<&'a Token> {
         if self.is_done() {
             return None;
         }
@@ -128,7 +144,7 @@ impl<'a> TokenStream<'a> {
 
     /// Returns the next token from the back without advancing the stream.
     #[must_use]
-    pub fn peek_back(&mut self) -> Option<&Token> {
+    pub fn peek_back(&mut self) -> Option<&'a Token> {
         if self.is_done() {
             return None;
         }
@@ -146,22 +162,19 @@ impl<'a> TokenStream<'a> {
     /// stream.
     #[must_use]
     pub fn parse_entire<T: FromTokens>(&mut self, flags: FromTokenFlags) -> Result<T, ParseError> {
-        let start = self.start;
-        let end = self.end;
+        let checkpoint = self.checkpoint();
 
         match T::parse_volatile(self, flags) {
             Ok(value) => {
                 if self.is_done() {
                     Ok(value)
                 } else {
-                    self.start = start;
-                    self.end = end;
+                    self.restore(checkpoint);
                     Err(ParseError::TooManyTokens)
                 }
             }
             Err(error) => {
-                self.start = start;
-                self.end = end;
+                self.restore(checkpoint);
                 Err(error)
             }
         }
@@ -172,6 +185,46 @@ impl<'a> TokenStream<'a> {
     pub fn remaining_tokens(&self) -> &'a [Token] {
         &self.tokens[self.start..self.end]
     }
+
+    /// Returns the number of tokens left to parse.
+    pub const fn remaining(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Returns the index of the next token from the front, relative to the start of the
+    /// underlying token list.
+    pub const fn position(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the index one past the next token from the back, relative to the start of the
+    /// underlying token list.
+    pub const fn position_back(&self) -> usize {
+        self.end
+    }
+
+    /// Limits this stream to its next `len` tokens, so that parsers reading from the back of the
+    /// stream treat the token after them as the end of the word. Does nothing if fewer than `len`
+    /// tokens remain.
+    ///
+    /// The limit can be removed by restoring a [`Checkpoint`] created beforehand.
+    pub fn truncate(&mut self, len: usize) {
+        self.end = self.end.min(self.start.saturating_add(len));
+    }
+
+    /// Saves the current position of this stream so it can be restored later.
+    pub const fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    /// Restores this stream to a position saved by [`TokenStream::checkpoint`].
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.start = checkpoint.start;
+        self.end = checkpoint.end;
+    }
 }
 
 macro_rules! parse_error_defn {
@@ -254,6 +307,12 @@ parse_error_defn!(match self {
     GlottalizedVx => "Vx forms cannot have glottal stops except in formatives",
 
     AffixualFormativeWithCaShortcut => "affixual formatives cannot have Ca shortcuts",
+    AffixualFormativeWithSpecification => "affixual formatives must have BSC specification",
+    SpecialFormativeWithAffixShortcut =>
+        "referential and affixual formatives cannot have affix shortcuts",
+    SpecialFormativeWithStem => "referential and affixual formatives must be in stem 1",
+    ReferentialFormativeWithCaShortcut =>
+        "referential formatives can only have the default and PRX Ca shortcuts",
     AspectualCnShortcut => "Cn shortcuts cannot indicate an aspectual Vn",
     AntepenultimateStress => "only unconcatenated formatives can have antepenultimate stress",
     ComboReferentialWithSchwa => "combination referential cannot have ë mid-word",
@@ -270,6 +329,11 @@ parse_error_defn!(match self {
     TooFewSlotVAffixes => "not enough slot V affixes (indicated by glottal stop in Vv)",
     TooManySlotVAffixes => "too many slot V affixes (indicated by absence of a glottal stop in Vv)",
     TooManyTokens => "expected end of word, found more tokens",
+    TooManyWords => "expected at most an affixual adjunct followed by a formative",
+    ExpectedSlotXiAdjunct =>
+        "expected an affixual adjunct with only formative scope before an extended formative",
+    ExpectedCarriedContent => "expected a word carried by a carrier or naming adjunct",
+    UnterminatedCarriedPhrase => "expected an END register adjunct after a carried phrase",
 
     WordEmpty => "cannot parse nothing",
     WordInitialGlottalStop => "words cannot begin with glottal stops not followed by vowels",
@@ -277,3 +341,38 @@ parse_error_defn!(match self {
 
     Unknown => "an unknown error occurred",
 });
+
+#[cfg(test)]
+mod tests {
+    use crate::{category::Case, romanize::token_list::TokenList};
+
+    #[test]
+    fn checkpoints() {
+        let list: TokenList = "malëuţřait".parse().expect("word should be valid");
+        let mut stream = list.stream();
+        assert_eq!(stream.remaining(), list.tokens.len());
+
+        let checkpoint = stream.checkpoint();
+        assert_eq!(stream.position(), 0);
+
+        let _ = stream.next_any();
+        let _ = stream.next_back_any();
+        assert_eq!(stream.position(), 1);
+        assert_eq!(stream.position_back(), list.tokens.len() - 1);
+        assert_eq!(stream.remaining(), list.tokens.len() - 2);
+
+        stream.restore(checkpoint);
+        assert_eq!(stream.position(), 0);
+        assert_eq!(stream.remaining_tokens(), &list.tokens[..]);
+
+        let _: Result<Case, _> = stream.parse(Default::default());
+        assert_eq!(stream.checkpoint(), checkpoint);
+
+        let stress = stream.stress();
+        stream.truncate(2);
+        let _ = stream.next_any();
+        assert_eq!(stream.stress(), stress);
+        stream.restore(checkpoint);
+        assert_eq!(stream.stress(), list.stress);
+    }
+}
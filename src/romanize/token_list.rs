@@ -1,15 +1,17 @@
 //! Defines a [`TokenList`] type which can be used to parse Ithkuil tokens and stress markings.
 
 use super::{
-    flags::IntoTokensFlags,
+    flags::{FromTokenFlags, IntoTokensFlags},
     stream::{ParseError, TokenStream},
-    token::Token,
+    token::{HForm, Token},
     traits::{IntoToken, IntoTokens},
     transform::{
-        add_stress, detect_stress, normalize, tokenize, tokens_to_string, unstress_vowels,
+        add_stress_in_place, apply_phonology, count_syllables, detect_stress, normalize,
+        normalize_alternates, tokenize, tokens_to_string, unstress_vowels, write_token,
+        write_tokens,
     },
 };
-use crate::category::Stress;
+use crate::category::{Category, Stress};
 use std::str::FromStr;
 
 /// A tokenized word with stress marked.
@@ -42,6 +44,13 @@ impl TokenList {
         }
     }
 
+    /// Removes every token and the stress from `self`, keeping its allocated capacity so that it
+    /// may be reused for another word.
+    pub fn clear(&mut self) {
+        self.tokens.clear();
+        self.stress = None;
+    }
+
     /// Pushes a token into `self`.
     pub fn push<T: IntoToken>(&mut self, token: T) {
         self.tokens.push(token.into_token());
@@ -56,13 +65,76 @@ impl TokenList {
     pub fn set_stress(&mut self, stress: Stress) {
         self.stress = Some(stress);
     }
-}
 
-impl FromStr for TokenList {
-    type Err = ParseError;
+    /// Adjusts the tokens of `self` so that they romanize as well-formed Ithkuil, applying Ca
+    /// allomorphs and inserting an epenthetic ë where needed. See [`apply_phonology`] for the
+    /// exact rules.
+    pub fn apply_phonology(&mut self) {
+        apply_phonology(&mut self.tokens);
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let source = normalize(s);
+    /// Counts the syllables in `self`. See [`count_syllables`] for the exact rules.
+    pub fn syllable_count(&self) -> usize {
+        count_syllables(&tokens_to_string(&self.tokens))
+    }
+
+    /// Appends `self` as romanized text to `output`. This writes the same text as `.to_string()`
+    /// without allocating a new string.
+    pub fn write_to(&self, output: &mut String) {
+        let start = output.len();
+        write_tokens(&self.tokens, output);
+        if let Some(stress) = self.stress {
+            add_stress_in_place(output, start, stress);
+        }
+    }
+
+    /// Describes the tokens of `self` for debugging, labeling each with the formative slot it most
+    /// likely fills.
+    ///
+    /// Each token is written as `label:text`, separated by spaces, followed by the stress of the
+    /// word if it is marked. Glottal stops and epenthetic vowels fill no slot, so they are written
+    /// without a label. For example, `"hlamröé"` is described as
+    /// `"Cc:hl Vv:a Cr:mr Vc/Vk:öe; stress: Ultimate"`.
+    ///
+    /// The labels follow the structure the formative parser expects (Cc, Vv, Cr, Vr, Cs, Vx, Ca,
+    /// Vn, Cn, and Vc/Vk), but are assigned by position alone. They are a heuristic meant to help
+    /// debug malformed words, so they may be wrong for valid formatives and are meaningless for
+    /// other words.
+    pub fn describe(&self) -> String {
+        let labels = label_formative_slots(&self.tokens);
+        let mut output = String::new();
+        let mut romanized = String::new();
+        let final_index = self.tokens.len().saturating_sub(1);
+
+        for (index, (token, label)) in self.tokens.iter().zip(labels).enumerate() {
+            if index != 0 {
+                output.push(' ');
+            }
+
+            let text_start = romanized.len();
+            write_token(token, &mut romanized, 0, index == final_index);
+            if let Some(label) = label {
+                output += label;
+                output.push(':');
+            }
+            output += &romanized[text_start..];
+        }
+
+        if let Some(stress) = self.stress {
+            output += "; stress: ";
+            output += stress.name();
+        }
+
+        output
+    }
+
+    /// Parses a [`TokenList`] from a string, respecting the passed [`FromTokenFlags`].
+    pub fn from_str_with(s: &str, flags: FromTokenFlags) -> Result<Self, ParseError> {
+        let source = if flags.matches(FromTokenFlags::ACCEPT_ALTERNATES) {
+            normalize(&normalize_alternates(s))
+        } else {
+            normalize(s)
+        };
         let stress = detect_stress(&source)?;
         let source = unstress_vowels(&source);
         let tokens = tokenize(&source)?;
@@ -70,12 +142,108 @@ impl FromStr for TokenList {
     }
 }
 
+impl FromStr for TokenList {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(s, FromTokenFlags::NONE)
+    }
+}
+
 impl ToString for TokenList {
     fn to_string(&self) -> String {
-        let word = tokens_to_string(&self.tokens);
-        if let Some(stress) = self.stress {
-            return add_stress(&word, stress).unwrap_or(word);
+        let mut output = String::new();
+        self.write_to(&mut output);
+        output
+    }
+}
+
+/// Labels each token of a word with the formative slot it most likely fills. See
+/// [`TokenList::describe`] for details.
+///
+/// Glottal stops and epenthetic vowels don't fill slots, so they are labeled [`None`].
+pub(crate) fn label_formative_slots(tokens: &[Token]) -> Vec<Option<&'static str>> {
+    let mut labels = vec![None; tokens.len()];
+
+    let indices: Vec<_> = (0..tokens.len())
+        .filter(|&index| !matches!(tokens[index], Token::GlottalStop | Token::Schwa))
+        .collect();
+
+    let is_vowel = |index: usize| matches!(tokens[indices[index]], Token::V(_) | Token::ÜA);
+    let is_h = |index: usize| matches!(tokens[indices[index]], Token::H(_));
+    let is_root = |index: usize| matches!(tokens[indices[index]], Token::C(_) | Token::N(_));
+
+    let mut start = 0;
+    let mut end = indices.len();
+
+    let mut has_ca_shortcut = false;
+
+    if start < end && is_h(start) {
+        if let Token::H(cc) = &tokens[indices[start]] {
+            has_ca_shortcut = *cc != HForm::H && *cc != HForm::HW;
+        }
+        labels[indices[start]] = Some("Cc");
+        start += 1;
+    }
+
+    if start < end && is_vowel(start) {
+        labels[indices[start]] = Some("Vv");
+        start += 1;
+    }
+
+    if start < end && is_root(start) {
+        labels[indices[start]] = Some("Cr");
+        start += 1;
+    }
+
+    if !has_ca_shortcut && start < end && is_vowel(start) {
+        labels[indices[start]] = Some("Vr");
+        start += 1;
+    }
+
+    // A Cn directly after Vr is a Cn shortcut, which leaves no room for Ca.
+    let mut has_cn_shortcut = false;
+
+    if start < end && is_h(start) {
+        labels[indices[start]] = Some("Cn");
+        start += 1;
+        has_cn_shortcut = true;
+    }
+
+    if start < end && is_vowel(end - 1) {
+        labels[indices[end - 1]] = Some("Vc/Vk");
+        end -= 1;
+
+        if !has_cn_shortcut && start + 1 < end && is_h(end - 1) && is_vowel(end - 2) {
+            labels[indices[end - 1]] = Some("Cn");
+            labels[indices[end - 2]] = Some("Vn");
+            end -= 2;
         }
-        return word;
     }
+
+    // Slot V affixes are written CsVx and force Ca to be geminated, while slot VII affixes are
+    // written VxCs after Ca.
+    let ca = if has_ca_shortcut || has_cn_shortcut {
+        None
+    } else {
+        let is_geminate =
+            |&index: &usize| matches!(&tokens[indices[index]], Token::C(ca) if ca.is_geminate());
+
+        (start..end)
+            .filter(|&index| !is_vowel(index))
+            .find(is_geminate)
+            .or_else(|| (start..end).find(|&index| !is_vowel(index)))
+    };
+
+    for index in start..end {
+        labels[indices[index]] = Some(if Some(index) == ca {
+            "Ca"
+        } else if is_vowel(index) {
+            "Vx"
+        } else {
+            "Cs"
+        });
+    }
+
+    labels
 }
@@ -6,7 +6,6 @@ use super::{
     token::{Token, VowelForm},
     token_list::TokenList,
 };
-use std::str::FromStr;
 
 /// Allows types to be converted into a single token.
 pub trait IntoVowelForm: Sized {
@@ -40,7 +39,8 @@ pub trait FromTokens: Sized {
     ///
     /// The [`TokenStream`] will advance correctly if an [`Ok`] is returned, but will advance an
     /// unspecified number of tokens if an [`Err`] is returned. If an [`Err`] is detected, it is
-    /// best to reset the underlying [`TokenStream`]'s `start` and `end` indices.
+    /// best to restore the underlying [`TokenStream`] to a
+    /// [`Checkpoint`](super::stream::Checkpoint) saved beforehand.
     fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError>;
 
     /// Creates this item from a [`TokenStream`], returning [`Err`] if it fails.
@@ -48,14 +48,12 @@ pub trait FromTokens: Sized {
     /// The [`TokenStream`] will advance correctly if an [`Ok`] is returned and will advance no
     /// tokens if an [`Err`] is returned.
     fn parse(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
-        let start = stream.start;
-        let end = stream.end;
+        let checkpoint = stream.checkpoint();
 
         match Self::parse_volatile(stream, flags) {
             Ok(value) => Ok(value),
             Err(error) => {
-                stream.start = start;
-                stream.end = end;
+                stream.restore(checkpoint);
                 Err(error)
             }
         }
@@ -63,7 +61,7 @@ pub trait FromTokens: Sized {
 
     /// Parses this item from a string.
     fn parse_str(source: &str, flags: FromTokenFlags) -> Result<Self, ParseError> {
-        let list = TokenList::from_str(source)?;
+        let list = TokenList::from_str_with(source, flags)?;
         let mut stream = list.stream();
         let result = Self::parse_volatile(&mut stream, flags)?;
         if stream.is_done() {
@@ -90,6 +88,16 @@ pub trait IntoTokens {
     fn to_string_with(&self, flags: IntoTokensFlags) -> String {
         self.into_tokens(flags).to_string()
     }
+
+    /// Appends the romanized form of this item to an existing string, so that many words can be
+    /// romanized into one buffer.
+    ///
+    /// This only allocates an intermediate [`TokenList`]. To avoid that as well, reuse a single
+    /// [`TokenList`] by calling [`TokenList::clear`], [`IntoTokens::append_tokens_to`], and
+    /// [`TokenList::write_to`] for each item.
+    fn write_ithkuil(&self, output: &mut String, flags: IntoTokensFlags) {
+        self.into_tokens(flags).write_to(output)
+    }
 }
 
 impl<T: IntoVowelForm> IntoToken for T {
@@ -23,8 +23,11 @@ use std::fmt::Write;
 
 use super::stream::ParseError;
 use crate::{
-    category::Stress,
-    romanize::token::{NumeralForm, OwnedConsonantForm, Token},
+    category::{Ca, Stress},
+    romanize::{
+        token::{ConsonantForm, NumeralForm, OwnedConsonantForm, Token},
+        token_list::label_formative_slots,
+    },
 };
 
 /// Normalizes a string into proper New Ithkuil format. This means making it lowercase,
@@ -49,6 +52,12 @@ pub fn normalize(word: &str) -> String {
         .replace("ú", "ú")
         .replace("ü", "ü")
         .replace("û", "û")
+        // Vowels with a diaeresis mark stress with a circumflex, but an acute is accepted too.
+        .replace("ä\u{301}", "â")
+        .replace("ë\u{301}", "ê")
+        .replace("ö\u{301}", "ô")
+        .replace("ü\u{301}", "û")
+        .replace("ǘ", "û")
         .replace("č", "č")
         .replace("ç", "ç")
         .replace("ţ", "ţ")
@@ -123,6 +132,20 @@ pub fn normalize(word: &str) -> String {
     output
 }
 
+/// Replaces alternate glottal stop notations in a string, such as \` and ʻ, with apostrophes.
+/// This is used when parsing with [`FromTokenFlags::ACCEPT_ALTERNATES`] and should be called
+/// before [`normalize`], which already handles alternate vowel notations such as ì and ù.
+///
+/// [`FromTokenFlags::ACCEPT_ALTERNATES`]: super::flags::FromTokenFlags::ACCEPT_ALTERNATES
+pub fn normalize_alternates(word: &str) -> String {
+    word.chars()
+        .map(|char| match char {
+            '`' | 'ʹ' | 'ʻ' | 'ʽ' | '′' => '\'',
+            value => value,
+        })
+        .collect()
+}
+
 /// Detects the stress in a word, returning it. If no vowel form is accented, [`None`] is returned
 /// instead of a definite stress marker. If two vowel forms are stressed, the [`Err`] variant is
 /// returned.
@@ -251,6 +274,36 @@ pub fn unstress_vowels(word: &str) -> String {
         .replace("û", "ü")
 }
 
+/// Counts the syllables in a word, which may be stressed.
+///
+/// Each vowel letter is the nucleus of one syllable, including an epenthetic ë, except that a
+/// vowel followed directly by i or u forms a single diphthong with it, as in "ai", "ëu", or "iu".
+/// This mirrors how [`detect_stress`] counts vowel forms. A glottal stop between two vowels keeps
+/// them from forming a diphthong, so "a'i" has two syllables. Words without any vowels, such as
+/// bias adjuncts or numerals written as digits, have zero syllables.
+pub fn count_syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut next = None;
+
+    for char in unstress_vowels(word).chars().rev() {
+        let is_diphthong = matches!(
+            (char, next),
+            ('a' | 'e' | 'ë' | 'o' | 'u', Some('i')) | ('a' | 'e' | 'ë' | 'o' | 'i', Some('u'))
+        );
+
+        if is_diphthong {
+            next = None;
+        } else if matches!(char, 'a' | 'ä' | 'e' | 'ë' | 'i' | 'o' | 'ö' | 'u' | 'ü') {
+            count += 1;
+            next = Some(char);
+        } else {
+            next = None;
+        }
+    }
+
+    count
+}
+
 /// Turns a word into a sequence of tokens. Underscores are assumed to be consonants, and may be
 /// used to force the positioning of certain cores and extensions in the script. For example,
 /// "malëuţřait" will by default place "ţ" as the core of an affix with a "ř" bottom extension, but
@@ -373,30 +426,142 @@ pub fn tokenize(word: &str) -> Result<Vec<Token>, ParseError> {
 /// Converts a list of tokens into a string.
 pub fn tokens_to_string(tokens: &[Token]) -> String {
     let mut output = String::new();
+    write_tokens(tokens, &mut output);
+    output
+}
+
+/// Appends a list of tokens to an existing string.
+pub fn write_tokens(tokens: &[Token], output: &mut String) {
+    let start = output.len();
     let final_index = match tokens.len().checked_sub(1) {
         Some(value) => value,
-        _ => return output,
+        _ => return,
     };
     for (index, token) in tokens.iter().enumerate() {
-        match token {
-            Token::C(value) => output += value,
-            Token::V(value) => output += value.as_str_after(&output, final_index == index),
-            Token::H(value) => output += value.as_str(),
-            Token::N(value) => write!(output, "{}", value.integer_part)
-                .expect("a Display implementation errored unexpectedly"),
-            Token::ÜA => output += "üa",
-            Token::Schwa => output += "ë",
-            Token::GlottalStop => output += "'",
+        write_token(token, output, start, final_index == index);
+    }
+}
+
+/// Appends a single token of a word to `output`, where the word starts at byte index `start` of
+/// `output` and the token is the last in its word if `is_word_final` is true.
+pub(crate) fn write_token(token: &Token, output: &mut String, start: usize, is_word_final: bool) {
+    match token {
+        Token::C(value) => *output += value,
+        Token::V(value) => {
+            let value = value.as_str_after(&output[start..], is_word_final);
+            *output += value;
         }
+        Token::H(value) => *output += value.as_str(),
+        Token::N(value) => write!(output, "{}", value.integer_part)
+            .expect("a Display implementation errored unexpectedly"),
+        Token::ÜA => *output += "üa",
+        Token::Schwa => *output += "ë",
+        Token::GlottalStop => *output += "'",
     }
+}
 
-    output
+/// Adjusts a list of tokens built one slot at a time so that it romanizes as well-formed
+/// Ithkuil.
+///
+/// Three adjustments are made, in this order:
+///
+/// 1. If the token in the Ca slot, as labeled by [`TokenList::describe`], is an ungeminated Ca form
+///    written without its allomorphic substitutions (such as `pb` or `řr`), the substitutions are
+///    applied as [`Ca::allomorph`] does (giving `mb` or `ňs`). Only the Ca slot is affected, as the
+///    substitutions don't apply to any other consonant form. Forms which look geminated, such as
+///    `tt` or `rr`, are left alone, as they are also the geminated forms of other Cas.
+///
+/// 2. An epenthetic ë is inserted between any two adjacent consonant forms. They are never merged
+///    into a single form, as that would erase the slot boundary between them.
+///
+/// 3. If the list starts with a consonant form which is not
+///    [valid word-initially][ConsonantForm::is_valid_word_initial], an epenthetic ë is inserted
+///    before it, just as referentials and affixual adjuncts do.
+///
+/// Lists produced by the [`IntoTokens`][super::traits::IntoTokens] implementations of this crate
+/// are already well-formed, and are left unchanged.
+///
+/// [`TokenList::describe`]: super::token_list::TokenList::describe
+pub fn apply_phonology(tokens: &mut Vec<Token>) {
+    let ca = label_formative_slots(tokens)
+        .iter()
+        .position(|label| *label == Some("Ca"));
+
+    if let Some(Token::C(ca)) = ca.map(|index| &mut tokens[index]) {
+        if let Some(allomorphed) = allomorph_ca(&ca.0) {
+            ca.0 = allomorphed;
+        }
+    }
+
+    let mut output: Vec<Token> = Vec::with_capacity(tokens.len());
+
+    for token in tokens.drain(..) {
+        if matches!((output.last(), &token), (Some(Token::C(_)), Token::C(_))) {
+            output.push(Token::Schwa);
+        }
+
+        output.push(token);
+    }
+
+    if let Some(Token::C(first)) = output.first() {
+        if !first.is_valid_word_initial() {
+            output.insert(0, Token::Schwa);
+        }
+    }
+
+    *tokens = output;
+}
+
+/// Applies the allomorphic substitutions of an ungeminated Ca form, returning the result if it
+/// differs from `ca` but is still read as the same [`Ca`].
+fn allomorph_ca(ca: &str) -> Option<String> {
+    if ConsonantForm::new(ca).is_geminate() {
+        return None;
+    }
+
+    let allomorphed = Ca::allomorph(ca);
+
+    (allomorphed != ca
+        && Ca::from_ungeminated_string(ca).is_some()
+        && Ca::from_ungeminated_string(&allomorphed) == Ca::from_ungeminated_string(ca))
+    .then_some(allomorphed)
 }
 
 /// Adds a stress marker to an unstressed word.
 ///
 /// Returns [`None`] if it is not possible.
 pub fn add_stress(word: &str, stress: Stress) -> Option<String> {
+    let mut output = word.to_owned();
+    if let Some((index, accented)) = find_stress_mark(word, stress)? {
+        replace_char(&mut output, index, accented);
+    }
+    Some(output)
+}
+
+/// Adds a stress marker to the unstressed word at the end of `output`, which starts at byte
+/// `start`. Unlike [`add_stress`], this modifies `output` without copying the word.
+///
+/// Returns `false` and leaves `output` unchanged if it is not possible.
+pub fn add_stress_in_place(output: &mut String, start: usize, stress: Stress) -> bool {
+    match find_stress_mark(&output[start..], stress) {
+        None => false,
+        Some(None) => true,
+        Some(Some((index, accented))) => {
+            replace_char(output, start + index, accented);
+            true
+        }
+    }
+}
+
+fn replace_char(output: &mut String, index: usize, char: char) {
+    let len = output[index..].chars().next().map_or(0, char::len_utf8);
+    output.replace_range(index..index + len, char.encode_utf8(&mut [0; 4]));
+}
+
+/// Finds the vowel which must be accented to mark `stress` on an unstressed word, returning its
+/// byte index and accented form. Returns `Some(None)` if the stress is unmarked, and [`None`] if it
+/// is not possible.
+fn find_stress_mark(word: &str, stress: Stress) -> Option<Option<(usize, char)>> {
     let vowels_required = match stress {
         Stress::Monosyllabic => 1,
         Stress::Ultimate => 1,
@@ -404,122 +569,274 @@ pub fn add_stress(word: &str, stress: Stress) -> Option<String> {
         Stress::Antepenultimate => 3,
     };
 
+    let is_vowel =
+        |(_, x): (usize, char)| matches!(x, 'a' | 'ä' | 'e' | 'ë' | 'i' | 'o' | 'ö' | 'u' | 'ü');
+
     let mut vowels_found = 0;
-    let mut char_list: Vec<char> = Vec::new();
-    let mut chars = word.chars().rev().peekable();
+    let mut chars = word.char_indices().rev().peekable();
 
     loop {
-        let char = chars.next()?;
+        let (index, char) = chars.next()?;
 
-        match char {
-            'a' | 'ä' | 'e' | 'ë' | 'o' | 'ö' | 'ü' => {
-                vowels_found += 1;
-
-                if vowels_found == vowels_required {
-                    match stress {
-                        Stress::Monosyllabic => {
-                            if chars.any(|x| {
-                                matches!(x, 'a' | 'ä' | 'e' | 'ë' | 'i' | 'o' | 'ö' | 'u' | 'ü')
-                            }) {
-                                return None;
-                            } else {
-                                return Some(word.to_owned());
-                            }
-                        }
+        let (index, char) = match char {
+            'a' | 'ä' | 'e' | 'ë' | 'o' | 'ö' | 'ü' => (index, char),
 
-                        Stress::Ultimate => {
-                            if !chars.any(|x| {
-                                matches!(x, 'a' | 'ä' | 'e' | 'ë' | 'i' | 'o' | 'ö' | 'u' | 'ü')
-                            }) {
-                                return Some(word.to_owned());
-                            }
-                        }
+            // The first vowel of a diphthong is the one which carries the stress.
+            'i' | 'u' => {
+                // We can't use `matches!()` here because rustfmt can't handle it.
+                match chars.next_if(|&(_, next_char)| match next_char {
+                    'a' | 'e' | 'ë' | 'i' | 'o' | 'u' if next_char != char => true,
+                    _ => false,
+                }) {
+                    Some(next) => next,
+                    None => (index, char),
+                }
+            }
 
-                        Stress::Penultimate => return Some(word.to_owned()),
+            _ => continue,
+        };
 
-                        Stress::Antepenultimate => {}
-                    }
+        vowels_found += 1;
 
-                    let mut output = chars.rev().collect::<String>();
-                    output.push(match char {
-                        'a' => 'á',
-                        'ä' => 'â',
-                        'e' => 'é',
-                        'ë' => 'ê',
-                        'o' => 'ó',
-                        'ö' => 'ô',
-                        'ü' => 'û',
-                        _ => unreachable!(),
-                    });
-                    for char in char_list.into_iter().rev() {
-                        output.push(char);
-                    }
-                    return Some(output);
+        if vowels_found != vowels_required {
+            continue;
+        }
+
+        match stress {
+            Stress::Monosyllabic => {
+                return if chars.any(is_vowel) {
+                    None
                 } else {
-                    char_list.push(char);
+                    Some(None)
+                };
+            }
+
+            Stress::Ultimate => {
+                if !chars.any(is_vowel) {
+                    return Some(None);
                 }
             }
 
-            'i' | 'u' => {
-                vowels_found += 1;
+            Stress::Penultimate => return Some(None),
 
-                // We can't use `matches!()` here because rustfmt can't handle it.
-                let char = match chars.next_if(|next_char| match next_char {
-                    'a' | 'e' | 'ë' | 'i' | 'o' | 'u' if *next_char != char => true,
-                    _ => false,
-                }) {
-                    Some(next_char) => {
-                        char_list.push(char);
-                        next_char
-                    }
-                    None => char,
-                };
+            Stress::Antepenultimate => {}
+        }
 
-                if vowels_found == vowels_required {
-                    match stress {
-                        Stress::Monosyllabic => {
-                            if chars.any(|x| {
-                                matches!(x, 'a' | 'ä' | 'e' | 'ë' | 'i' | 'o' | 'ö' | 'u' | 'ü')
-                            }) {
-                                return None;
-                            } else {
-                                return Some(word.to_owned());
-                            }
-                        }
+        return Some(Some((
+            index,
+            match char {
+                'a' => 'á',
+                'ä' => 'â',
+                'e' => 'é',
+                'ë' => 'ê',
+                'i' => 'í',
+                'o' => 'ó',
+                'ö' => 'ô',
+                'u' => 'ú',
+                'ü' => 'û',
+                _ => unreachable!(),
+            },
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        category::Stress,
+        prelude::*,
+        romanize::token::{OwnedConsonantForm, Token, VowelForm},
+    };
+
+    fn c(source: &str) -> Token {
+        Token::C(OwnedConsonantForm(source.to_owned()))
+    }
+
+    fn v() -> Token {
+        Token::V(VowelForm::default())
+    }
+
+    #[test]
+    fn phonology() {
+        #[track_caller]
+        fn check(tokens: Vec<Token>, expected: &str) {
+            let mut list = TokenList::new();
+            list.tokens = tokens;
+            list.apply_phonology();
+            assert_eq!(list.to_string(), expected);
+
+            // The result reads back as the same tokens.
+            let reparsed: TokenList = expected.parse().unwrap();
+            assert_eq!(reparsed.tokens, list.tokens);
+        }
+
+        // Unallomorphed Ca forms are allomorphed.
+        check(vec![c("m"), v(), c("pb"), v()], "mamba");
+        check(vec![c("m"), v(), c("řr"), v()], "maňsa");
 
-                        Stress::Ultimate => {
-                            if !chars.any(|x| {
-                                matches!(x, 'a' | 'ä' | 'e' | 'ë' | 'i' | 'o' | 'ö' | 'u' | 'ü')
-                            }) {
-                                return Some(word.to_owned());
+        // Unallomorphed forms which look geminated are ambiguous, so they are left alone.
+        check(vec![c("m"), v(), c("rr"), v()], "marra");
+
+        // Other slots are never allomorphed, even where the same letters meet.
+        check(vec![c("m"), v(), c("l"), v(), c("pb"), v()], "malapba");
+
+        // Adjacent consonant forms are kept apart by an epenthetic ë, even if they would make up
+        // a valid conjunct.
+        check(vec![v(), c("s"), c("m"), c("l"), v(), c("l")], "asëmëlal");
+        check(vec![v(), c("t"), c("s"), v()], "atësa");
+        check(vec![c("p"), c("b"), v()], "pëba");
+
+        // So are invalid word-initial conjuncts.
+        check(vec![c("ļ"), v()], "ëļa");
+        check(vec![c("rt"), v(), c("l")], "ërtal");
+    }
+
+    #[test]
+    fn phonology_round_trip() -> Result<(), ParseError> {
+        use crate::{
+            category::{Affiliation, Ca, Configuration, Essence, Extension, Perspective},
+            romanize::token::ConsonantForm,
+            word::UncheckedFormative,
+        };
+
+        let base: UncheckedFormative = "mala".parse()?;
+
+        // Writing a formative's Ca without its allomorphs is undone by `apply_phonology`.
+        for affiliation in Affiliation::ALL_ITEMS {
+            for configuration in Configuration::ALL_ITEMS {
+                for extension in Extension::ALL_ITEMS {
+                    for perspective in Perspective::ALL_ITEMS {
+                        for essence in Essence::ALL_ITEMS {
+                            let ca = Ca {
+                                affiliation,
+                                configuration,
+                                extension,
+                                perspective,
+                                essence,
+                            };
+
+                            let unallomorphed = ca.to_unallomorphed_string();
+                            if unallomorphed == ca.to_ungeminated_string()
+                                || ConsonantForm::new(&unallomorphed).is_geminate()
+                            {
+                                continue;
                             }
-                        }
 
-                        Stress::Penultimate => return Some(word.to_owned()),
+                            let mut formative = base.clone();
+                            formative.ca = ca;
 
-                        Stress::Antepenultimate => {}
-                    }
+                            let expected = formative.into_tokens(IntoTokensFlags::NONE);
+                            let mut list = expected.clone();
+                            list.tokens[2] = c(&unallomorphed);
+                            list.apply_phonology();
 
-                    let mut output = chars.rev().collect::<String>();
-                    output.push(match char {
-                        'a' => 'á',
-                        'e' => 'é',
-                        'ë' => 'ê',
-                        'i' => 'í',
-                        'o' => 'ó',
-                        'u' => 'ú',
-                        _ => unreachable!(),
-                    });
-                    for char in char_list.into_iter().rev() {
-                        output.push(char);
+                            assert_eq!(list, expected, "{ca:?}");
+                            assert_eq!(list.to_string().parse::<UncheckedFormative>()?, formative);
+                        }
                     }
-                    return Some(output);
-                } else {
-                    char_list.push(char);
                 }
             }
+        }
 
-            _ => char_list.push(char),
+        // Removing a word-initial epenthetic ë is undone by `apply_phonology`.
+        let referential: Word = "ërta".parse()?;
+        let expected = referential.into_tokens(IntoTokensFlags::NONE);
+        let mut list = expected.clone();
+        list.tokens.retain(|token| *token != Token::Schwa);
+        list.apply_phonology();
+
+        assert_eq!(list, expected);
+        assert_eq!(list.to_string().parse::<Word>()?, referential);
+
+        Ok(())
+    }
+
+    #[test]
+    fn phonology_keeps_valid_words() -> Result<(), ParseError> {
+        for source in [
+            "hliosulţe",
+            "ašflaleče",
+            "aesmlal",
+            "holřäksa",
+            "ırburučpaızya",
+            "psakpaevv",
+            "oëtil",
+            "ëlawe",
+            "hlamalá",
+        ] {
+            let word: Word = source.parse()?;
+            let mut list = word.into_tokens(IntoTokensFlags::NONE);
+            let original = list.clone();
+            list.apply_phonology();
+            assert_eq!(list, original, "source was {source:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn describe() -> Result<(), ParseError> {
+        let describe = |source: &str| -> Result<String, ParseError> {
+            Ok(source.parse::<TokenList>()?.describe())
+        };
+
+        assert_eq!(
+            describe("hlamröé")?,
+            "Cc:hl Vv:a Cr:mr Vc/Vk:öe; stress: Ultimate"
+        );
+        assert_eq!(describe("lala")?, "Cr:l Vr:a Ca:l Vc/Vk:a");
+        assert_eq!(
+            describe("lalwehá")?,
+            "Cr:l Vr:a Ca:lw Vn:e Cn:h Vc/Vk:a; stress: Ultimate"
+        );
+        assert_eq!(describe("ëlalölwa")?, "ë Cr:l Vr:a Ca:l Vx:ö Cs:lw Vc/Vk:a");
+        assert_eq!(describe("la'lwasa")?, "Cr:l Vr:a' Ca:lw Vx:a Cs:s Vc/Vk:a");
+        assert_eq!(describe("lëtl")?, "Cr:l ë Ca:tl; stress: Monosyllabic");
+        assert_eq!(describe("wala")?, "Cc:w Vv:a Cr:l Vc/Vk:a");
+
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_stress() -> Result<(), ParseError> {
+        for (source, stress) in [
+            ("la", Some(Stress::Monosyllabic)),
+            ("lá", Some(Stress::Ultimate)),
+            ("lala", None),
+            ("lalá", Some(Stress::Ultimate)),
+            ("lála", Some(Stress::Penultimate)),
+            ("lálala", Some(Stress::Antepenultimate)),
+            ("LÁLA", Some(Stress::Penultimate)),
+            ("la\u{301}la", Some(Stress::Penultimate)),
+            ("laláil", Some(Stress::Ultimate)),
+            ("láula", Some(Stress::Penultimate)),
+            ("lêila", Some(Stress::Penultimate)),
+            ("lá'ala", Some(Stress::Antepenultimate)),
+            ("lâla", Some(Stress::Penultimate)),
+            ("lä\u{301}la", Some(Stress::Penultimate)),
+            ("lǘla", Some(Stress::Penultimate)),
+            ("lü\u{301}la", Some(Stress::Penultimate)),
+        ] {
+            let list: TokenList = source.parse()?;
+            assert_eq!(list.stress, stress, "{source}");
+            assert_eq!(list.stream().stress(), stress, "{source}");
         }
+
+        assert_eq!("lǘla".parse::<TokenList>()?, "lûla".parse()?);
+
+        for source in ["lálá", "láá", "lâlé"] {
+            assert_eq!(
+                source.parse::<TokenList>(),
+                Err(ParseError::StressDoubled),
+                "{source}"
+            );
+        }
+
+        assert_eq!(
+            "lálalala".parse::<TokenList>(),
+            Err(ParseError::StressInvalid)
+        );
+
+        Ok(())
     }
 }
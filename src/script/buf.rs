@@ -35,4 +35,38 @@ impl CharacterBuf {
     pub fn append<T: IntoScript>(&mut self, item: T, flags: IntoScriptFlags) {
         item.append_script_to(self, flags);
     }
+
+    /// Moves every character of `other` onto the end of this buffer.
+    pub fn extend_from(&mut self, mut other: CharacterBuf) {
+        self.vec.append(&mut other.vec);
+    }
+
+    /// Returns the number of characters in this buffer.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if this buffer contains no characters.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns an iterator over the characters in this buffer.
+    pub fn iter(&self) -> std::slice::Iter<'_, Character> {
+        self.vec.iter()
+    }
+}
+
+impl Extend<Character> for CharacterBuf {
+    fn extend<I: IntoIterator<Item = Character>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+impl FromIterator<Character> for CharacterBuf {
+    fn from_iter<I: IntoIterator<Item = Character>>(iter: I) -> Self {
+        CharacterBuf {
+            vec: Vec::from_iter(iter),
+        }
+    }
 }
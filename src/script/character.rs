@@ -1,8 +1,9 @@
 //! Defines character types.
 
 use crate::category::{
-    AffixSlot, AffixType, Aspect, Ca, Case, CaseAccessorMode, CaseScope, Context, DatalessRelation,
-    Effect, Function, Level, Mood, Phase, Specification, Stem, Valence, VcOrVk, Version,
+    AffixDegree, AffixSlot, AffixType, Aspect, Ca, Case, CaseAccessorMode, CaseScope, Context,
+    DatalessRelation, Effect, Function, Level, Mood, Phase, Specification, Stem, Valence, VcOrVk,
+    Version,
 };
 
 macro_rules! item {
@@ -323,10 +324,27 @@ pub struct AccessorQuaternary {
     pub r#type: AffixType,
 }
 
+/// A quaternary character holding the type, slot, and degree of a plain or numeric affix, used
+/// when [`IntoScriptFlags::AFFIXES_AS_QUATERNARIES`] is enabled.
+///
+/// [`IntoScriptFlags::AFFIXES_AS_QUATERNARIES`]: super::flags::IntoScriptFlags::AFFIXES_AS_QUATERNARIES
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AffixQuaternary {
+    /// The slot of this affix.
+    pub slot: AffixSlot,
+
+    /// The degree of this affix.
+    pub degree: AffixDegree,
+
+    /// The type of this affix.
+    pub r#type: AffixType,
+}
+
 /// A numeral character.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Numeral {
-    /// The value of this character. If greater than 9999, extra bits will be ignored during
-    /// conversion to a secondary.
+    /// The value of this character. A single numeral character shows at most four digits, so
+    /// only `value % 10_000` is shown when converting it to a secondary. Use
+    /// [`Numeral::numeral`] to split larger numbers across several characters.
     pub value: u16,
 }
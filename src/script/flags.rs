@@ -4,7 +4,7 @@ use crate::macros::bitflags;
 
 /// A bit flag of options passed to script generation functions.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct IntoScriptFlags(u8);
+pub struct IntoScriptFlags(u16);
 
 impl IntoScriptFlags {
     /// A [`IntoScriptFlags`] instance with no flags active.
@@ -18,7 +18,8 @@ impl IntoScriptFlags {
 
     /// A [`IntoScriptFlags`] instance with only the `keep_quaternaries` flag enabled.
     ///
-    /// If passed to `.append_script_to()`, formatives will not elide quaternary characters.
+    /// If passed to `.append_script_to()`, formatives will not elide quaternary characters. This is
+    /// the same as passing both `KEEP_VK_QUATERNARIES` and `KEEP_CASE_QUATERNARIES`.
     pub const KEEP_QUATERNARIES: Self = Self(1 << 1);
 
     /// A [`IntoScriptFlags`] instance with only the `tertiaries_as_affixes` flag enabled.
@@ -45,6 +46,26 @@ impl IntoScriptFlags {
     /// If passed to `.append_script_to()`, referentials with perspectives will write their
     /// perspectives as usual, instead of turning into referential-root formatives.
     pub const INLINE_REFERENTIAL_PERSPECTIVES: Self = Self(1 << 5);
+
+    /// A [`IntoScriptFlags`] instance with only the `keep_vk_quaternaries` flag enabled.
+    ///
+    /// If passed to `.append_script_to()`, verbal formatives will not elide quaternary characters,
+    /// so their illocution and validation are always written. Nominal formatives are unaffected.
+    pub const KEEP_VK_QUATERNARIES: Self = Self(1 << 6);
+
+    /// A [`IntoScriptFlags`] instance with only the `keep_case_quaternaries` flag enabled.
+    ///
+    /// If passed to `.append_script_to()`, non-verbal formatives will not elide quaternary
+    /// characters, so their case is always written. Verbal formatives are unaffected.
+    pub const KEEP_CASE_QUATERNARIES: Self = Self(1 << 7);
+
+    /// A [`IntoScriptFlags`] instance with only the `affixes_as_quaternaries` flag enabled.
+    ///
+    /// If passed to `.append_script_to()`, plain and numeric affixes will be written without
+    /// diacritics, each followed by an [`AffixQuaternary`] holding its type, slot, and degree.
+    ///
+    /// [`AffixQuaternary`]: super::character::AffixQuaternary
+    pub const AFFIXES_AS_QUATERNARIES: Self = Self(1 << 8);
 }
 
 bitflags!(IntoScriptFlags);
@@ -2,10 +2,10 @@ use super::{character::*, flags::IntoScriptFlags, traits::IntoSecondary};
 use crate::{
     ca_pat,
     category::{
-        Affiliation, AffixDegree, AffixSlot, AffixType, Case, CaseAccessorMode, CaseScope, Context,
-        DatalessRelation, Essence, Extension, Function, Illocution, Level, Mood, Perspective,
-        Plexity, Separability, Similarity, SimilarityAndSeparability, Specification, Stem,
-        Validation, VcOrVk, Version,
+        Affiliation, AffixDegree, AffixSlot, AffixType, Bias, Case, CaseAccessorMode, CaseScope,
+        Context, DatalessRelation, Essence, Extension, Function, Illocution, Level, Mood,
+        Perspective, Plexity, RegisterType, Separability, Similarity, SimilarityAndSeparability,
+        Specification, Stem, Valence, Validation, VcOrVk, Version,
     },
     prelude::token::NumeralForm,
 };
@@ -51,7 +51,7 @@ impl Core {
             'v' => Some(Self::V),
             'x' => Some(Self::X),
             'z' => Some(Self::Z),
-            'ż' => Some(Self::Ẓ),
+            'ẓ' | 'ż' => Some(Self::Ẓ),
             'ž' => Some(Self::Ž),
             _ => None,
         }
@@ -168,6 +168,93 @@ impl Ext {
         }
     }
 
+    /// Gets the extension and perspective shown by the top left extension of a primary character.
+    /// This is the inverse of [`Ext::primary_top`], and returns [`None`] if `ext` isn't used there.
+    pub const fn decode_primary_top(ext: Option<Self>) -> Option<(Extension, Perspective)> {
+        match ext {
+            None => Some((Extension::DEL, Perspective::M)),
+            Some(Self::S) => Some((Extension::PRX, Perspective::M)),
+            Some(Self::T) => Some((Extension::ICP, Perspective::M)),
+            Some(Self::D) => Some((Extension::ATV, Perspective::M)),
+            Some(Self::M) => Some((Extension::GRA, Perspective::M)),
+            Some(Self::N) => Some((Extension::DPL, Perspective::M)),
+
+            Some(Self::P) => Some((Extension::DEL, Perspective::G)),
+            Some(Self::G) => Some((Extension::PRX, Perspective::G)),
+            Some(Self::Ž) => Some((Extension::ICP, Perspective::G)),
+            Some(Self::Ḑ) => Some((Extension::ATV, Perspective::G)),
+            Some(Self::V) => Some((Extension::GRA, Perspective::G)),
+            Some(Self::X) => Some((Extension::DPL, Perspective::G)),
+
+            Some(Self::Š) => Some((Extension::DEL, Perspective::N)),
+            Some(Self::GeminateExt) => Some((Extension::PRX, Perspective::N)),
+            Some(Self::W) => Some((Extension::ICP, Perspective::N)),
+            Some(Self::H) => Some((Extension::ATV, Perspective::N)),
+            Some(Self::F) => Some((Extension::GRA, Perspective::N)),
+            Some(Self::Ř) => Some((Extension::DPL, Perspective::N)),
+
+            Some(Self::B) => Some((Extension::DEL, Perspective::A)),
+            Some(Self::K) => Some((Extension::PRX, Perspective::A)),
+            Some(Self::C) => Some((Extension::ICP, Perspective::A)),
+            Some(Self::Č) => Some((Extension::ATV, Perspective::A)),
+            Some(Self::Ẓ) => Some((Extension::GRA, Perspective::A)),
+            Some(Self::J) => Some((Extension::DPL, Perspective::A)),
+
+            _ => None,
+        }
+    }
+
+    /// Gets the function, version, plexity, and stem shown by the bottom right extension of a
+    /// primary character. This is the inverse of [`Ext::primary_bottom`], and returns [`None`] if
+    /// `ext` isn't used there.
+    pub const fn decode_primary_bottom(
+        ext: Option<Self>,
+    ) -> Option<(Function, Version, Plexity, Stem)> {
+        match ext {
+            Some(Self::B) => Some((Function::STA, Version::PRC, Plexity::M, Stem::S0)),
+            None => Some((Function::STA, Version::PRC, Plexity::M, Stem::S1)),
+            Some(Self::P) => Some((Function::STA, Version::PRC, Plexity::M, Stem::S2)),
+            Some(Self::Š) => Some((Function::STA, Version::PRC, Plexity::M, Stem::S3)),
+
+            Some(Self::C) => Some((Function::STA, Version::PRC, Plexity::D, Stem::S0)),
+            Some(Self::Z) => Some((Function::STA, Version::PRC, Plexity::D, Stem::S1)),
+            Some(Self::PWithLine) => Some((Function::STA, Version::PRC, Plexity::D, Stem::S2)),
+            Some(Self::W) => Some((Function::STA, Version::PRC, Plexity::D, Stem::S3)),
+
+            Some(Self::K) => Some((Function::STA, Version::CPT, Plexity::M, Stem::S0)),
+            Some(Self::L) => Some((Function::STA, Version::CPT, Plexity::M, Stem::S1)),
+            Some(Self::G) => Some((Function::STA, Version::CPT, Plexity::M, Stem::S2)),
+            Some(Self::GeminateExt) => Some((Function::STA, Version::CPT, Plexity::M, Stem::S3)),
+
+            Some(Self::Č) => Some((Function::STA, Version::CPT, Plexity::D, Stem::S0)),
+            Some(Self::RFlipped) => Some((Function::STA, Version::CPT, Plexity::D, Stem::S1)),
+            Some(Self::GWithLine) => Some((Function::STA, Version::CPT, Plexity::D, Stem::S2)),
+            Some(Self::H) => Some((Function::STA, Version::CPT, Plexity::D, Stem::S3)),
+
+            Some(Self::D) => Some((Function::DYN, Version::PRC, Plexity::M, Stem::S0)),
+            Some(Self::M) => Some((Function::DYN, Version::PRC, Plexity::M, Stem::S1)),
+            Some(Self::GeminateCore) => Some((Function::DYN, Version::PRC, Plexity::M, Stem::S2)),
+            Some(Self::T) => Some((Function::DYN, Version::PRC, Plexity::M, Stem::S3)),
+
+            Some(Self::DWithLine) => Some((Function::DYN, Version::PRC, Plexity::D, Stem::S0)),
+            Some(Self::N) => Some((Function::DYN, Version::PRC, Plexity::D, Stem::S1)),
+            Some(Self::Ň) => Some((Function::DYN, Version::PRC, Plexity::D, Stem::S2)),
+            Some(Self::Ž) => Some((Function::DYN, Version::PRC, Plexity::D, Stem::S3)),
+
+            Some(Self::Ţ) => Some((Function::DYN, Version::CPT, Plexity::M, Stem::S0)),
+            Some(Self::S) => Some((Function::DYN, Version::CPT, Plexity::M, Stem::S1)),
+            Some(Self::X) => Some((Function::DYN, Version::CPT, Plexity::M, Stem::S2)),
+            Some(Self::F) => Some((Function::DYN, Version::CPT, Plexity::M, Stem::S3)),
+
+            Some(Self::Ḑ) => Some((Function::DYN, Version::CPT, Plexity::D, Stem::S0)),
+            Some(Self::R) => Some((Function::DYN, Version::CPT, Plexity::D, Stem::S1)),
+            Some(Self::Ř) => Some((Function::DYN, Version::CPT, Plexity::D, Stem::S2)),
+            Some(Self::V) => Some((Function::DYN, Version::CPT, Plexity::D, Stem::S3)),
+
+            _ => None,
+        }
+    }
+
     /// Gets the top and bottom extensions for a standard quaternary character.
     pub const fn standard_quaternary_exts(vc_or_vk: VcOrVk) -> (Option<Self>, Option<Self>) {
         match vc_or_vk {
@@ -271,7 +358,7 @@ impl Ext {
             'x' => Some(Self::X),
             'y' => Some(Self::Y),
             'z' => Some(Self::Z),
-            'ż' => Some(Self::Ẓ),
+            'ẓ' | 'ż' => Some(Self::Ẓ),
             'ž' => Some(Self::Ž),
             _ => None,
         }
@@ -729,8 +816,197 @@ impl Secondary {
     }
 }
 
+impl Tertiary {
+    /// Packs valences, tertiary segments, and levels into as few tertiary characters as possible.
+    ///
+    /// Each character holds one valence, two segments, an absolute level as its superposed
+    /// diacritic, and a relative level as its underposed diacritic. Items are taken from the ends
+    /// of their lists, so the last items of each list share the first character. Characters
+    /// without a valence use MNO. If `flags` includes `PREFER_BOTTOM_TERTIARIES`, a lone segment
+    /// in a character is placed on the bottom instead of the top.
+    ///
+    /// This is how formatives write their Vn and tertiary affixes in script.
+    pub fn from_segments(
+        mut valences: Vec<Valence>,
+        mut segments: Vec<TertiarySegment>,
+        mut relative_levels: Vec<Level>,
+        mut absolute_levels: Vec<Level>,
+        flags: IntoScriptFlags,
+    ) -> Vec<Tertiary> {
+        let mut output = Vec::new();
+
+        while !(valences.is_empty()
+            && segments.is_empty()
+            && absolute_levels.is_empty()
+            && relative_levels.is_empty())
+        {
+            let (top, bottom) = if flags.matches(IntoScriptFlags::PREFER_BOTTOM_TERTIARIES) {
+                let bottom = segments.pop();
+                let top = segments.pop();
+                (top, bottom)
+            } else {
+                let top = segments.pop();
+                let bottom = segments.pop();
+                (top, bottom)
+            };
+
+            output.push(Tertiary {
+                valence: valences.pop().unwrap_or_default(),
+                top,
+                bottom,
+                superposed: absolute_levels.pop(),
+                underposed: relative_levels.pop(),
+            });
+        }
+
+        output
+    }
+}
+
+impl Register {
+    /// Gets the standard register character written for a register adjunct.
+    ///
+    /// In the script, a register is written by placing its character both before and after the
+    /// words it contains, so a start register such as [`RegisterType::PNT`] and its paired end
+    /// register [`RegisterType::PNT_END`] share a character. Returns [`None`] for the discursive
+    /// registers and the general [`RegisterType::END`], which have no standard register character.
+    pub const fn from_register_type(register: RegisterType) -> Option<Self> {
+        match register {
+            RegisterType::PNT | RegisterType::PNT_END => Some(Self::StandardPNT),
+            RegisterType::SPF | RegisterType::SPF_END => Some(Self::StandardSPF),
+            RegisterType::EXM | RegisterType::EXM_END => Some(Self::StandardEXM),
+            RegisterType::CGT | RegisterType::CGT_END => Some(Self::StandardCGT),
+            RegisterType::DSV | RegisterType::DSV_END | RegisterType::END => None,
+        }
+    }
+
+    /// Gets the register adjunct written by a standard register character. This is the inverse of
+    /// [`Register::from_register_type`].
+    ///
+    /// Since start and end registers share a character, `is_end` picks which one is returned.
+    /// Returns [`None`] for alphabetic, transcriptive, and transliterative register characters.
+    pub const fn decode_register_type(self, is_end: bool) -> Option<RegisterType> {
+        let (start, end) = match self {
+            Self::StandardPNT => (RegisterType::PNT, RegisterType::PNT_END),
+            Self::StandardSPF => (RegisterType::SPF, RegisterType::SPF_END),
+            Self::StandardEXM => (RegisterType::EXM, RegisterType::EXM_END),
+            Self::StandardCGT => (RegisterType::CGT, RegisterType::CGT_END),
+            _ => return None,
+        };
+
+        Some(if is_end { end } else { start })
+    }
+}
+
+impl Bias {
+    /// Gets the bias written by a secondary character. This is the inverse of
+    /// [`IntoSecondary::into_secondary`] for [`Bias`].
+    ///
+    /// Biases are written with a sigma core, rotated for the first half of the list. DCC and PSM
+    /// use a dot diacritic on the left or right instead of an extension. Returns [`None`] if the
+    /// secondary doesn't represent a bias.
+    pub const fn from_secondary(secondary: &Secondary) -> Option<Self> {
+        let Secondary {
+            is_rotated,
+            core: Core::Sigma,
+            top,
+            bottom,
+            superposed: None,
+            underposed: None,
+            leftposed,
+            rightposed,
+        } = *secondary
+        else {
+            return None;
+        };
+
+        match (leftposed, rightposed) {
+            (None, None) => {}
+            (Some(Diacritic::Dot), None) if is_rotated && top.is_none() && bottom.is_none() => {
+                return Some(Bias::DCC)
+            }
+            (None, Some(Diacritic::Dot)) if !is_rotated && top.is_none() && bottom.is_none() => {
+                return Some(Bias::PSM)
+            }
+            _ => return None,
+        }
+
+        Some(match (is_rotated, top, bottom) {
+            (true, None, None) => Bias::ACC,
+            (true, None, Some(Ext::L)) => Bias::ACH,
+            (true, None, Some(Ext::Z)) => Bias::ADS,
+            (true, None, Some(Ext::S)) => Bias::ANN,
+            (true, None, Some(Ext::K)) => Bias::ANP,
+            (true, None, Some(Ext::G)) => Bias::APB,
+            (true, None, Some(Ext::GeminateExt)) => Bias::APH,
+            (true, None, Some(Ext::GeminateCore)) => Bias::ARB,
+            (true, None, Some(Ext::D)) => Bias::ATE,
+            (true, None, Some(Ext::T)) => Bias::CMD,
+            (true, None, Some(Ext::Š)) => Bias::CNV,
+            (true, None, Some(Ext::P)) => Bias::COI,
+            (true, None, Some(Ext::X)) => Bias::CRP,
+            (true, None, Some(Ext::Ř)) => Bias::CRR,
+            (true, None, Some(Ext::Ţ)) => Bias::CTP,
+            (true, None, Some(Ext::F)) => Bias::CTV,
+
+            (true, Some(Ext::L), None) => Bias::DEJ,
+            (true, Some(Ext::Z), None) => Bias::DES,
+            (true, Some(Ext::S), None) => Bias::DFD,
+            (true, Some(Ext::K), None) => Bias::DIS,
+            (true, Some(Ext::G), None) => Bias::DLC,
+            (true, Some(Ext::GeminateExt), None) => Bias::DOL,
+            (true, Some(Ext::GeminateCore), None) => Bias::DPB,
+            (true, Some(Ext::D), None) => Bias::DRS,
+            (true, Some(Ext::T), None) => Bias::DUB,
+            (true, Some(Ext::Š), None) => Bias::EUH,
+            (true, Some(Ext::P), None) => Bias::EUP,
+            (true, Some(Ext::X), None) => Bias::EXA,
+            (true, Some(Ext::Ř), None) => Bias::EXG,
+            (true, Some(Ext::Ţ), None) => Bias::MNF,
+            (true, Some(Ext::F), None) => Bias::FOR,
+
+            (false, None, None) => Bias::FSC,
+            (false, None, Some(Ext::L)) => Bias::GRT,
+            (false, None, Some(Ext::Z)) => Bias::IDG,
+            (false, None, Some(Ext::S)) => Bias::IFT,
+            (false, None, Some(Ext::K)) => Bias::IPL,
+            (false, None, Some(Ext::G)) => Bias::IPT,
+            (false, None, Some(Ext::GeminateExt)) => Bias::IRO,
+            (false, None, Some(Ext::GeminateCore)) => Bias::ISP,
+            (false, None, Some(Ext::D)) => Bias::IVD,
+            (false, None, Some(Ext::T)) => Bias::MAN,
+            (false, None, Some(Ext::Š)) => Bias::OPT,
+            (false, None, Some(Ext::P)) => Bias::PES,
+            (false, None, Some(Ext::X)) => Bias::PPT,
+            (false, None, Some(Ext::Ř)) => Bias::PPX,
+            (false, None, Some(Ext::Ţ)) => Bias::PPV,
+            (false, None, Some(Ext::F)) => Bias::PSC,
+
+            (false, Some(Ext::L), None) => Bias::RAC,
+            (false, Some(Ext::Z), None) => Bias::RFL,
+            (false, Some(Ext::S), None) => Bias::RSG,
+            (false, Some(Ext::K), None) => Bias::RPU,
+            (false, Some(Ext::G), None) => Bias::RVL,
+            (false, Some(Ext::GeminateExt), None) => Bias::SAT,
+            (false, Some(Ext::GeminateCore), None) => Bias::SGS,
+            (false, Some(Ext::D), None) => Bias::SKP,
+            (false, Some(Ext::T), None) => Bias::SOL,
+            (false, Some(Ext::Š), None) => Bias::STU,
+            (false, Some(Ext::P), None) => Bias::TRP,
+            (false, Some(Ext::X), None) => Bias::VEX,
+
+            _ => return None,
+        })
+    }
+}
+
 impl Numeral {
     /// Gets a sequence of numerals representing a numeric form.
+    ///
+    /// Each numeral character shows four digits, so numbers of 10000 or more are split into groups
+    /// of four digits, written most significant group first. Each group is the digit of a power of
+    /// 10000. For example, 123456789 is written as the three characters 1, 2345, and 6789. Zero
+    /// groups in the middle of a number are written as the character for zero.
     pub fn numeral(form: NumeralForm) -> Vec1<Numeral> {
         let mut value = form.integer_part;
 
@@ -1,8 +1,8 @@
 //! Provides types and traits for converting into Ithkuil script.
+pub mod buf;
 pub mod character;
 pub mod flags;
 mod inherent_impls;
+pub mod repr;
 mod trait_impls;
 pub mod traits;
-pub mod buf;
-pub mod repr;
@@ -6,8 +6,8 @@ use crate::{
     prelude::{
         category::*,
         character::{
-            AccessorQuaternary, Core, Diacritic, Ext, Numeral, Primary, Register, Secondary,
-            StandardQuaternary, Tertiary, TertiarySegment,
+            AccessorQuaternary, AffixQuaternary, Core, Diacritic, Ext, Numeral, Primary, Register,
+            Secondary, StandardQuaternary, Tertiary, TertiarySegment,
         },
         token::NumeralForm,
         *,
@@ -113,9 +113,27 @@ impl IntoSecondary for AccessorQuaternary {
     }
 }
 
+impl IntoSecondary for AffixQuaternary {
+    fn into_secondary(self) -> Secondary {
+        Secondary {
+            is_rotated: false,
+            core: Core::VertBar,
+            top: None,
+            bottom: None,
+            // Quaternaries can't be rotated, so this uses the same diacritics as numeric affixes
+            // to show the slot.
+            superposed: Diacritic::numeric_affix_type(self.r#type, self.slot),
+            underposed: Some(Diacritic::affix_degree(self.degree)),
+            leftposed: None,
+            rightposed: None,
+        }
+    }
+}
+
 impl IntoSecondary for Numeral {
     fn into_secondary(self) -> Secondary {
-        let mut value = self.value;
+        // Only four digits fit in one character. `Numeral::numeral` never produces more.
+        let mut value = self.value % 10_000;
         let ones = value % 10;
         value /= 10;
         let tens = value % 10;
@@ -308,6 +326,8 @@ impl IntoSecondary for Case {
     }
 }
 
+/// Gets the placeholder written in place of a consonant form that can't be written in the script,
+/// which may be present in unchecked formatives.
 fn vec1_h() -> Vec1<Secondary> {
     return Vec1::new(Secondary {
         is_rotated: false,
@@ -356,6 +376,22 @@ impl IntoScript for Extended<ShortcutCheckedFormative> {
     }
 }
 
+/// Writes a formative as script characters in a fixed order:
+///
+/// 1. its primary, or the diacritic replacing it if [`IntoScriptFlags::ELIDE_PRIMARIES`] is set;
+/// 2. the secondaries of its root, the first of which carries the diacritics of an elided
+///    quaternary;
+/// 3. its slot V, slot VII, and slot XI affixes, in that order and each in its written order.
+///    Plain, numeric, and Ca-stacking affixes are written as secondaries, each followed by its
+///    affix quaternary if [`IntoScriptFlags::AFFIXES_AS_QUATERNARIES`] is set, and case accessor
+///    affixes are written as quaternaries in place. Case-stacking and referential affixes are
+///    deferred;
+/// 4. tertiaries holding its Vn and, unless [`IntoScriptFlags::TERTIARIES_AS_AFFIXES`] is set, the
+///    valence, phase, effect, level, and aspect affixes taken out of slot VII;
+/// 5. one quaternary for each case-stacking affix, in order;
+/// 6. its own quaternary, unless it was elided onto the root;
+/// 7. each referential affix, in order, as a standalone single referential (its case quaternary
+///    followed by its referents). These come last because they are separate words in script.
 impl IntoScript for Extended<UncheckedFormative> {
     fn append_script_to(&self, list: &mut CharacterBuf, flags: IntoScriptFlags) {
         let base = &self.base;
@@ -382,21 +418,22 @@ impl IntoScript for Extended<UncheckedFormative> {
 
         let mut elided_quaternary = false;
 
+        let keeps_quaternary = flags.matches(IntoScriptFlags::KEEP_QUATERNARIES)
+            || match base.relation {
+                DatalessRelation::VRB => flags.matches(IntoScriptFlags::KEEP_VK_QUATERNARIES),
+                _ => flags.matches(IntoScriptFlags::KEEP_CASE_QUATERNARIES),
+            };
+
         match &base.root {
             ShortcutCheckedFormativeRoot::Normal(NormalFormativeRoot { cr }) => {
                 let mut data = Secondary::cr_or_cs(&cr, false, flags).unwrap_or_else(vec1_h);
 
-                if base.cn == ArbitraryMoodOrCaseScope::FAC_CCN
-                    && !flags.matches(IntoScriptFlags::KEEP_QUATERNARIES)
-                {
+                if base.cn == ArbitraryMoodOrCaseScope::FAC_CCN && !keeps_quaternary {
                     elided_quaternary = true;
 
                     let (superposed, underposed) =
                         Diacritic::elided_quaternary_pair(match base.relation {
-                            DatalessRelation::VRB => match base.vc.as_vk() {
-                                Some(ivl) => ivl.as_general(),
-                                None => VcOrVk::Illocution(Illocution::ASR),
-                            },
+                            DatalessRelation::VRB => base.vc.as_vk_or_usp().as_general(),
                             _ => VcOrVk::Case(base.vc),
                         });
 
@@ -413,8 +450,8 @@ impl IntoScript for Extended<UncheckedFormative> {
                 list.append(*form, flags);
             }
             ShortcutCheckedFormativeRoot::Referential(ReferentialFormativeRoot { referents }) => {
-                let mut data = Secondary::cr_or_cs(&referents.to_string(), true, flags)
-                    .expect("referent lists should become valid Cr roots");
+                let mut data =
+                    Secondary::cr_or_cs(&referents.to_string(), true, flags).unwrap_or_else(vec1_h);
 
                 data.first_mut().superposed = Some(Diacritic::Dot);
 
@@ -423,8 +460,7 @@ impl IntoScript for Extended<UncheckedFormative> {
                 }
             }
             ShortcutCheckedFormativeRoot::Affixual(AffixualFormativeRoot { cs, degree }) => {
-                let mut data = Secondary::cr_or_cs(&cs, true, flags)
-                    .expect("referent lists should become valid Cr roots");
+                let mut data = Secondary::cr_or_cs(&cs, true, flags).unwrap_or_else(vec1_h);
 
                 data.first_mut().underposed = Some(Diacritic::affix_degree(*degree));
 
@@ -455,184 +491,70 @@ impl IntoScript for Extended<UncheckedFormative> {
                         continue;
                     };
 
-                    match (&affix.cs[..], affix.r#type, affix.degree) {
-                        (Valence::CS_FORM, AffixType::T1, degree) => valences.push(match degree {
-                            AffixDegree::D0 => {
-                                new_affixes.push(RegularAffix::Plain(affix));
+                    match (&affix.cs[..], affix.r#type) {
+                        (Valence::CS_FORM, AffixType::T1) => {
+                            if let Some(valence) = Valence::from_affix_degree(affix.degree) {
+                                valences.push(valence);
                                 continue;
                             }
-                            AffixDegree::D1 => Valence::MNO,
-                            AffixDegree::D2 => Valence::PRL,
-                            AffixDegree::D3 => Valence::CRO,
-                            AffixDegree::D4 => Valence::RCP,
-                            AffixDegree::D5 => Valence::CPL,
-                            AffixDegree::D6 => Valence::DUP,
-                            AffixDegree::D7 => Valence::DEM,
-                            AffixDegree::D8 => Valence::CNG,
-                            AffixDegree::D9 => Valence::PTI,
-                        }),
-
-                        (Phase::CS_FORM, AffixType::T1, degree) => {
-                            tertiary_segments.push(TertiarySegment::Phase(match degree {
-                                AffixDegree::D0 => {
-                                    new_affixes.push(RegularAffix::Plain(affix));
-                                    continue;
-                                }
-                                AffixDegree::D1 => Phase::PUN,
-                                AffixDegree::D2 => Phase::ITR,
-                                AffixDegree::D3 => Phase::REP,
-                                AffixDegree::D4 => Phase::ITM,
-                                AffixDegree::D5 => Phase::RCT,
-                                AffixDegree::D6 => Phase::FRE,
-                                AffixDegree::D7 => Phase::FRG,
-                                AffixDegree::D8 => Phase::VAC,
-                                AffixDegree::D9 => Phase::FLC,
-                            }))
-                        }
-
-                        (Effect::CS_FORM, AffixType::T1, degree) => {
-                            tertiary_segments.push(TertiarySegment::Effect(match degree {
-                                AffixDegree::D0 => {
-                                    new_affixes.push(RegularAffix::Plain(affix));
-                                    continue;
-                                }
-                                AffixDegree::D1 => Effect::BEN1,
-                                AffixDegree::D2 => Effect::BEN2,
-                                AffixDegree::D3 => Effect::BEN3,
-                                AffixDegree::D4 => Effect::BENSELF,
-                                AffixDegree::D5 => Effect::UNK,
-                                AffixDegree::D6 => Effect::DETSELF,
-                                AffixDegree::D7 => Effect::DET3,
-                                AffixDegree::D8 => Effect::DET2,
-                                AffixDegree::D9 => Effect::DET1,
-                            }))
                         }
 
-                        (Aspect::CS_FORM_1, AffixType::T1, degree) => {
-                            tertiary_segments.push(TertiarySegment::Aspect(match degree {
-                                AffixDegree::D0 => {
-                                    new_affixes.push(RegularAffix::Plain(affix));
-                                    continue;
-                                }
-                                AffixDegree::D1 => Aspect::RTR,
-                                AffixDegree::D2 => Aspect::PRS,
-                                AffixDegree::D3 => Aspect::HAB,
-                                AffixDegree::D4 => Aspect::PRG,
-                                AffixDegree::D5 => Aspect::IMM,
-                                AffixDegree::D6 => Aspect::PCS,
-                                AffixDegree::D7 => Aspect::REG,
-                                AffixDegree::D8 => Aspect::SMM,
-                                AffixDegree::D9 => Aspect::ATP,
-                            }))
-                        }
-
-                        (Aspect::CS_FORM_2, AffixType::T1, degree) => {
-                            tertiary_segments.push(TertiarySegment::Aspect(match degree {
-                                AffixDegree::D0 => {
-                                    new_affixes.push(RegularAffix::Plain(affix));
-                                    continue;
-                                }
-                                AffixDegree::D1 => Aspect::RSM,
-                                AffixDegree::D2 => Aspect::CSS,
-                                AffixDegree::D3 => Aspect::PAU,
-                                AffixDegree::D4 => Aspect::RGR,
-                                AffixDegree::D5 => Aspect::PCL,
-                                AffixDegree::D6 => Aspect::CNT,
-                                AffixDegree::D7 => Aspect::ICS,
-                                AffixDegree::D8 => Aspect::EXP,
-                                AffixDegree::D9 => Aspect::IRP,
-                            }))
+                        (Phase::CS_FORM, AffixType::T1) => {
+                            if let Some(phase) = Phase::from_affix_degree(affix.degree) {
+                                tertiary_segments.push(TertiarySegment::Phase(phase));
+                                continue;
+                            }
                         }
 
-                        (Aspect::CS_FORM_3, AffixType::T1, degree) => {
-                            tertiary_segments.push(TertiarySegment::Aspect(match degree {
-                                AffixDegree::D0 => {
-                                    new_affixes.push(RegularAffix::Plain(affix));
-                                    continue;
-                                }
-                                AffixDegree::D1 => Aspect::PMP,
-                                AffixDegree::D2 => Aspect::CLM,
-                                AffixDegree::D3 => Aspect::DLT,
-                                AffixDegree::D4 => Aspect::TMP,
-                                AffixDegree::D5 => Aspect::XPD,
-                                AffixDegree::D6 => Aspect::LIM,
-                                AffixDegree::D7 => Aspect::EPD,
-                                AffixDegree::D8 => Aspect::PTC,
-                                AffixDegree::D9 => Aspect::PPR,
-                            }))
+                        (Effect::CS_FORM, AffixType::T1) => {
+                            if let Some(effect) = Effect::from_affix_degree(affix.degree) {
+                                tertiary_segments.push(TertiarySegment::Effect(effect));
+                                continue;
+                            }
                         }
 
-                        (Aspect::CS_FORM_4, AffixType::T1, degree) => {
-                            tertiary_segments.push(TertiarySegment::Aspect(match degree {
-                                AffixDegree::D0 => {
-                                    new_affixes.push(RegularAffix::Plain(affix));
-                                    continue;
-                                }
-                                AffixDegree::D1 => Aspect::DCL,
-                                AffixDegree::D2 => Aspect::CCL,
-                                AffixDegree::D3 => Aspect::CUL,
-                                AffixDegree::D4 => Aspect::IMD,
-                                AffixDegree::D5 => Aspect::TRD,
-                                AffixDegree::D6 => Aspect::TNS,
-                                AffixDegree::D7 => Aspect::ITC,
-                                AffixDegree::D8 => Aspect::MTV,
-                                AffixDegree::D9 => Aspect::SQN,
-                            }))
+                        (Level::CS_FORM, AffixType::T1) => {
+                            if let Some(level) = Level::from_affix_degree(affix.degree) {
+                                relative_levels.push(level);
+                                continue;
+                            }
                         }
 
-                        (Level::CS_FORM, AffixType::T1, degree) => {
-                            relative_levels.push(match degree {
-                                AffixDegree::D0 => {
-                                    new_affixes.push(RegularAffix::Plain(affix));
-                                    continue;
-                                }
-                                AffixDegree::D1 => Level::MIN,
-                                AffixDegree::D2 => Level::SBE,
-                                AffixDegree::D3 => Level::IFR,
-                                AffixDegree::D4 => Level::DFC,
-                                AffixDegree::D5 => Level::EQU,
-                                AffixDegree::D6 => Level::SUR,
-                                AffixDegree::D7 => Level::SPL,
-                                AffixDegree::D8 => Level::SPQ,
-                                AffixDegree::D9 => Level::MAX,
-                            })
+                        (Level::CS_FORM, AffixType::T2) => {
+                            if let Some(level) = Level::from_affix_degree(affix.degree) {
+                                absolute_levels.push(level);
+                                continue;
+                            }
                         }
 
-                        (Level::CS_FORM, AffixType::T2, degree) => {
-                            absolute_levels.push(match degree {
-                                AffixDegree::D0 => {
-                                    new_affixes.push(RegularAffix::Plain(affix));
-                                    continue;
-                                }
-                                AffixDegree::D1 => Level::MIN,
-                                AffixDegree::D2 => Level::SBE,
-                                AffixDegree::D3 => Level::IFR,
-                                AffixDegree::D4 => Level::DFC,
-                                AffixDegree::D5 => Level::EQU,
-                                AffixDegree::D6 => Level::SUR,
-                                AffixDegree::D7 => Level::SPL,
-                                AffixDegree::D8 => Level::SPQ,
-                                AffixDegree::D9 => Level::MAX,
-                            })
+                        (cs, AffixType::T1) => {
+                            if let Some(aspect) = Aspect::from_cs_and_degree(cs, affix.degree) {
+                                tertiary_segments.push(TertiarySegment::Aspect(aspect));
+                                continue;
+                            }
                         }
 
-                        _ => new_affixes.push(RegularAffix::Plain(affix)),
+                        _ => {}
                     }
+
+                    new_affixes.push(RegularAffix::Plain(affix));
                 }
             }
         }
 
         match base.vn {
-            Vn::Valence(Valence::MNO) => {}
+            vn if vn.is_default() => {}
             Vn::Valence(value) => valences.push(value),
             Vn::Phase(value) => tertiary_segments.push(TertiarySegment::Phase(value)),
             Vn::Effect(value) => tertiary_segments.push(TertiarySegment::Effect(value)),
             Vn::Aspect(value) => tertiary_segments.push(TertiarySegment::Aspect(value)),
             Vn::Level(value) => relative_levels.push(value),
+            Vn::AbsoluteLevel(value) => absolute_levels.push(value),
         }
 
         let mut case_stacking_affixes = Vec::new();
         let mut referentials = CharacterBuf::new();
+        let affixes_as_quaternaries = flags.matches(IntoScriptFlags::AFFIXES_AS_QUATERNARIES);
 
         // It's a bit hacky to use `Result` here, but it stops us from creating more one-off enums.
         for (affix_list, slot, is_rotated) in [
@@ -666,14 +588,24 @@ impl IntoScript for Extended<UncheckedFormative> {
                                 let mut data = Secondary::cr_or_cs(&cs, is_rotated, flags)
                                     .unwrap_or_else(vec1_h);
 
-                                let first = data.first_mut();
-                                first.superposed = Diacritic::affix_type(*r#type);
-                                first.rightposed = Diacritic::affix_slot(slot);
-                                first.underposed = Some(Diacritic::affix_degree(*degree));
+                                if !affixes_as_quaternaries {
+                                    let first = data.first_mut();
+                                    first.superposed = Diacritic::affix_type(*r#type);
+                                    first.rightposed = Diacritic::affix_slot(slot);
+                                    first.underposed = Some(Diacritic::affix_degree(*degree));
+                                }
 
                                 for char in data {
                                     list.push(char);
                                 }
+
+                                if affixes_as_quaternaries {
+                                    list.push(AffixQuaternary {
+                                        slot,
+                                        degree: *degree,
+                                        r#type: *r#type,
+                                    });
+                                }
                             }
 
                             RegularAffix::Numeric(NumericAffix {
@@ -683,13 +615,23 @@ impl IntoScript for Extended<UncheckedFormative> {
                             }) => {
                                 let mut data = Secondary::numeral(*form);
 
-                                let first = data.first_mut();
-                                first.superposed = Diacritic::numeric_affix_type(*r#type, slot);
-                                first.underposed = Some(Diacritic::affix_degree(*degree));
+                                if !affixes_as_quaternaries {
+                                    let first = data.first_mut();
+                                    first.superposed = Diacritic::numeric_affix_type(*r#type, slot);
+                                    first.underposed = Some(Diacritic::affix_degree(*degree));
+                                }
 
                                 for char in data {
                                     list.push(char);
                                 }
+
+                                if affixes_as_quaternaries {
+                                    list.push(AffixQuaternary {
+                                        slot,
+                                        degree: *degree,
+                                        r#type: *r#type,
+                                    });
+                                }
                             }
 
                             RegularAffix::Ca(CaStackingAffix { ca }) => {
@@ -698,7 +640,7 @@ impl IntoScript for Extended<UncheckedFormative> {
                                     is_rotated,
                                     flags,
                                 )
-                                .expect("Ca forms should be valid Cs forms");
+                                .unwrap_or_else(vec1_h);
 
                                 let first = data.first_mut();
                                 first.underposed = Some(Diacritic::CA_STACKING_UNDERPOSED);
@@ -749,28 +691,14 @@ impl IntoScript for Extended<UncheckedFormative> {
             }
         }
 
-        while !(valences.is_empty()
-            && tertiary_segments.is_empty()
-            && absolute_levels.is_empty()
-            && relative_levels.is_empty())
-        {
-            let (top, bottom) = if flags.matches(IntoScriptFlags::PREFER_BOTTOM_TERTIARIES) {
-                let bottom = tertiary_segments.pop();
-                let top = tertiary_segments.pop();
-                (top, bottom)
-            } else {
-                let top = tertiary_segments.pop();
-                let bottom = tertiary_segments.pop();
-                (top, bottom)
-            };
-
-            list.push(Tertiary {
-                valence: valences.pop().unwrap_or_default(),
-                top,
-                bottom,
-                superposed: absolute_levels.pop(),
-                underposed: relative_levels.pop(),
-            });
+        for tertiary in Tertiary::from_segments(
+            valences,
+            tertiary_segments,
+            relative_levels,
+            absolute_levels,
+            flags,
+        ) {
+            list.push(tertiary);
         }
 
         for case in case_stacking_affixes {
@@ -786,10 +714,7 @@ impl IntoScript for Extended<UncheckedFormative> {
                 DatalessRelation::VRB => (
                     CaseScope::CCN,
                     base.cn.as_specific(),
-                    match base.vc.as_vk() {
-                        Some(ivl) => ivl.as_general(),
-                        None => VcOrVk::Illocution(Illocution::ASR),
-                    },
+                    base.vc.as_vk_or_usp().as_general(),
                 ),
                 _ => (base.cn.as_specific(), Mood::FAC, VcOrVk::Case(base.vc)),
             };
@@ -801,7 +726,7 @@ impl IntoScript for Extended<UncheckedFormative> {
             });
         }
 
-        list.vec.extend_from_slice(&referentials.vec);
+        list.extend_from(referentials);
     }
 }
 
@@ -814,8 +739,7 @@ fn append_single_referential(
 ) {
     list.push(first_case);
 
-    let mut data = Secondary::cr_or_cs(&referent.to_string(), false, flags)
-        .expect("a valid referent list should generate a valid Cr form");
+    let mut data = Secondary::cr_or_cs(&referent.to_string(), false, flags).unwrap_or_else(vec1_h);
     data.first_mut().superposed = Some(Diacritic::HorizBar);
     for item in data {
         list.push(item);
@@ -984,6 +908,114 @@ mod tests {
         check("ırburučpaızya", r#"\^p_xr_bč'_p_ä|^t^a_aò"#);
     }
 
+    #[test]
+    fn quaternary_elision() {
+        use crate::{prelude::*, script::repr::IthkuilBasicEncoding};
+
+        fn encode(source: &str, flags: IntoScriptFlags) -> String {
+            let word: UncheckedFormative = source.parse().unwrap();
+            IthkuilBasicEncoding::encode(&word, flags).0
+        }
+
+        for (source, is_verbal) in [("malá", true), ("mala", false), ("malo", false)] {
+            let elided = encode(source, IntoScriptFlags::NONE);
+            let kept = encode(source, IntoScriptFlags::KEEP_QUATERNARIES);
+            assert_ne!(elided, kept, "word was {source}");
+
+            let vk = encode(source, IntoScriptFlags::KEEP_VK_QUATERNARIES);
+            let case = encode(source, IntoScriptFlags::KEEP_CASE_QUATERNARIES);
+
+            if is_verbal {
+                assert_eq!(vk, kept, "word was {source}");
+                assert_eq!(case, elided, "word was {source}");
+            } else {
+                assert_eq!(vk, elided, "word was {source}");
+                assert_eq!(case, kept, "word was {source}");
+            }
+        }
+    }
+
+    #[test]
+    fn vn_parity() {
+        use crate::{
+            category::{Aspect, Phase, Valence, Vn},
+            prelude::*,
+        };
+
+        for (source, vn) in [
+            ("malaha", Vn::Valence(Valence::MNO)),
+            ("maliha", Vn::Valence(Valence::RCP)),
+            ("malauha", Vn::Phase(Phase::ITR)),
+            ("malahwa", Vn::Aspect(Aspect::RTR)),
+        ] {
+            let word: UncheckedFormative = source.parse().unwrap();
+            assert_eq!(word.vn, vn, "word was {source}");
+
+            let glossed = word
+                .gloss(GlossFlags::NONE)
+                .split(['-', '.'])
+                .any(|segment| segment == vn.gloss_static(GlossFlags::NONE));
+
+            let scripted = word
+                .into_script(IntoScriptFlags::NONE)
+                .vec
+                .iter()
+                .any(|char| matches!(char, Character::Tertiary(_)));
+
+            assert_eq!(glossed, !vn.is_default(), "word was {source}");
+            assert_eq!(scripted, !vn.is_default(), "word was {source}");
+        }
+    }
+
+    #[test]
+    fn concatenated_buffers() {
+        use crate::{
+            prelude::*,
+            script::{buf::CharacterBuf, repr::IthkuilBasicEncoding},
+        };
+
+        let words: Vec<UncheckedFormative> = ["malá", "lala", "ırburučpaızya"]
+            .into_iter()
+            .map(|source| source.parse().unwrap())
+            .collect();
+
+        let mut sentence = CharacterBuf::new();
+        for word in &words {
+            if !sentence.is_empty() {
+                sentence.push(Character::WordBreak);
+            }
+            sentence.extend_from(word.into_script(IntoScriptFlags::NONE));
+        }
+
+        let collected: CharacterBuf = words
+            .iter()
+            .enumerate()
+            .flat_map(|(index, word)| {
+                let separator = (index != 0).then_some(Character::WordBreak);
+                separator
+                    .into_iter()
+                    .chain(word.into_script(IntoScriptFlags::NONE).vec)
+            })
+            .collect();
+
+        assert_eq!(sentence, collected);
+        assert_eq!(sentence.len(), sentence.iter().count());
+        assert_eq!(
+            sentence
+                .iter()
+                .filter(|char| **char == Character::WordBreak)
+                .count(),
+            2
+        );
+
+        let mut extended = CharacterBuf::new();
+        extended.extend(sentence.iter().copied());
+        assert_eq!(
+            IthkuilBasicEncoding::from_chars(&extended.vec).0,
+            IthkuilBasicEncoding::from_chars(&sentence.vec).0
+        );
+    }
+
     #[test]
     fn referential() {
         use crate::{prelude::*, script::repr::IthkuilBasicEncoding};
@@ -997,4 +1029,428 @@ mod tests {
         check("lo", r#"|_kl^ä"#);
         check("luxar", r#"\l'^ar'_a|_m"#);
     }
+
+    #[test]
+    fn tertiaries() {
+        use crate::{
+            category::{Aspect, Level, Phase, Valence},
+            prelude::*,
+            script::character::{Tertiary, TertiarySegment},
+        };
+
+        assert_eq!(
+            Tertiary::from_segments(
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                IntoScriptFlags::NONE
+            ),
+            [],
+        );
+
+        let segments = vec![
+            TertiarySegment::Phase(Phase::ITR),
+            TertiarySegment::Aspect(Aspect::RTR),
+            TertiarySegment::Aspect(Aspect::HAB),
+        ];
+
+        assert_eq!(
+            Tertiary::from_segments(
+                vec![Valence::PRL],
+                segments.clone(),
+                vec![Level::MIN],
+                Vec::new(),
+                IntoScriptFlags::NONE,
+            ),
+            [
+                Tertiary {
+                    valence: Valence::PRL,
+                    top: Some(TertiarySegment::Aspect(Aspect::HAB)),
+                    bottom: Some(TertiarySegment::Aspect(Aspect::RTR)),
+                    superposed: None,
+                    underposed: Some(Level::MIN),
+                },
+                Tertiary {
+                    valence: Valence::MNO,
+                    top: Some(TertiarySegment::Phase(Phase::ITR)),
+                    bottom: None,
+                    superposed: None,
+                    underposed: None,
+                },
+            ],
+        );
+
+        assert_eq!(
+            Tertiary::from_segments(
+                Vec::new(),
+                segments,
+                Vec::new(),
+                vec![Level::MAX],
+                IntoScriptFlags::PREFER_BOTTOM_TERTIARIES,
+            ),
+            [
+                Tertiary {
+                    valence: Valence::MNO,
+                    top: Some(TertiarySegment::Aspect(Aspect::RTR)),
+                    bottom: Some(TertiarySegment::Aspect(Aspect::HAB)),
+                    superposed: Some(Level::MAX),
+                    underposed: None,
+                },
+                Tertiary {
+                    valence: Valence::MNO,
+                    top: None,
+                    bottom: Some(TertiarySegment::Phase(Phase::ITR)),
+                    superposed: None,
+                    underposed: None,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn bias_secondaries() {
+        use crate::{
+            category::Bias,
+            script::{
+                character::{Core, Diacritic, Secondary},
+                traits::IntoSecondary,
+            },
+        };
+
+        for bias in Bias::ALL_ITEMS {
+            let secondary = bias.into_secondary();
+            assert_eq!(Bias::from_secondary(&secondary), Some(bias), "{bias:?}");
+        }
+
+        let dcc = Bias::DCC.into_secondary();
+
+        assert_eq!(
+            Bias::from_secondary(&Secondary {
+                rightposed: Some(Diacritic::Dot),
+                ..dcc
+            }),
+            None,
+        );
+
+        assert_eq!(
+            Bias::from_secondary(&Secondary {
+                core: Core::H,
+                ..Bias::ACC.into_secondary()
+            }),
+            None,
+        );
+    }
+
+    #[test]
+    fn primary_extensions() {
+        use crate::{
+            category::{Extension, Function, Perspective, Plexity, Stem, Version},
+            script::character::Ext,
+        };
+
+        for extension in Extension::ALL_ITEMS {
+            for perspective in Perspective::ALL_ITEMS {
+                let ext = Ext::primary_top(extension, perspective);
+                assert_eq!(
+                    Ext::decode_primary_top(ext),
+                    Some((extension, perspective)),
+                    "{ext:?}",
+                );
+            }
+        }
+
+        for function in Function::ALL_ITEMS {
+            for version in Version::ALL_ITEMS {
+                for plexity in Plexity::ALL_ITEMS {
+                    for stem in Stem::ALL_ITEMS {
+                        let ext = Ext::primary_bottom(function, version, plexity, stem);
+                        assert_eq!(
+                            Ext::decode_primary_bottom(ext),
+                            Some((function, version, plexity, stem)),
+                            "{ext:?}",
+                        );
+                    }
+                }
+            }
+        }
+
+        assert_eq!(Ext::decode_primary_top(Some(Ext::L)), None);
+        assert_eq!(Ext::decode_primary_bottom(Some(Ext::Ẓ)), None);
+    }
+
+    #[test]
+    fn register_characters() {
+        use crate::{
+            category::RegisterType,
+            script::{
+                character::{Character, Register},
+                traits::IntoCharacter,
+            },
+        };
+
+        let mut seen = Vec::new();
+
+        for register in RegisterType::ALL_ITEMS {
+            let Some(character) = Register::from_register_type(register) else {
+                assert!(
+                    matches!(
+                        register,
+                        RegisterType::DSV | RegisterType::DSV_END | RegisterType::END
+                    ),
+                    "{register:?} has no character"
+                );
+                continue;
+            };
+
+            assert_eq!(
+                character.decode_register_type(register.is_end()),
+                Some(register),
+                "{register:?}"
+            );
+
+            if register.is_start() {
+                assert!(
+                    !seen.contains(&character),
+                    "{register:?} reused {character:?}"
+                );
+                seen.push(character);
+            } else {
+                assert_eq!(
+                    register.closes().map(Register::from_register_type),
+                    Some(Some(character))
+                );
+            }
+
+            assert_eq!(character.into_character(), Character::Register(character));
+        }
+
+        assert_eq!(seen.len(), 4);
+        assert_eq!(Register::AlphabeticPNT.decode_register_type(false), None);
+    }
+
+    #[test]
+    fn inconsistent_unchecked_formatives() {
+        use crate::{
+            affix::{AffixList, CaStackingAffix, RegularAffix},
+            ca,
+            category::{AffixDegree, ArbitraryMoodOrCaseScope, DatalessRelation, ShortcutType},
+            gloss::{Gloss, GlossFlags},
+            prelude::*,
+            word::formative::root::ShortcutCheckedFormativeRoot,
+        };
+
+        let base: UncheckedFormative = "malá".parse().unwrap();
+        let mut formatives = Vec::new();
+
+        // Roots which can't be written in the script.
+        for root in [
+            ShortcutCheckedFormativeRoot::new_normal(""),
+            ShortcutCheckedFormativeRoot::new_normal("aei"),
+            ShortcutCheckedFormativeRoot::new_affixual("", AffixDegree::D1),
+            ShortcutCheckedFormativeRoot::new_affixual("'", AffixDegree::D9),
+            ShortcutCheckedFormativeRoot::new_referential("ẓ").unwrap(),
+        ] {
+            formatives.push(UncheckedFormative {
+                root,
+                ..base.clone()
+            });
+        }
+
+        // Shortcuts which don't match the rest of the formative.
+        formatives.push(UncheckedFormative {
+            shortcut: ShortcutType::Ca,
+            ca: ca!(VAR, DFF, GRA, A, RPV),
+            ..base.clone()
+        });
+        formatives.push(UncheckedFormative {
+            shortcut: ShortcutType::Cn,
+            cn: ArbitraryMoodOrCaseScope::FAC_CCN,
+            ..base.clone()
+        });
+        formatives.push(UncheckedFormative {
+            shortcut: ShortcutType::Ca,
+            relation: DatalessRelation::T2,
+            root: ShortcutCheckedFormativeRoot::new_affixual("rs", AffixDegree::D0),
+            ..base.clone()
+        });
+
+        // A Ca-stacking affix whose Ca is written with `ẓ`.
+        formatives.push(UncheckedFormative {
+            slot_vii_affixes: AffixList::Normal(vec![RegularAffix::Ca(CaStackingAffix {
+                ca: ca!(COA, MSS, PRX),
+            })]),
+            ..base.clone()
+        });
+
+        for formative in formatives {
+            for flags in [
+                GlossFlags::NONE,
+                GlossFlags::SHOW_DEFAULTS | GlossFlags::LONG,
+                GlossFlags::SHOW_SLOTS,
+            ] {
+                formative.gloss(flags);
+            }
+
+            for flags in [
+                IntoScriptFlags::NONE,
+                IntoScriptFlags::ELIDE_PRIMARIES | IntoScriptFlags::PREFER_TOP_EXTENSIONS,
+                IntoScriptFlags::KEEP_QUATERNARIES | IntoScriptFlags::TERTIARIES_AS_AFFIXES,
+            ] {
+                assert!(
+                    !formative.into_script(flags).vec.is_empty(),
+                    "{formative:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn affixes_as_quaternaries() {
+        use crate::{
+            affix::{AffixList, NumericAffix, RegularAffix},
+            category::{AffixDegree, AffixType},
+            prelude::{token::NumeralForm, *},
+            script::repr::IthkuilBasicEncoding,
+        };
+
+        fn check(word: &UncheckedFormative, diacritics: &str, quaternaries: &str) {
+            let encoded = IthkuilBasicEncoding::encode(word, IntoScriptFlags::NONE);
+            assert_eq!(encoded.0, diacritics);
+
+            let encoded =
+                IthkuilBasicEncoding::encode(word, IntoScriptFlags::AFFIXES_AS_QUATERNARIES);
+            assert_eq!(encoded.0, quaternaries);
+        }
+
+        // Slot VII, then slot V, affixes.
+        check(
+            &"malarlai".parse().unwrap(),
+            r"\m^ar'_l_a",
+            r"\m^ar'_l|^i_a",
+        );
+        check(&"marlalla".parse().unwrap(), r"\mr_l_a", r"\mr_l|_a");
+        check(
+            &"malëuţřait".parse().unwrap(),
+            r"\mţ'_ř^a_it'^a_a",
+            r"\mţ'_ř|^ò_it'|^ò_a",
+        );
+
+        let mut word: UncheckedFormative = "mala".parse().unwrap();
+        word.slot_vii_affixes = AffixList::Normal(vec![RegularAffix::Numeric(NumericAffix {
+            form: NumeralForm { integer_part: 12 },
+            r#type: AffixType::T1,
+            degree: AffixDegree::D1,
+        })]);
+
+        let diacritics = IthkuilBasicEncoding::encode(&word, IntoScriptFlags::NONE).0;
+        let quaternaries =
+            IthkuilBasicEncoding::encode(&word, IntoScriptFlags::AFFIXES_AS_QUATERNARIES).0;
+        assert_ne!(diacritics, quaternaries);
+        assert!(quaternaries.contains("|^i_a"));
+    }
+
+    #[test]
+    fn large_numerals() {
+        use crate::{
+            prelude::{token::NumeralForm, *},
+            script::{
+                character::{Character, Numeral},
+                repr::IthkuilBasicEncoding,
+                traits::IntoSecondary,
+            },
+        };
+
+        fn values(integer_part: u64) -> Vec<u16> {
+            Numeral::numeral(NumeralForm { integer_part })
+                .iter()
+                .map(|numeral| numeral.value)
+                .collect()
+        }
+
+        assert_eq!(values(0), [0]);
+        assert_eq!(values(9999), [9999]);
+        assert_eq!(values(10_000), [1, 0]);
+        assert_eq!(values(123_456_789), [1, 2345, 6789]);
+        assert_eq!(values(100_000_005), [1, 0, 5]);
+        assert_eq!(values(u64::MAX), [1844, 6744, 737, 955, 1615]);
+
+        // Every digit is shown, so no two numbers share a rendering.
+        let encode = |integer_part| {
+            IthkuilBasicEncoding::encode(&NumeralForm { integer_part }, IntoScriptFlags::NONE).0
+        };
+        assert_ne!(encode(12_345), encode(2_345));
+        assert_ne!(encode(10_000), encode(1));
+        assert_eq!(
+            Numeral::numeral(NumeralForm {
+                integer_part: 12_345
+            })
+            .len(),
+            2
+        );
+
+        // Characters built by hand only show their last four digits.
+        assert_eq!(
+            Character::Secondary(Numeral { value: 12_345 }.into_secondary()),
+            Character::Secondary(Numeral { value: 2_345 }.into_secondary()),
+        );
+    }
+
+    #[test]
+    fn character_order() {
+        use crate::{
+            affix::{AffixList, PlainAffix, RegularAffix},
+            category::{AffixDegree, AffixType},
+            prelude::*,
+            script::{
+                character::{Character, Core},
+                repr::IthkuilBasicEncoding,
+            },
+            word::Extended,
+        };
+
+        let plain = |cs, degree| RegularAffix::Plain(PlainAffix::new(cs, AffixType::T1, degree));
+
+        // `maloélk` has a single slot VII referential affix.
+        let mut formative: UncheckedFormative = "maloélk".parse().unwrap();
+        formative.slot_v_affixes = AffixList::Normal(vec![plain("r", AffixDegree::D2)]);
+        let AffixList::Normal(slot_vii_affixes) = &mut formative.slot_vii_affixes else {
+            unreachable!()
+        };
+        slot_vii_affixes.insert(0, plain("c", AffixDegree::D4));
+
+        let formative =
+            Extended::new(formative).with_slot_xi_affixes(vec![plain("t", AffixDegree::D3)]);
+
+        let cores: Vec<_> = formative
+            .into_script(IntoScriptFlags::NONE)
+            .vec
+            .into_iter()
+            .map(|char| match char {
+                Character::Secondary(secondary) => secondary.core,
+                char => panic!("expected a secondary, found {char:?}"),
+            })
+            .collect();
+
+        assert_eq!(
+            cores,
+            [
+                // The primary and root.
+                Core::BSC,
+                Core::M,
+                // Slot V, slot VII, and slot XI affixes.
+                Core::R,
+                Core::C,
+                Core::T,
+                // The referential affix, as an ERG quaternary and its referents.
+                Core::VertBar,
+                Core::L,
+            ]
+        );
+
+        assert_eq!(
+            IthkuilBasicEncoding::encode(&formative, IntoScriptFlags::NONE).0,
+            r#"\_amr_ec'_ot_u>a|_kl_k^ä"#,
+        );
+    }
 }
@@ -48,3 +48,92 @@ where
         Some(self.as_specific())
     }
 }
+
+/// Marks a [`CastInto`] implementation backed by [`AsGeneral`].
+#[derive(Clone, Copy, Debug)]
+pub enum ViaGeneral {}
+
+/// Marks a [`CastInto`] implementation backed by [`AsSpecific`].
+#[derive(Clone, Copy, Debug)]
+pub enum ViaSpecific {}
+
+/// Marks a [`CastInto`] implementation backed by [`TryAsGeneral`].
+#[derive(Clone, Copy, Debug)]
+pub enum ViaTryGeneral {}
+
+/// Marks a [`CastInto`] implementation backed by [`TryAsSpecific`].
+#[derive(Clone, Copy, Debug)]
+pub enum ViaTrySpecific {}
+
+/// Converts a value into a more general or more specific form using whichever of the four
+/// specificity traits applies. `Via` is one of the `Via*` marker types and is always inferred.
+///
+/// Infallible casts produce `T` directly, while fallible casts produce an [`Option<T>`].
+pub trait CastInto<T, Via>: Sized {
+    /// Converts `self` into a `T`.
+    fn cast_into(self) -> T;
+}
+
+impl<T, This> CastInto<T, ViaGeneral> for This
+where
+    This: AsGeneral<T>,
+    T: TryAsSpecific<This> + From<This>,
+{
+    fn cast_into(self) -> T {
+        self.as_general()
+    }
+}
+
+impl<T, This> CastInto<T, ViaSpecific> for This
+where
+    This: AsSpecific<T>,
+{
+    fn cast_into(self) -> T {
+        self.as_specific()
+    }
+}
+
+impl<T, This> CastInto<Option<T>, ViaTryGeneral> for This
+where
+    This: TryAsGeneral<T>,
+    T: TryAsSpecific<This>,
+{
+    fn cast_into(self) -> Option<T> {
+        self.try_as_general()
+    }
+}
+
+impl<T, This> CastInto<Option<T>, ViaTrySpecific> for This
+where
+    This: TryAsSpecific<T>,
+{
+    fn cast_into(self) -> Option<T> {
+        self.try_as_specific()
+    }
+}
+
+/// Provides a single `cast` method in place of [`AsGeneral::as_general`],
+/// [`AsSpecific::as_specific`], [`TryAsGeneral::try_as_general`], and
+/// [`TryAsSpecific::try_as_specific`].
+///
+/// ```
+/// # use tnil::prelude::*;
+/// use tnil::category::{ArbitraryMoodOrCaseScope, Mood, NonDefaultMood};
+///
+/// let general: ArbitraryMoodOrCaseScope = Mood::SUB.cast();
+/// assert_eq!(general, ArbitraryMoodOrCaseScope::SUB_CCA);
+///
+/// assert_eq!(general.cast::<Mood, _>(), Mood::SUB);
+/// assert_eq!(Mood::FAC.cast::<Option<NonDefaultMood>, _>(), None);
+/// ```
+pub trait Cast: Sized {
+    /// Converts `self` into a `T`, which is an [`Option`] if the conversion may fail.
+    fn cast<T, Via>(self) -> T
+    where
+        Self: CastInto<T, Via>,
+    {
+        self.cast_into()
+    }
+}
+
+impl<T> Cast for T {}
@@ -39,8 +39,14 @@ impl Gloss for MultipleAffixAdjunct {
         for affix in &self.other_affixes {
             output.add_dashed(&affix.gloss(flags));
         }
+        // A missing Vz means the other affixes share the scope of the first affix, so an explicit
+        // default scope must still be shown if it differs from that of the first affix.
         if let Some(other_scope) = self.other_scope {
-            output.add_dashed(other_scope.gloss_static_non_default(flags));
+            if other_scope != self.first_scope {
+                output.add_dashed(other_scope.gloss_static(flags));
+            } else {
+                output.add_dashed(other_scope.gloss_static_non_default(flags));
+            }
         }
         output.add_dashed(self.mode.gloss_static_non_default(flags));
         output
@@ -87,7 +93,15 @@ impl FromTokens for MultipleAffixAdjunct {
 impl IntoTokens for MultipleAffixAdjunct {
     fn append_tokens_to(&self, list: &mut TokenList, flags: IntoTokensFlags) {
         let (mut first_vx, first_cs) = self.first_affix.into_vx_cs();
-        if flags.matches(IntoTokensFlags::WORD_INITIAL_VOWEL) || !first_cs.is_valid_word_initial() {
+        // A Cz of 'hl or 'hr would otherwise be read as the Cn of a formative whose Vc glottal
+        // stop was moved into Vr, so these scopes always take a leading ë.
+        if flags.matches(IntoTokensFlags::WORD_INITIAL_VOWEL)
+            || !first_cs.is_valid_word_initial()
+            || matches!(
+                self.first_scope,
+                AffixualAdjunctScope::VIIDom | AffixualAdjunctScope::VIISub
+            )
+        {
             list.push(Schwa);
         }
         let (needs_glottal_stop, first_cz) = match self.first_scope {
@@ -124,5 +138,9 @@ impl IntoTokens for MultipleAffixAdjunct {
         } else if flags.matches(IntoTokensFlags::WORD_FINAL_VOWEL) {
             list.push(Vz { scope: None });
         }
+        list.set_stress(match self.mode {
+            AffixualAdjunctMode::Concatenated => Stress::Ultimate,
+            AffixualAdjunctMode::Full => Stress::Penultimate,
+        });
     }
 }
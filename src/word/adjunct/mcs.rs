@@ -1,7 +1,7 @@
 //! Defines types related to mood/case-scope adjuncts.
 
 use crate::{
-    category::MoodOrCaseScope,
+    category::{ModularAdjunctMode, MoodOrCaseScope, NonAspectualVn, Valence, Vn},
     gloss::{GlossFlags, GlossStatic},
     prelude::{IntoTokens, IntoTokensFlags, TokenList},
     romanize::{
@@ -10,6 +10,7 @@ use crate::{
         token::Hr,
         traits::FromTokens,
     },
+    word::ModularAdjunct,
 };
 
 /// A mood/case-scope adjunct.
@@ -19,6 +20,34 @@ pub struct MCSAdjunct {
     pub mcs: MoodOrCaseScope,
 }
 
+impl MCSAdjunct {
+    /// Converts this adjunct into the modular adjunct which marks the same mood or case-scope in
+    /// current Ithkuil.
+    ///
+    /// Mood/case-scope adjuncts were replaced by the Cn slot of modular adjuncts, so the result is
+    /// a [`ModularAdjunct::NonScoped`] in full mode whose Cn is this adjunct's mood or case-scope
+    /// and whose Vn slots are the default MNO valence. Every outdated adjunct has such an
+    /// equivalent, so this conversion cannot fail. However, a Cn does not record whether it is a
+    /// mood or a case-scope; that is decided by the relation of the formative it applies to, as
+    /// with any other modular adjunct.
+    ///
+    /// ```
+    /// # use tnil::prelude::*;
+    /// let adjunct: word::MCSAdjunct = "hre".parse()?;
+    /// assert_eq!(adjunct.to_modern().to_string(), "ahla");
+    /// # Ok::<(), tnil::romanize::stream::ParseError>(())
+    /// ```
+    pub fn to_modern(self) -> ModularAdjunct {
+        ModularAdjunct::NonScoped {
+            mode: ModularAdjunctMode::Full,
+            vn1: Vn::Valence(Valence::MNO),
+            cn: self.mcs.into(),
+            vn2: None,
+            vn3: NonAspectualVn::Valence(Valence::MNO),
+        }
+    }
+}
+
 impl GlossStatic for MCSAdjunct {
     fn gloss_static(&self, flags: GlossFlags) -> &'static str {
         self.mcs.gloss_static(flags)
@@ -12,7 +12,7 @@ use crate::{
     },
 };
 
-/// A parsing adjunct.
+/// A parsing adjunct, which marks the stress of the word after it.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ParsingAdjunct {
     /// The stress indicated by this adjunct.
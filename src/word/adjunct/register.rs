@@ -10,7 +10,9 @@ use crate::{
         token::Hh,
         traits::FromTokens,
     },
+    word::Word,
 };
+use std::{error::Error, fmt};
 
 /// A register adjunct.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -41,3 +43,109 @@ impl IntoTokens for RegisterAdjunct {
         list.push(self.register);
     }
 }
+
+/// An error arising from incorrectly nested registers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegisterError {
+    /// A register was closed without any register being open.
+    UnmatchedEnd {
+        /// The index of the word closing the register.
+        index: usize,
+
+        /// The register that closed nothing.
+        register: RegisterType,
+    },
+
+    /// A register was closed by the end register of a different register.
+    MismatchedEnd {
+        /// The index of the word which opened the innermost open register.
+        start_index: usize,
+
+        /// The innermost open register.
+        start: RegisterType,
+
+        /// The index of the word closing the register.
+        end_index: usize,
+
+        /// The register which attempted to close `start`.
+        end: RegisterType,
+    },
+
+    /// A register was opened but never closed.
+    Unclosed {
+        /// The index of the word which opened the register.
+        index: usize,
+
+        /// The register that was never closed.
+        register: RegisterType,
+    },
+}
+
+impl fmt::Display for RegisterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnmatchedEnd { index, register } => {
+                write!(f, "word {index} closes {register:?} but no register is open")
+            }
+            Self::MismatchedEnd {
+                start_index,
+                start,
+                end_index,
+                end,
+            } => write!(
+                f,
+                "word {end_index} closes {end:?} but the innermost register is {start:?} (opened by word {start_index})"
+            ),
+            Self::Unclosed { index, register } => {
+                write!(f, "word {index} opens {register:?} but it is never closed")
+            }
+        }
+    }
+}
+
+impl Error for RegisterError {}
+
+/// Checks that the registers opened and closed by the register adjuncts in `words` are properly
+/// nested.
+///
+/// Each start register (such as [`RegisterType::DSV`]) must be closed either by its paired end
+/// register (such as [`RegisterType::DSV_END`]) or by the general [`RegisterType::END`], which
+/// closes whichever register is innermost. Registers may nest, but must be closed in the reverse
+/// order they were opened. Words other than register adjuncts are ignored.
+///
+/// On failure, the returned error reports the indices into `words` of the offending adjuncts. If
+/// several registers are left unclosed, the outermost one is reported.
+pub fn validate_register_nesting(words: &[Word]) -> Result<(), RegisterError> {
+    let mut open: Vec<(usize, RegisterType)> = Vec::new();
+
+    for (index, word) in words.iter().enumerate() {
+        let Word::Register(RegisterAdjunct { register }) = *word else {
+            continue;
+        };
+
+        if register.is_start() {
+            open.push((index, register));
+            continue;
+        }
+
+        let Some((start_index, start)) = open.pop() else {
+            return Err(RegisterError::UnmatchedEnd { index, register });
+        };
+
+        if let Some(closes) = register.closes() {
+            if closes != start {
+                return Err(RegisterError::MismatchedEnd {
+                    start_index,
+                    start,
+                    end_index: index,
+                    end: register,
+                });
+            }
+        }
+    }
+
+    match open.first() {
+        Some(&(index, register)) => Err(RegisterError::Unclosed { index, register }),
+        None => Ok(()),
+    }
+}
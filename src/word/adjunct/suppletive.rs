@@ -1,15 +1,17 @@
 //! Contains types related to suppletive adjuncts.
 
 use crate::{
-    category::{Case, SuppletiveAdjunctMode},
-    gloss::{Gloss, GlossFlags, GlossHelpers, GlossStatic},
+    category::{Case, RegisterType, SuppletiveAdjunctMode},
+    gloss::{escape_if_requested, Gloss, GlossFlags, GlossHelpers, GlossStatic},
     prelude::{IntoTokens, IntoTokensFlags, TokenList},
     romanize::{
         flags::FromTokenFlags,
         stream::{ParseError, TokenStream},
         traits::FromTokens,
     },
+    word::{RegisterAdjunct, Word},
 };
+use std::{fmt, str::FromStr};
 
 /// A suppletive adjunct.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -44,3 +46,193 @@ impl IntoTokens for SuppletiveAdjunct {
         list.push(self.case);
     }
 }
+
+impl SuppletiveAdjunct {
+    /// Checks whether the content carried by this adjunct is raw text (for the CAR and NAM modes)
+    /// rather than a phrase of Ithkuil words (for the QUO and PHR modes).
+    pub fn carries_raw_text(self) -> bool {
+        matches!(
+            self.mode,
+            SuppletiveAdjunctMode::CAR | SuppletiveAdjunctMode::NAM
+        )
+    }
+}
+
+/// A suppletive adjunct together with the content it carries.
+///
+/// In romanized text, the content follows the adjunct. Raw content, used by the CAR and NAM
+/// modes, is exactly one word, which is taken verbatim and need not be valid Ithkuil. Phrasal
+/// content, used by the QUO and PHR modes, is every following word up to an END register adjunct
+/// (`hü`), which closes the phrase and is not part of it. Carriers may be nested inside phrases,
+/// in which case each one consumes its own content and terminator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CarrierAdjunct {
+    /// The suppletive adjunct introducing the content.
+    pub adjunct: SuppletiveAdjunct,
+
+    /// The content carried by the adjunct.
+    pub content: CarriedContent,
+}
+
+/// The content carried by a [`CarrierAdjunct`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CarriedContent {
+    /// A single word of raw text, such as a name or foreign word. It should be non-empty and
+    /// contain no whitespace so that it can be romanized unambiguously.
+    Raw(String),
+
+    /// A phrase of Ithkuil words.
+    Phrase(Vec<CarriedWord>),
+}
+
+/// A word inside the phrasal content of a [`CarrierAdjunct`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CarriedWord {
+    /// A plain word. This should never be a [`Word::Suppletive`], as suppletive adjuncts always
+    /// carry content of their own, or an END register adjunct, as that would end the phrase. Such
+    /// words are never produced by parsing, but nothing prevents constructing them.
+    Word(Word),
+
+    /// A nested carrier adjunct and its content.
+    Carrier(CarrierAdjunct),
+}
+
+/// The register adjunct which terminates phrasal content.
+const PHRASE_END: RegisterAdjunct = RegisterAdjunct {
+    register: RegisterType::END,
+};
+
+impl CarrierAdjunct {
+    /// Parses a carrier adjunct and its content from the start of `words`, leaving any words after
+    /// the content untouched.
+    fn parse_words<'a>(
+        adjunct: SuppletiveAdjunct,
+        words: &mut impl Iterator<Item = &'a str>,
+        flags: FromTokenFlags,
+    ) -> Result<Self, ParseError> {
+        if adjunct.carries_raw_text() {
+            let text = words.next().ok_or(ParseError::ExpectedCarriedContent)?;
+
+            return Ok(Self {
+                adjunct,
+                content: CarriedContent::Raw(text.to_owned()),
+            });
+        }
+
+        let mut phrase = Vec::new();
+
+        loop {
+            let word = words.next().ok_or(ParseError::UnterminatedCarriedPhrase)?;
+
+            phrase.push(match Word::parse_str(word, flags)? {
+                Word::Register(PHRASE_END) => break,
+                Word::Suppletive(adjunct) => {
+                    CarriedWord::Carrier(Self::parse_words(adjunct, words, flags)?)
+                }
+                word => CarriedWord::Word(word),
+            });
+        }
+
+        Ok(Self {
+            adjunct,
+            content: CarriedContent::Phrase(phrase),
+        })
+    }
+
+    /// Parses a carrier adjunct and its content from romanized text.
+    ///
+    /// Returns [`ParseError::ExpectedCarriedContent`] if raw content is missing,
+    /// [`ParseError::UnterminatedCarriedPhrase`] if a phrase has no END register adjunct, and
+    /// [`ParseError::TooManyWords`] if words remain after the content.
+    pub fn parse_str(source: &str, flags: FromTokenFlags) -> Result<Self, ParseError> {
+        let mut words = source.split_whitespace();
+        let adjunct = words.next().ok_or(ParseError::WordEmpty)?;
+        let adjunct = SuppletiveAdjunct::parse_str(adjunct, flags)?;
+        let carrier = Self::parse_words(adjunct, &mut words, flags)?;
+
+        match words.next() {
+            Some(_) => Err(ParseError::TooManyWords),
+            None => Ok(carrier),
+        }
+    }
+
+    /// Appends the romanization of this adjunct and its content to `output`, separating words with
+    /// spaces. `flags` apply to every Ithkuil word.
+    fn write_words(&self, output: &mut String, flags: IntoTokensFlags) {
+        self.adjunct.write_ithkuil(output, flags);
+
+        match &self.content {
+            CarriedContent::Raw(text) => {
+                output.push(' ');
+                output.push_str(text);
+            }
+
+            CarriedContent::Phrase(phrase) => {
+                for word in phrase {
+                    output.push(' ');
+
+                    match word {
+                        CarriedWord::Word(word) => word.write_ithkuil(output, flags),
+                        CarriedWord::Carrier(carrier) => carrier.write_words(output, flags),
+                    }
+                }
+
+                output.push(' ');
+                PHRASE_END.write_ithkuil(output, flags);
+            }
+        }
+    }
+
+    /// Romanizes this adjunct and its content using `flags`.
+    ///
+    /// If a [`CarriedWord::Word`] in the content is a [`Word::Suppletive`] or an END register
+    /// adjunct, it is written as is, and the output can't be read back by
+    /// [`CarrierAdjunct::parse_str`]: the suppletive adjunct would take the following words as its
+    /// own content, and the register adjunct would end the phrase early.
+    pub fn to_string_with(&self, flags: IntoTokensFlags) -> String {
+        let mut output = String::new();
+        self.write_words(&mut output, flags);
+        output
+    }
+}
+
+impl Gloss for CarrierAdjunct {
+    fn gloss(&self, flags: GlossFlags) -> String {
+        let mut output = self.adjunct.gloss(flags);
+        output.push_str(" “");
+
+        match &self.content {
+            CarriedContent::Raw(text) => output.push_str(&escape_if_requested(text, flags)),
+
+            CarriedContent::Phrase(phrase) => {
+                let words: Vec<_> = phrase
+                    .iter()
+                    .map(|word| match word {
+                        CarriedWord::Word(word) => word.gloss(flags),
+                        CarriedWord::Carrier(carrier) => carrier.gloss(flags),
+                    })
+                    .collect();
+
+                output.push_str(&words.join(" "));
+            }
+        }
+
+        output.push('”');
+        output
+    }
+}
+
+impl FromStr for CarrierAdjunct {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s, FromTokenFlags::NONE)
+    }
+}
+
+/// Writes the romanization of this adjunct followed by its content.
+impl fmt::Display for CarrierAdjunct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_with(IntoTokensFlags::NONE))
+    }
+}
@@ -181,7 +181,7 @@ macro_rules! as_general_impl {
         $specification_value:expr,
     ) => {
         paste! {
-            #[allow(unused_parens)]
+            #[allow(unused_parens, clippy::redundant_field_names)]
             impl AsGeneral<$general> for $specific {
                 fn as_general(self) -> $general {
                     #[allow(unused_variables)]
@@ -209,14 +209,14 @@ macro_rules! as_general_impl {
                 }
             }
 
-            #[allow(unused_parens)]
+            #[allow(unused_parens, clippy::redundant_field_names)]
             impl From<$specific> for $general {
                 fn from(value: $specific) -> Self {
                     value.as_general()
                 }
             }
 
-            #[allow(unused_parens)]
+            #[allow(unused_parens, clippy::redundant_field_names)]
             impl TryAsSpecific<$specific> for $general {
                 fn try_as_specific(self) -> Option<$specific> {
                     #[allow(unused_variables)]
@@ -259,8 +259,8 @@ as_general_impl!(
     specification,                   // the specification field (because macro hygiene)
     (AffixShortcut::None),           // the general value to put in `affix_shortcut`
     (),                              // the specific value to put in `affix_shortcut`
-    (Specification::BSC),            // the general value to put in `specification`
-    (Specification::BSC),            // the specific value to put in `specification`
+    specification,                   // the general value to put in `specification`
+    specification,                   // the specific value to put in `specification`
 );
 
 // Note that parentheses are required around the `Some(...)` patterns because we match them as token
@@ -290,8 +290,8 @@ as_general_impl!(
     specification,
     (AffixShortcut::None),
     (),
-    (Specification::BSC),
-    (Specification::BSC),
+    specification,
+    specification,
 );
 
 as_general_impl!(
@@ -0,0 +1,317 @@
+//! Provides a way to compare two formatives category by category.
+
+use crate::{
+    affix::{AffixList, RegularAffix},
+    category::{AffixDegree, AffixShortcut, ShortcutType, Specification, Stem},
+    gloss::{Gloss, GlossFlags},
+    word::{formative::root::ShortcutCheckedFormativeRoot, UncheckedFormative},
+};
+
+/// A field of an [`UncheckedFormative`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FormativeField {
+    /// The relation of a formative.
+    Relation,
+
+    /// The shortcut type of a formative.
+    Shortcut,
+
+    /// The stem of a formative.
+    Stem,
+
+    /// The version of a formative.
+    Version,
+
+    /// The affix shortcut of a formative.
+    AffixShortcut,
+
+    /// The root of a formative.
+    Root,
+
+    /// The function of a formative.
+    Function,
+
+    /// The specification of a formative.
+    Specification,
+
+    /// The context of a formative.
+    Context,
+
+    /// The slot V affixes of a formative.
+    SlotVAffixes,
+
+    /// The Ca of a formative.
+    Ca,
+
+    /// The slot VII affixes of a formative.
+    SlotVIIAffixes,
+
+    /// The Vn of a formative.
+    Vn,
+
+    /// The Cn of a formative.
+    Cn,
+
+    /// The Vc/Vk of a formative.
+    Vc,
+}
+
+/// A change between two values of a single formative field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldChange {
+    /// The field has a different value. Both values are glossed with defaults shown.
+    Changed {
+        /// The gloss of the original value.
+        old: String,
+
+        /// The gloss of the new value.
+        new: String,
+    },
+
+    /// An affix is present only in the new affix list.
+    AffixAdded {
+        /// The index of the affix in the new affix list.
+        index: usize,
+
+        /// The gloss of the added affix.
+        affix: String,
+    },
+
+    /// An affix is present only in the original affix list.
+    AffixRemoved {
+        /// The index of the affix in the original affix list.
+        index: usize,
+
+        /// The gloss of the removed affix.
+        affix: String,
+    },
+
+    /// A plain affix is present in both affix lists, but with a different degree.
+    AffixDegreeChanged {
+        /// The index of the affix in the original affix list.
+        old_index: usize,
+
+        /// The index of the affix in the new affix list.
+        new_index: usize,
+
+        /// The Cs form of the affix.
+        cs: String,
+
+        /// The degree of the affix in the original affix list.
+        old: AffixDegree,
+
+        /// The degree of the affix in the new affix list.
+        new: AffixDegree,
+    },
+}
+
+/// A difference between two formatives in a single field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// The field which differs.
+    pub field: FormativeField,
+
+    /// How the field differs.
+    pub change: FieldChange,
+}
+
+/// Checks whether two affixes should be aligned with each other when diffing affix lists. Plain
+/// affixes are aligned if they have the same Cs form and type, so that a change in degree shows up
+/// as a single change instead of a removal and an addition.
+fn is_same_affix(a: &RegularAffix, b: &RegularAffix) -> bool {
+    match (a, b) {
+        (RegularAffix::Plain(a), RegularAffix::Plain(b)) => a.cs == b.cs && a.r#type == b.r#type,
+        _ => a == b,
+    }
+}
+
+/// Diffs two lists of regular affixes, pushing each difference into `output`.
+fn diff_affixes(
+    field: FormativeField,
+    old: &[RegularAffix],
+    new: &[RegularAffix],
+    output: &mut Vec<FieldDiff>,
+) {
+    // `lengths[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if is_same_affix(&old[i], &new[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let flags = GlossFlags::SHOW_DEFAULTS;
+    let (mut i, mut j) = (0, 0);
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && is_same_affix(&old[i], &new[j]) {
+            match (&old[i], &new[j]) {
+                (a, b) if a == b => {}
+
+                (RegularAffix::Plain(a), RegularAffix::Plain(b)) => output.push(FieldDiff {
+                    field,
+                    change: FieldChange::AffixDegreeChanged {
+                        old_index: i,
+                        new_index: j,
+                        cs: a.cs.clone(),
+                        old: a.degree,
+                        new: b.degree,
+                    },
+                }),
+
+                _ => unreachable!("non-plain affixes are only aligned when they are equal"),
+            }
+
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+            output.push(FieldDiff {
+                field,
+                change: FieldChange::AffixAdded {
+                    index: j,
+                    affix: new[j].gloss(flags),
+                },
+            });
+
+            j += 1;
+        } else {
+            output.push(FieldDiff {
+                field,
+                change: FieldChange::AffixRemoved {
+                    index: i,
+                    affix: old[i].gloss(flags),
+                },
+            });
+
+            i += 1;
+        }
+    }
+}
+
+/// Diffs two affix lists, pushing each difference into `output`.
+fn diff_affix_lists(
+    field: FormativeField,
+    old: &AffixList,
+    new: &AffixList,
+    output: &mut Vec<FieldDiff>,
+) {
+    match (old, new) {
+        (AffixList::Normal(old), AffixList::Normal(new)) => diff_affixes(field, old, new, output),
+        _ if old == new => {}
+        _ => output.push(FieldDiff {
+            field,
+            change: FieldChange::Changed {
+                old: old.gloss(GlossFlags::SHOW_DEFAULTS),
+                new: new.gloss(GlossFlags::SHOW_DEFAULTS),
+            },
+        }),
+    }
+}
+
+impl UncheckedFormative {
+    /// Lists the differences between `self` and `other`, in slot order.
+    ///
+    /// Differing values are glossed with defaults shown, so that a change to or from a default
+    /// value is still readable. Affix lists are compared affix by affix, and report affixes which
+    /// were added, removed, or changed in degree.
+    pub fn diff(&self, other: &Self) -> Vec<FieldDiff> {
+        let mut output = Vec::new();
+
+        macro_rules! diff {
+            ($($field:ident => $variant:ident,)+) => {
+                $(if self.$field != other.$field {
+                    output.push(FieldDiff {
+                        field: FormativeField::$variant,
+                        change: FieldChange::Changed {
+                            old: self.$field.gloss(GlossFlags::SHOW_DEFAULTS),
+                            new: other.$field.gloss(GlossFlags::SHOW_DEFAULTS),
+                        },
+                    });
+                })+
+            };
+        }
+
+        diff!(
+            relation => Relation,
+            shortcut => Shortcut,
+            stem => Stem,
+            version => Version,
+            affix_shortcut => AffixShortcut,
+            root => Root,
+            function => Function,
+            specification => Specification,
+            context => Context,
+        );
+
+        diff_affix_lists(
+            FormativeField::SlotVAffixes,
+            &self.slot_v_affixes,
+            &other.slot_v_affixes,
+            &mut output,
+        );
+
+        diff!(ca => Ca,);
+
+        diff_affix_lists(
+            FormativeField::SlotVIIAffixes,
+            &self.slot_vii_affixes,
+            &other.slot_vii_affixes,
+            &mut output,
+        );
+
+        diff!(
+            vn => Vn,
+            cn => Cn,
+            vc => Vc,
+        );
+
+        output
+    }
+
+    /// Copies `self`, resetting fields which are not encoded in the romanized word to their
+    /// defaults, so that two formatives written identically compare equal.
+    fn normalized(&self) -> Self {
+        let mut output = self.clone();
+
+        output.shortcut = ShortcutType::Normal;
+
+        match output.root {
+            ShortcutCheckedFormativeRoot::Normal(_) | ShortcutCheckedFormativeRoot::Numeric(_) => {}
+
+            ShortcutCheckedFormativeRoot::Referential(_) => {
+                output.stem = Stem::default();
+                output.affix_shortcut = AffixShortcut::None;
+            }
+
+            ShortcutCheckedFormativeRoot::Affixual(_) => {
+                output.stem = Stem::default();
+                output.affix_shortcut = AffixShortcut::None;
+                output.specification = Specification::default();
+            }
+        }
+
+        output
+    }
+
+    /// Checks whether `self` and `other` have the same meaning, even if they differ structurally.
+    ///
+    /// Unlike the derived [`PartialEq`] implementation, this ignores:
+    ///
+    /// - the [`shortcut`](UncheckedFormative::shortcut) of each formative, which only affects how
+    ///   its Ca or Cn is written;
+    /// - the stem and affix shortcut of formatives with referential or affixual roots, which have
+    ///   no way to express them; and
+    /// - the specification of formatives with affixual roots, whose Vr encodes an affix degree
+    ///   instead.
+    ///
+    /// Affix order is always significant, as it determines the scope of each affix, and Ca-stacking
+    /// affixes are never merged into the Ca of the formative.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
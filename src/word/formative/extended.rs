@@ -1,10 +1,29 @@
 use crate::{
     affix::RegularAffix,
-    prelude::{AsGeneral, TryAsSpecific},
-    word::{CheckedFormative, ShortcutCheckedFormative, UncheckedFormative},
+    category::{AffixualAdjunctMode, AffixualAdjunctScope},
+    prelude::{
+        AsGeneral, FromTokenFlags, FromTokens, IntoTokens, IntoTokensFlags, ParseError,
+        TryAsSpecific,
+    },
+    word::{
+        AffixualAdjunct, CheckedFormative, MultipleAffixAdjunct, ShortcutCheckedFormative,
+        SingleAffixAdjunct, UncheckedFormative,
+    },
 };
+use std::{fmt, str::FromStr};
+use vec1::Vec1;
 
 /// A formative with slot XI affixes that scope over the whole formative.
+///
+/// Slot XI only exists in Ithkuil script, where affixes scoping over an entire formative are
+/// written directly after it. In romanized text, the same affixes are carried by a separate
+/// affixual adjunct, so they are not part of the romanized formative itself. For this reason,
+/// the base formative types ([`CheckedFormative`], [`ShortcutCheckedFormative`], and
+/// [`UncheckedFormative`]) have no slot XI, and this wrapper adds one when it is needed, such as
+/// when glossing or writing a formative in script.
+///
+/// Any base formative can be converted into an [`Extended`] formative with no slot XI affixes
+/// using [`Extended::new`] or `.as_general()`.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Extended<T> {
     /// The base of this formative.
@@ -14,6 +33,152 @@ pub struct Extended<T> {
     pub slot_xi_affixes: Vec<RegularAffix>,
 }
 
+impl<T> Extended<T> {
+    /// Creates an [`Extended`] formative with no slot XI affixes.
+    pub const fn new(base: T) -> Self {
+        Self {
+            base,
+            slot_xi_affixes: Vec::new(),
+        }
+    }
+
+    /// Replaces the slot XI affixes of this formative.
+    pub fn with_slot_xi_affixes(mut self, slot_xi_affixes: Vec<RegularAffix>) -> Self {
+        self.slot_xi_affixes = slot_xi_affixes;
+        self
+    }
+
+    /// Appends an affix to slot XI of this formative.
+    pub fn push_slot_xi_affix(&mut self, affix: RegularAffix) {
+        self.slot_xi_affixes.push(affix);
+    }
+
+    /// Unwraps this formative into its base, discarding any slot XI affixes.
+    pub fn into_base(self) -> T {
+        self.base
+    }
+
+    /// Gets the affixual adjunct which carries the slot XI affixes of this formative in romanized
+    /// text, or [`None`] if it has no slot XI affixes.
+    ///
+    /// The adjunct has formative scope and the full mode. It is a single-affix adjunct if there is
+    /// one slot XI affix, and a multiple-affix adjunct whose other affixes share the scope of the
+    /// first otherwise.
+    pub fn slot_xi_adjunct(&self) -> Option<AffixualAdjunct> {
+        let (first_affix, other_affixes) = self.slot_xi_affixes.split_first()?;
+
+        Some(match Vec1::try_from(other_affixes) {
+            Ok(other_affixes) => AffixualAdjunct::Multiple(MultipleAffixAdjunct {
+                first_affix: first_affix.clone(),
+                first_scope: AffixualAdjunctScope::Formative,
+                other_affixes,
+                other_scope: None,
+                mode: AffixualAdjunctMode::Full,
+            }),
+
+            Err(_) => AffixualAdjunct::Single(SingleAffixAdjunct {
+                affix: first_affix.clone(),
+                scope: AffixualAdjunctScope::Formative,
+                mode: AffixualAdjunctMode::Full,
+            }),
+        })
+    }
+}
+
+/// Gets the affixes of an adjunct produced by [`Extended::slot_xi_adjunct`], or [`None`] if the
+/// adjunct has any other scope or mode.
+fn slot_xi_affixes(adjunct: AffixualAdjunct) -> Option<Vec<RegularAffix>> {
+    const FORMATIVE: AffixualAdjunctScope = AffixualAdjunctScope::Formative;
+
+    match adjunct {
+        AffixualAdjunct::Single(SingleAffixAdjunct {
+            affix,
+            scope: FORMATIVE,
+            mode: AffixualAdjunctMode::Full,
+        }) => Some(vec![affix]),
+
+        AffixualAdjunct::Multiple(MultipleAffixAdjunct {
+            first_affix,
+            first_scope: FORMATIVE,
+            other_affixes,
+            other_scope: None | Some(FORMATIVE),
+            mode: AffixualAdjunctMode::Full,
+        }) => {
+            let mut affixes = vec![first_affix];
+            affixes.extend(other_affixes);
+            Some(affixes)
+        }
+
+        _ => None,
+    }
+}
+
+impl<T: IntoTokens> Extended<T> {
+    /// Romanizes this formative using `flags`.
+    ///
+    /// If there are slot XI affixes, the result is two words: the affixual adjunct given by
+    /// [`slot_xi_adjunct`][Self::slot_xi_adjunct], a space, and then the base formative.
+    /// Otherwise, it is just the base formative. `flags` only apply to the formative.
+    pub fn to_string_with(&self, flags: IntoTokensFlags) -> String {
+        let mut output = String::new();
+
+        if let Some(adjunct) = self.slot_xi_adjunct() {
+            adjunct.write_ithkuil(&mut output, IntoTokensFlags::NONE);
+            output.push(' ');
+        }
+
+        self.base.write_ithkuil(&mut output, flags);
+        output
+    }
+}
+
+/// Writes the romanization of this formative, preceded by the affixual adjunct which carries its
+/// slot XI affixes if it has any.
+impl<T: IntoTokens> fmt::Display for Extended<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_with(IntoTokensFlags::NONE))
+    }
+}
+
+impl<T: FromTokens> Extended<T> {
+    /// Parses an extended formative from its romanization, as written by
+    /// [`to_string_with`][Self::to_string_with].
+    ///
+    /// `source` must be a formative, optionally preceded by an affixual adjunct with formative
+    /// scope and the full mode, whose affixes become the slot XI affixes. Returns
+    /// [`ParseError::ExpectedSlotXiAdjunct`] if the adjunct has any other scope or mode, and
+    /// [`ParseError::TooManyWords`] if `source` has more than two words.
+    pub fn parse_str(source: &str, flags: FromTokenFlags) -> Result<Self, ParseError> {
+        let words: Vec<_> = source.split_whitespace().collect();
+
+        match words[..] {
+            [] => Err(ParseError::WordEmpty),
+
+            [formative] => Ok(Self::new(T::parse_str(formative, flags)?)),
+
+            [adjunct, formative] => {
+                let adjunct = AffixualAdjunct::parse_str(adjunct, flags)?;
+
+                Ok(Self {
+                    slot_xi_affixes: slot_xi_affixes(adjunct)
+                        .ok_or(ParseError::ExpectedSlotXiAdjunct)?,
+                    base: T::parse_str(formative, flags)?,
+                })
+            }
+
+            _ => Err(ParseError::TooManyWords),
+        }
+    }
+}
+
+impl<T: FromTokens> FromStr for Extended<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s, FromTokenFlags::NONE)
+    }
+}
+
 macro_rules! as_general_impl {
     ($general:ident, $specific:ident) => {
         impl AsGeneral<Extended<$general>> for Extended<$specific> {
@@ -50,10 +215,7 @@ macro_rules! as_extended_impl {
     ($ty:ident) => {
         impl AsGeneral<Extended<$ty>> for $ty {
             fn as_general(self) -> Extended<$ty> {
-                Extended {
-                    base: self,
-                    slot_xi_affixes: Vec::new(),
-                }
+                Extended::new(self)
             }
         }
 
@@ -79,10 +241,7 @@ macro_rules! as_extended_general_impl {
     ($general:ident, $specific:ident) => {
         impl AsGeneral<Extended<$general>> for $specific {
             fn as_general(self) -> Extended<$general> {
-                Extended {
-                    base: self.as_general(),
-                    slot_xi_affixes: Vec::new(),
-                }
+                Extended::new(self.as_general())
             }
         }
 
@@ -1,17 +1,18 @@
 use crate::{
-    affix::AffixList,
+    affix::{AffixList, PlainAffix, RegularAffix},
     ca, ca_pat,
     category::{
-        AffixShortcut, ArbitraryMoodOrCaseScope, Ca, Case, Context, DatalessRelation, Function,
-        HFormDegree, HFormSequence, IllocutionOrValidation, Mood, NominalMode, NormalCaShortcut,
+        AffixShortcut, AffixType, ArbitraryMoodOrCaseScope, Aspect, Ca, Case, Context,
+        DatalessRelation, Effect, Function, HFormDegree, HFormSequence, Level, NominalMode, Phase,
         ShortcutType, Specification, Stem, Stress, Valence, Version, Vn, VowelFormDegree,
         VowelFormSequence,
     },
+    gloss::GlossToken,
     prelude::*,
     romanize::{
         segment::VnCnWithGlottalStop,
         stream::TokenStream,
-        token::{HForm, NumeralForm, OwnedConsonantForm, Token, VowelForm},
+        token::{HForm, OwnedConsonantForm, Token, VowelForm},
         traits::{IntoVowelForm, IntoVxCs},
     },
     word::{
@@ -25,15 +26,14 @@ use crate::{
                 AffixualFormativeCore, FormativeCore, NormalFormativeCore, NumericFormativeCore,
                 ReferentialFormativeCore, ShortcutCheckedFormativeCore,
             },
+            parse::{assemble, parse_bare_root, parse_cc_vv, parse_middle_segments, parse_root},
             relation::{NormalRelation, Relation},
-            root::{
-                AffixualFormativeRoot, NormalFormativeRoot, NumericFormativeRoot,
-                ShortcutCheckedFormativeRoot,
-            },
+            root::ShortcutCheckedFormativeRoot,
         },
         Extended,
     },
 };
+use std::{error::Error, fmt};
 
 /// A formative.
 ///
@@ -124,8 +124,8 @@ pub struct UncheckedFormative {
     /// The Vc/Vk of this formative.
     ///
     /// This field is called `vc` because it stores a `Case`, but it represents an
-    /// [`IllocutionOrValidation`] in verbal formatives. It's not incorporated into `self.relation`
-    /// because that would make it harder to work with.
+    /// [`IllocutionOrValidation`][crate::category::IllocutionOrValidation] in verbal formatives.
+    /// It's not incorporated into `self.relation` because that would make it harder to work with.
     pub vc: Case,
 }
 
@@ -159,28 +159,143 @@ impl From<CheckedFormative> for ShortcutCheckedFormative {
 
 impl TryAsSpecific<CheckedFormative> for ShortcutCheckedFormative {
     fn try_as_specific(self) -> Option<CheckedFormative> {
-        let ShortcutCheckedFormative(core, additions) = self;
+        self.try_into().ok()
+    }
+}
+
+/// Checks the parts of a referential or affixual formative which its specific types cannot
+/// represent, so that converting it to a [`CheckedFormative`] can report why it failed.
+fn check_special_formative(
+    core: &ShortcutCheckedFormativeCore,
+    additions: &ShortcutCheckedFormativeAdditions,
+    is_affixual: bool,
+) -> Result<(), ParseError> {
+    if core.stem != Stem::S1 {
+        return Err(ParseError::SpecialFormativeWithStem);
+    }
+
+    let (affix_shortcut, specification) = match additions {
+        ShortcutCheckedFormativeAdditions::Normal(value) => {
+            (value.affix_shortcut, value.specification)
+        }
+        ShortcutCheckedFormativeAdditions::CnShortcut(value) => {
+            (value.affix_shortcut, value.specification)
+        }
+        ShortcutCheckedFormativeAdditions::CaShortcut(_) => {
+            if is_affixual {
+                return Err(ParseError::AffixualFormativeWithCaShortcut);
+            }
+
+            (AffixShortcut::None, Specification::BSC)
+        }
+    };
+
+    if affix_shortcut != AffixShortcut::None {
+        return Err(ParseError::SpecialFormativeWithAffixShortcut);
+    }
+
+    if is_affixual && specification != Specification::BSC {
+        return Err(ParseError::AffixualFormativeWithSpecification);
+    }
+
+    Ok(())
+}
+
+impl TryFrom<ShortcutCheckedFormative> for CheckedFormative {
+    type Error = ParseError;
+
+    fn try_from(value: ShortcutCheckedFormative) -> Result<Self, Self::Error> {
+        let ShortcutCheckedFormative(core, additions) = value;
 
         match core.root {
-            ShortcutCheckedFormativeRoot::Normal(_) => Some(CheckedFormative::Normal(
-                core.try_as_specific()?,
-                additions.try_as_specific()?,
+            ShortcutCheckedFormativeRoot::Normal(_) => Ok(CheckedFormative::Normal(
+                core.try_as_specific().ok_or(ParseError::InvalidFormative)?,
+                additions
+                    .try_as_specific()
+                    .ok_or(ParseError::InvalidFormative)?,
             )),
 
-            ShortcutCheckedFormativeRoot::Numeric(_) => Some(CheckedFormative::Numeric(
-                core.try_as_specific()?,
-                additions.try_as_specific()?,
+            ShortcutCheckedFormativeRoot::Numeric(_) => Ok(CheckedFormative::Numeric(
+                core.try_as_specific().ok_or(ParseError::InvalidFormative)?,
+                additions
+                    .try_as_specific()
+                    .ok_or(ParseError::InvalidFormative)?,
             )),
 
-            ShortcutCheckedFormativeRoot::Referential(_) => Some(CheckedFormative::Referential(
-                core.try_as_specific()?,
-                additions.try_as_specific()?,
-            )),
+            ShortcutCheckedFormativeRoot::Referential(_) => {
+                check_special_formative(&core, &additions, false)?;
+
+                // At this point, the only way the additions can fail to convert is if they use a
+                // Ca shortcut which referential formatives can't express.
+                Ok(CheckedFormative::Referential(
+                    core.try_as_specific().ok_or(ParseError::InvalidFormative)?,
+                    additions
+                        .try_as_specific()
+                        .ok_or(ParseError::ReferentialFormativeWithCaShortcut)?,
+                ))
+            }
 
-            ShortcutCheckedFormativeRoot::Affixual(_) => Some(CheckedFormative::Affixual(
-                core.try_as_specific()?,
-                additions.try_as_specific()?,
-            )),
+            ShortcutCheckedFormativeRoot::Affixual(_) => {
+                check_special_formative(&core, &additions, true)?;
+
+                Ok(CheckedFormative::Affixual(
+                    core.try_as_specific().ok_or(ParseError::InvalidFormative)?,
+                    additions
+                        .try_as_specific()
+                        .ok_or(ParseError::InvalidFormative)?,
+                ))
+            }
+        }
+    }
+}
+
+impl ShortcutCheckedFormative {
+    /// Gets the root of this formative.
+    pub fn root(&self) -> &ShortcutCheckedFormativeRoot {
+        &self.0.root
+    }
+
+    /// Gets the stem of this formative.
+    pub fn stem(&self) -> Stem {
+        self.0.stem
+    }
+
+    /// Gets the version of this formative.
+    pub fn version(&self) -> Version {
+        self.0.version
+    }
+
+    /// Gets the slot VII affixes of this formative.
+    pub fn slot_vii_affixes(&self) -> &AffixList {
+        &self.0.slot_vii_affixes
+    }
+
+    /// Gets the relation of this formative, whichever shortcut it uses.
+    pub fn relation(&self) -> NormalRelation {
+        match &self.1 {
+            ShortcutCheckedFormativeAdditions::Normal(data) => data.relation,
+            ShortcutCheckedFormativeAdditions::CnShortcut(data) => data.relation.as_general(),
+            ShortcutCheckedFormativeAdditions::CaShortcut(data) => data.relation,
+        }
+    }
+
+    /// Gets the Ca of this formative. Returns [`None`] for Cn-shortcut formatives, which have no
+    /// Ca of their own.
+    pub fn ca(&self) -> Option<Ca> {
+        match &self.1 {
+            ShortcutCheckedFormativeAdditions::Normal(data) => Some(data.ca),
+            ShortcutCheckedFormativeAdditions::CnShortcut(_) => None,
+            ShortcutCheckedFormativeAdditions::CaShortcut(data) => Some(data.ca.as_general()),
+        }
+    }
+
+    /// Gets the Vn of this formative. Returns [`None`] for Cn-shortcut formatives, which have no
+    /// Vn of their own.
+    pub fn vn(&self) -> Option<Vn> {
+        match &self.1 {
+            ShortcutCheckedFormativeAdditions::Normal(data) => Some(data.vn),
+            ShortcutCheckedFormativeAdditions::CnShortcut(_) => None,
+            ShortcutCheckedFormativeAdditions::CaShortcut(data) => Some(data.vn),
         }
     }
 }
@@ -371,6 +486,14 @@ impl TryAsSpecific<ShortcutCheckedFormative> for UncheckedFormative {
     }
 }
 
+impl TryFrom<UncheckedFormative> for ShortcutCheckedFormative {
+    type Error = ParseError;
+
+    fn try_from(value: UncheckedFormative) -> Result<Self, Self::Error> {
+        value.try_as_specific().ok_or(ParseError::InvalidFormative)
+    }
+}
+
 impl AsGeneral<UncheckedFormative> for CheckedFormative {
     fn as_general(self) -> UncheckedFormative {
         let shortcut_checked_formative: ShortcutCheckedFormative = self.as_general();
@@ -391,6 +514,15 @@ impl TryAsSpecific<CheckedFormative> for UncheckedFormative {
     }
 }
 
+impl TryFrom<UncheckedFormative> for CheckedFormative {
+    type Error = ParseError;
+
+    fn try_from(value: UncheckedFormative) -> Result<Self, Self::Error> {
+        let shortcut_checked_formative: ShortcutCheckedFormative = value.try_into()?;
+        shortcut_checked_formative.try_into()
+    }
+}
+
 enum RootType {
     Normal,
     Numeric,
@@ -398,33 +530,42 @@ enum RootType {
     Affixual,
 }
 
+/// The gloss of a slot holding a root or affixes, computed only once its slot is reached so that
+/// [`UncheckedFormative::gloss_iter`] doesn't gloss long affix lists before they're needed.
+type LazyGloss<'a> = Box<dyn FnOnce() -> String + 'a>;
+
 struct FormativeGlossInput<'a> {
-    root: String,
+    root: LazyGloss<'a>,
     stem: &'a str,
     version: Version,
-    slot_vii: String,
-    slot_xi: String,
+    slot_vii: LazyGloss<'a>,
+    slot_xi: LazyGloss<'a>,
     root_type: RootType,
 }
 
-struct Additions {
+struct Additions<'a> {
     shortcut_type: ShortcutType,
     relation: NormalRelation,
     ca: Ca,
-    slot_v: String,
+    slot_v: LazyGloss<'a>,
+    has_slot_v: bool,
     function: Function,
     specification: Specification,
     context: Context,
     vn: Option<Vn>,
 }
 
-fn make_additions(additions: &ShortcutCheckedFormativeAdditions, flags: GlossFlags) -> Additions {
+fn make_additions(
+    additions: &ShortcutCheckedFormativeAdditions,
+    flags: GlossFlags,
+) -> Additions<'_> {
     match additions {
         ShortcutCheckedFormativeAdditions::Normal(data) => Additions {
             shortcut_type: ShortcutType::Normal,
             relation: data.relation,
             ca: data.ca,
-            slot_v: data.slot_v_affixes.gloss(flags),
+            slot_v: Box::new(move || data.slot_v_affixes.gloss(flags)),
+            has_slot_v: !data.slot_v_affixes.is_empty(),
             function: data.function,
             specification: data.specification,
             context: data.context,
@@ -434,7 +575,8 @@ fn make_additions(additions: &ShortcutCheckedFormativeAdditions, flags: GlossFla
             shortcut_type: ShortcutType::Cn,
             relation: data.relation.as_general(),
             ca: Default::default(),
-            slot_v: String::new(),
+            slot_v: Box::new(String::new),
+            has_slot_v: false,
             function: data.function,
             specification: data.specification,
             context: data.context,
@@ -444,7 +586,8 @@ fn make_additions(additions: &ShortcutCheckedFormativeAdditions, flags: GlossFla
             shortcut_type: ShortcutType::Ca,
             relation: data.relation,
             ca: data.ca.as_general(),
-            slot_v: data.slot_v_affixes.gloss(flags),
+            slot_v: Box::new(move || data.slot_v_affixes.gloss(flags)),
+            has_slot_v: !data.slot_v_affixes.is_empty(),
             function: Default::default(),
             specification: Default::default(),
             context: Default::default(),
@@ -453,20 +596,23 @@ fn make_additions(additions: &ShortcutCheckedFormativeAdditions, flags: GlossFla
     }
 }
 
-fn gloss_formative(
-    data: FormativeGlossInput,
+/// Glosses a formative one slot at a time. Each slot is glossed only when the iterator reaches
+/// it, and empty slots are skipped.
+fn gloss_formative_tokens<'a>(
+    data: FormativeGlossInput<'a>,
     Additions {
         shortcut_type,
         relation,
         ca,
         slot_v,
+        has_slot_v,
         function,
         specification,
         context,
         vn,
-    }: Additions,
+    }: Additions<'a>,
     flags: GlossFlags,
-) -> String {
+) -> impl Iterator<Item = GlossToken> + 'a {
     let FormativeGlossInput {
         root,
         stem,
@@ -476,92 +622,6 @@ fn gloss_formative(
         root_type,
     } = data;
 
-    let (slot_vi, slot_viii) = match shortcut_type {
-        ShortcutType::Normal => (
-            {
-                let ca = ca.gloss(flags);
-
-                if ca == "" && slot_v != "" {
-                    "{Ca}".to_owned()
-                } else {
-                    ca
-                }
-            },
-            {
-                let mut slot_viii = match vn {
-                    Some(vn) => vn.gloss_non_default(flags),
-                    None => "".to_owned(),
-                };
-
-                slot_viii.add_dotted(
-                    relation
-                        .mood_or_case_scope()
-                        .gloss_static_non_fac_ccn(flags),
-                );
-
-                slot_viii
-            },
-        ),
-
-        ShortcutType::Cn => (relation.mood_or_case_scope().gloss(flags), String::new()),
-
-        ShortcutType::Ca => (if slot_v == "" { "" } else { "{Ca}" }.to_owned(), {
-            let mut slot_viii = match vn {
-                Some(vn) => vn.gloss_non_default(flags),
-                None => "".to_owned(),
-            };
-
-            slot_viii.add_dotted(
-                relation
-                    .mood_or_case_scope()
-                    .gloss_static_non_fac_ccn(flags),
-            );
-
-            slot_viii
-        }),
-    };
-
-    let slots_iii_and_iv = match root_type {
-        RootType::Affixual => {
-            let mut output = root;
-
-            match context.gloss_static_non_default(flags) {
-                "" => {}
-                value => {
-                    output += ".";
-                    output += value;
-                }
-            };
-
-            output
-        }
-        _ => {
-            let mut output = root;
-            let mut has_added_dash = false;
-
-            for el in [
-                function.gloss_static_non_default(flags),
-                specification.gloss_static_non_default(flags),
-                context.gloss_static_non_default(flags),
-            ] {
-                if el == "" {
-                    continue;
-                }
-
-                if has_added_dash {
-                    output += ".";
-                } else {
-                    output += "-";
-                    has_added_dash = true;
-                }
-
-                output += el;
-            }
-
-            output
-        }
-    };
-
     enum Mode {
         Nominal,
         Verbal,
@@ -580,79 +640,219 @@ fn gloss_formative(
         Relation::Verbal { .. } => Mode::Verbal,
     };
 
-    let slot_i = match mode {
-        Mode::T1 => {
-            if flags.matches(GlossFlags::LONG) {
-                "type_one"
-            } else {
-                "T1"
-            }
-        }
-        Mode::T2 => {
-            if flags.matches(GlossFlags::LONG) {
-                "type_two"
-            } else {
-                "T2"
+    let show_slots = flags.matches(GlossFlags::SHOW_SLOTS);
+
+    // Slot X holds a suffix rather than a dashed slot, so it is written last unless every slot is
+    // being labeled, in which case the slots are written in numerical order.
+    let mut slots = if show_slots {
+        [
+            "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI",
+        ]
+    } else {
+        [
+            "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "XI", "X",
+        ]
+    }
+    .into_iter();
+
+    let mut root = Some(root);
+    let mut slot_v = Some(slot_v);
+    let mut slot_vii = Some(slot_vii);
+    let mut slot_xi = Some(slot_xi);
+
+    // Each lazy slot is visited exactly once, so taking its gloss out of its `Option` never fails.
+    fn force(gloss: &mut Option<LazyGloss>) -> String {
+        gloss.take().map(|gloss| gloss()).unwrap_or_default()
+    }
+
+    let slot_viii = move || {
+        let mut slot_viii = match vn {
+            Some(vn) if flags.matches(GlossFlags::SHOW_DEFAULTS) || !vn.is_default() => {
+                vn.gloss(flags)
             }
-        }
-        _ => "",
+            _ => "".to_owned(),
+        };
+
+        slot_viii.add_dotted(
+            relation
+                .mood_or_case_scope()
+                .gloss_static_non_fac_ccn(flags),
+        );
+
+        slot_viii
     };
 
-    let slot_ii = match root_type {
-        RootType::Normal | RootType::Numeric => {
-            let mut output = stem.to_owned();
-            output.add_dotted(version.gloss_static_non_default(flags));
-            if matches!(shortcut_type, ShortcutType::Ca) {
-                output.add_dotted(&ca.gloss_non_default(flags));
-            }
-            output
-        }
-        RootType::Referential => {
-            let mut output = version.gloss_non_default(flags);
-            if matches!(shortcut_type, ShortcutType::Ca) {
-                output.add_dotted(&ca.gloss_non_default(flags));
+    let mut is_empty = true;
+
+    std::iter::from_fn(move || loop {
+        let slot = slots.next()?;
+
+        // Slot IV is attached directly to the root and slot X is a suffix, so they use their own
+        // separators. Every other slot mirrors `GlossHelpers::add_dashed`: only the first
+        // non-empty slot goes without a dash.
+        let (text, separator) = match slot {
+            "I" => (
+                match mode {
+                    Mode::T1 if flags.matches(GlossFlags::LONG) => "type_one",
+                    Mode::T1 => "T1",
+                    Mode::T2 if flags.matches(GlossFlags::LONG) => "type_two",
+                    Mode::T2 => "T2",
+                    _ => "",
+                }
+                .to_owned(),
+                None,
+            ),
+
+            "II" => (
+                match root_type {
+                    RootType::Normal | RootType::Numeric => {
+                        let mut output = stem.to_owned();
+                        output.add_dotted(version.gloss_static_non_default(flags));
+                        if matches!(shortcut_type, ShortcutType::Ca) {
+                            output.add_dotted(&ca.gloss_non_default(flags));
+                        }
+                        output
+                    }
+                    RootType::Referential => {
+                        let mut output = version.gloss_non_default(flags);
+                        if matches!(shortcut_type, ShortcutType::Ca) {
+                            output.add_dotted(&ca.gloss_non_default(flags));
+                        }
+                        output
+                    }
+                    RootType::Affixual => {
+                        let mut output = version.gloss_non_default(flags);
+                        output.add_dotted(function.gloss_static_non_default(flags));
+                        output
+                    }
+                },
+                None,
+            ),
+
+            "III" => (force(&mut root), None),
+
+            "IV" => match root_type {
+                // The root gloss already ends in its degree after a dash (as in `t/4-D4`), which
+                // takes the place of the dashed Vr of a normal root, so the context follows it
+                // with a dot.
+                RootType::Affixual => (
+                    context.gloss_static_non_default(flags).to_owned(),
+                    Some("."),
+                ),
+                _ => {
+                    let mut output = String::new();
+
+                    for el in [
+                        function.gloss_static_non_default(flags),
+                        specification.gloss_static_non_default(flags),
+                        context.gloss_static_non_default(flags),
+                    ] {
+                        output.add_dotted(el);
+                    }
+
+                    (output, Some("-"))
+                }
+            },
+
+            "V" => (force(&mut slot_v), None),
+
+            "VI" => (
+                match shortcut_type {
+                    ShortcutType::Normal => {
+                        let ca = ca.gloss(flags);
+
+                        if ca == "" && has_slot_v {
+                            "{Ca}".to_owned()
+                        } else {
+                            ca
+                        }
+                    }
+                    ShortcutType::Cn => relation.mood_or_case_scope().gloss(flags),
+                    ShortcutType::Ca => if has_slot_v { "{Ca}" } else { "" }.to_owned(),
+                },
+                None,
+            ),
+
+            "VII" => (force(&mut slot_vii), None),
+
+            "VIII" => (
+                match shortcut_type {
+                    ShortcutType::Normal | ShortcutType::Ca => slot_viii(),
+                    ShortcutType::Cn => String::new(),
+                },
+                None,
+            ),
+
+            "IX" => (
+                match relation {
+                    Relation::Nominal { case, .. }
+                        if flags.matches(GlossFlags::ALWAYS_SHOW_CASE) =>
+                    {
+                        case.gloss_static(flags)
+                    }
+
+                    Relation::Nominal { case, .. } => case.gloss_static_non_default(flags),
+
+                    // Illocution/Validation must always be shown for disambiguation
+                    Relation::Verbal { ivl, .. } => ivl.gloss_static(flags),
+                }
+                .to_owned(),
+                None,
+            ),
+
+            "X" => {
+                let slot_x = match mode {
+                    Mode::Nominal => ("", "\\UNF"),
+                    Mode::T1 => ("", ""),
+                    Mode::T2 => ("", ""),
+                    Mode::Framed => ("\\FRM", "\\FRM"),
+                    Mode::Verbal => ("", "\\UNF"),
+                };
+
+                let slot_x = if flags.matches(GlossFlags::NO_RELATION_SUFFIX) {
+                    ""
+                } else if flags.matches(GlossFlags::SHOW_DEFAULTS) {
+                    slot_x.1
+                } else {
+                    slot_x.0
+                };
+
+                (slot_x.to_owned(), Some(""))
             }
-            output
-        }
-        RootType::Affixual => {
-            let mut output = version.gloss_non_default(flags);
-            output.add_dotted(function.gloss_static_non_default(flags));
-            output
+
+            _ => (force(&mut slot_xi), None),
+        };
+
+        if text.is_empty() {
+            continue;
         }
-    };
 
-    let slot_ix = match relation {
-        Relation::Nominal { case, .. } => case.gloss_static_non_default(flags),
+        if show_slots {
+            return Some(GlossToken {
+                slot,
+                separator: "",
+                text: format!("[{slot}:{text}]"),
+            });
+        }
 
-        // Illocution/Validation must always be shown for disambiguation
-        Relation::Verbal { ivl, .. } => ivl.gloss_static(flags),
-    };
+        let separator = match separator {
+            Some(separator) => separator,
+            None if is_empty => "",
+            None => "-",
+        };
 
-    let slot_x = match mode {
-        Mode::Nominal => ("", "\\UNF"),
-        Mode::T1 => ("", ""),
-        Mode::T2 => ("", ""),
-        Mode::Framed => ("\\FRM", "\\FRM"),
-        Mode::Verbal => ("", "\\UNF"),
-    };
+        is_empty = false;
 
-    let slot_x = if flags.matches(GlossFlags::SHOW_DEFAULTS) {
-        slot_x.1
-    } else {
-        slot_x.0
-    };
+        return Some(GlossToken {
+            slot,
+            separator,
+            text,
+        });
+    })
+}
 
-    let mut gloss = slot_i.to_owned();
-    gloss.add_dashed(&slot_ii);
-    gloss.add_dashed(&slots_iii_and_iv);
-    gloss.add_dashed(&slot_v);
-    gloss.add_dashed(&slot_vi);
-    gloss.add_dashed(&slot_vii);
-    gloss.add_dashed(&slot_viii);
-    gloss.add_dashed(&slot_ix);
-    gloss.add_dashed(&slot_xi);
-    gloss += &slot_x;
-    gloss
+fn gloss_formative(data: FormativeGlossInput, additions: Additions, flags: GlossFlags) -> String {
+    gloss_formative_tokens(data, additions, flags).collect()
 }
 
 impl Gloss for CheckedFormative {
@@ -667,10 +867,10 @@ impl Gloss for CheckedFormative {
                 },
                 _,
             ) => (
-                root.gloss(flags),
+                Box::new(move || root.gloss(flags)) as LazyGloss,
                 stem.gloss_static(flags),
                 *version,
-                slot_vii_affixes.gloss(flags),
+                Box::new(move || slot_vii_affixes.gloss(flags)) as LazyGloss,
             ),
 
             Self::Numeric(
@@ -682,10 +882,10 @@ impl Gloss for CheckedFormative {
                 },
                 _,
             ) => (
-                root.gloss(flags),
+                Box::new(move || root.gloss(flags)) as LazyGloss,
                 stem.gloss_static(flags),
                 *version,
-                slot_vii_affixes.gloss(flags),
+                Box::new(move || slot_vii_affixes.gloss(flags)) as LazyGloss,
             ),
 
             Self::Referential(
@@ -697,10 +897,10 @@ impl Gloss for CheckedFormative {
                 },
                 _,
             ) => (
-                root.gloss(flags),
+                Box::new(move || root.gloss(flags)) as LazyGloss,
                 "",
                 *version,
-                slot_vii_affixes.gloss(flags),
+                Box::new(move || slot_vii_affixes.gloss(flags)) as LazyGloss,
             ),
 
             Self::Affixual(
@@ -712,10 +912,10 @@ impl Gloss for CheckedFormative {
                 },
                 _,
             ) => (
-                root.gloss(flags),
+                Box::new(move || root.gloss(flags)) as LazyGloss,
                 "",
                 *version,
-                slot_vii_affixes.gloss(flags),
+                Box::new(move || slot_vii_affixes.gloss(flags)) as LazyGloss,
             ),
         };
 
@@ -740,7 +940,7 @@ impl Gloss for CheckedFormative {
                 stem,
                 version,
                 slot_vii,
-                slot_xi: String::new(),
+                slot_xi: Box::new(String::new),
                 root_type,
             },
             make_additions(&additions, flags),
@@ -751,7 +951,7 @@ impl Gloss for CheckedFormative {
 
 impl Gloss for ShortcutCheckedFormative {
     fn gloss(&self, flags: GlossFlags) -> String {
-        let root = self.0.root.gloss(flags);
+        let root = Box::new(move || self.0.root.gloss(flags));
 
         let stem = match self.0.root {
             ShortcutCheckedFormativeRoot::Normal(_) | ShortcutCheckedFormativeRoot::Numeric(_) => {
@@ -762,7 +962,7 @@ impl Gloss for ShortcutCheckedFormative {
 
         let version = self.0.version;
 
-        let slot_vii = self.0.slot_vii_affixes.gloss(flags);
+        let slot_vii = Box::new(move || self.0.slot_vii_affixes.gloss(flags));
 
         let root_type = match self.0.root {
             ShortcutCheckedFormativeRoot::Normal(_) => RootType::Normal,
@@ -779,7 +979,7 @@ impl Gloss for ShortcutCheckedFormative {
                 stem,
                 version,
                 slot_vii,
-                slot_xi: String::new(),
+                slot_xi: Box::new(String::new),
                 root_type,
             },
             make_additions(additions, flags),
@@ -788,19 +988,19 @@ impl Gloss for ShortcutCheckedFormative {
     }
 }
 
-impl Gloss for UncheckedFormative {
-    fn gloss(&self, flags: GlossFlags) -> String {
-        gloss_formative(
+impl UncheckedFormative {
+    fn gloss_input(&self, flags: GlossFlags) -> (FormativeGlossInput<'_>, Additions<'_>) {
+        (
             FormativeGlossInput {
-                root: self.root.gloss(flags),
+                root: Box::new(move || self.root.gloss(flags)),
                 stem: match self.root {
                     ShortcutCheckedFormativeRoot::Normal(_)
                     | ShortcutCheckedFormativeRoot::Numeric(_) => self.stem.gloss_static(flags),
                     _ => "",
                 },
                 version: self.version,
-                slot_vii: self.slot_vii_affixes.gloss(flags),
-                slot_xi: String::new(),
+                slot_vii: Box::new(move || self.slot_vii_affixes.gloss(flags)),
+                slot_xi: Box::new(String::new),
                 root_type: match self.root {
                     ShortcutCheckedFormativeRoot::Normal(_) => RootType::Normal,
                     ShortcutCheckedFormativeRoot::Numeric(_) => RootType::Numeric,
@@ -813,7 +1013,7 @@ impl Gloss for UncheckedFormative {
                 relation: match self.relation {
                     DatalessRelation::VRB => Relation::Verbal {
                         mood: self.cn.as_specific(),
-                        ivl: self.vc.as_vk().unwrap_or(IllocutionOrValidation::USP),
+                        ivl: self.vc.as_vk_or_usp(),
                     },
 
                     _ => Relation::Nominal {
@@ -829,18 +1029,53 @@ impl Gloss for UncheckedFormative {
                     },
                 },
                 ca: self.ca,
-                slot_v: self.slot_v_affixes.gloss(flags),
+                slot_v: Box::new(move || self.slot_v_affixes.gloss(flags)),
+                has_slot_v: !self.slot_v_affixes.is_empty(),
                 function: self.function,
                 specification: match self.root {
                     ShortcutCheckedFormativeRoot::Affixual(_) => Specification::BSC,
                     _ => self.specification,
                 },
                 context: self.context,
-                vn: None,
+                vn: Some(self.vn),
             },
-            flags,
         )
     }
+
+    /// Glosses this formative as a sequence of per-slot tokens.
+    ///
+    /// Each [`GlossToken`] holds the gloss of a single non-empty slot along with the separator
+    /// written before it, letting callers such as syntax highlighters style each slot without
+    /// re-parsing the finished gloss. Collecting the tokens into a [`String`] gives exactly the
+    /// result of [`Gloss::gloss`].
+    ///
+    /// The iterator is lazy: each slot is glossed only when the iterator reaches it, so the root
+    /// and affixes of a long formative aren't glossed until they're needed.
+    ///
+    /// ```
+    /// # use tnil::{prelude::*, word::UncheckedFormative};
+    /// let formative: UncheckedFormative = "malëuţřa".parse().unwrap();
+    ///
+    /// let slots: Vec<_> = formative
+    ///     .gloss_iter(GlossFlags::NONE)
+    ///     .map(|token| token.slot)
+    ///     .collect();
+    /// assert_eq!(slots, ["II", "III", "VII"]);
+    ///
+    /// let gloss: String = formative.gloss_iter(GlossFlags::NONE).collect();
+    /// assert_eq!(gloss, formative.gloss(GlossFlags::NONE));
+    /// ```
+    pub fn gloss_iter(&self, flags: GlossFlags) -> impl Iterator<Item = GlossToken> + '_ {
+        let (data, additions) = self.gloss_input(flags);
+        gloss_formative_tokens(data, additions, flags)
+    }
+}
+
+impl Gloss for UncheckedFormative {
+    fn gloss(&self, flags: GlossFlags) -> String {
+        let (data, additions) = self.gloss_input(flags);
+        gloss_formative(data, additions, flags)
+    }
 }
 
 impl Gloss for Extended<CheckedFormative> {
@@ -855,10 +1090,10 @@ impl Gloss for Extended<CheckedFormative> {
                 },
                 _,
             ) => (
-                root.gloss(flags),
+                Box::new(move || root.gloss(flags)) as LazyGloss,
                 stem.gloss_static(flags),
                 *version,
-                slot_vii_affixes.gloss(flags),
+                Box::new(move || slot_vii_affixes.gloss(flags)) as LazyGloss,
             ),
 
             CheckedFormative::Numeric(
@@ -870,10 +1105,10 @@ impl Gloss for Extended<CheckedFormative> {
                 },
                 _,
             ) => (
-                root.gloss(flags),
+                Box::new(move || root.gloss(flags)) as LazyGloss,
                 stem.gloss_static(flags),
                 *version,
-                slot_vii_affixes.gloss(flags),
+                Box::new(move || slot_vii_affixes.gloss(flags)) as LazyGloss,
             ),
 
             CheckedFormative::Referential(
@@ -885,10 +1120,10 @@ impl Gloss for Extended<CheckedFormative> {
                 },
                 _,
             ) => (
-                root.gloss(flags),
+                Box::new(move || root.gloss(flags)) as LazyGloss,
                 "",
                 *version,
-                slot_vii_affixes.gloss(flags),
+                Box::new(move || slot_vii_affixes.gloss(flags)) as LazyGloss,
             ),
 
             CheckedFormative::Affixual(
@@ -900,10 +1135,10 @@ impl Gloss for Extended<CheckedFormative> {
                 },
                 _,
             ) => (
-                root.gloss(flags),
+                Box::new(move || root.gloss(flags)) as LazyGloss,
                 "",
                 *version,
-                slot_vii_affixes.gloss(flags),
+                Box::new(move || slot_vii_affixes.gloss(flags)) as LazyGloss,
             ),
         };
 
@@ -928,13 +1163,13 @@ impl Gloss for Extended<CheckedFormative> {
                 stem,
                 version,
                 slot_vii,
-                slot_xi: {
+                slot_xi: Box::new(move || {
                     let mut slot_xi = String::new();
                     for affix in &self.slot_xi_affixes {
                         slot_xi.add_dashed(&affix.gloss(flags));
                     }
                     slot_xi
-                },
+                }),
                 root_type,
             },
             make_additions(&additions, flags),
@@ -945,7 +1180,7 @@ impl Gloss for Extended<CheckedFormative> {
 
 impl Gloss for Extended<ShortcutCheckedFormative> {
     fn gloss(&self, flags: GlossFlags) -> String {
-        let root = self.base.0.root.gloss(flags);
+        let root = Box::new(move || self.base.0.root.gloss(flags));
 
         let stem = match self.base.0.root {
             ShortcutCheckedFormativeRoot::Normal(_) | ShortcutCheckedFormativeRoot::Numeric(_) => {
@@ -956,7 +1191,7 @@ impl Gloss for Extended<ShortcutCheckedFormative> {
 
         let version = self.base.0.version;
 
-        let slot_vii = self.base.0.slot_vii_affixes.gloss(flags);
+        let slot_vii = Box::new(move || self.base.0.slot_vii_affixes.gloss(flags));
 
         let root_type = match self.base.0.root {
             ShortcutCheckedFormativeRoot::Normal(_) => RootType::Normal,
@@ -973,13 +1208,13 @@ impl Gloss for Extended<ShortcutCheckedFormative> {
                 stem,
                 version,
                 slot_vii,
-                slot_xi: {
+                slot_xi: Box::new(move || {
                     let mut slot_xi = String::new();
                     for affix in &self.slot_xi_affixes {
                         slot_xi.add_dashed(&affix.gloss(flags));
                     }
                     slot_xi
-                },
+                }),
                 root_type,
             },
             make_additions(additions, flags),
@@ -990,80 +1225,30 @@ impl Gloss for Extended<ShortcutCheckedFormative> {
 
 impl Gloss for Extended<UncheckedFormative> {
     fn gloss(&self, flags: GlossFlags) -> String {
-        gloss_formative(
-            FormativeGlossInput {
-                root: self.base.root.gloss(flags),
-                stem: match self.base.root {
-                    ShortcutCheckedFormativeRoot::Normal(_)
-                    | ShortcutCheckedFormativeRoot::Numeric(_) => {
-                        self.base.stem.gloss_static(flags)
-                    }
-                    _ => "",
-                },
-                version: self.base.version,
-                slot_vii: self.base.slot_vii_affixes.gloss(flags),
-                slot_xi: {
-                    let mut slot_xi = String::new();
-                    for affix in &self.slot_xi_affixes {
-                        slot_xi.add_dashed(&affix.gloss(flags));
-                    }
-                    slot_xi
-                },
-                root_type: match self.base.root {
-                    ShortcutCheckedFormativeRoot::Normal(_) => RootType::Normal,
-                    ShortcutCheckedFormativeRoot::Numeric(_) => RootType::Numeric,
-                    ShortcutCheckedFormativeRoot::Referential(_) => RootType::Referential,
-                    ShortcutCheckedFormativeRoot::Affixual(_) => RootType::Affixual,
-                },
-            },
-            Additions {
-                shortcut_type: self.base.shortcut,
-                relation: match self.base.relation {
-                    DatalessRelation::VRB => Relation::Verbal {
-                        mood: self.base.cn.as_specific(),
-                        ivl: self.base.vc.as_vk().unwrap_or(IllocutionOrValidation::USP),
-                    },
+        let (mut data, additions) = self.base.gloss_input(flags);
 
-                    _ => Relation::Nominal {
-                        mode: match self.base.relation {
-                            DatalessRelation::NOM => NominalMode::NOM,
-                            DatalessRelation::T1 => NominalMode::T1,
-                            DatalessRelation::T2 => NominalMode::T2,
-                            DatalessRelation::VRB => unreachable!(),
-                            DatalessRelation::FRM => NominalMode::FRM,
-                        },
-                        case_scope: self.base.cn.as_specific(),
-                        case: self.base.vc,
-                    },
-                },
-                ca: self.base.ca,
-                slot_v: self.base.slot_v_affixes.gloss(flags),
-                function: self.base.function,
-                specification: match self.base.root {
-                    ShortcutCheckedFormativeRoot::Affixual(_) => Specification::BSC,
-                    _ => self.base.specification,
-                },
-                context: self.base.context,
-                vn: None,
-            },
-            flags,
-        )
+        data.slot_xi = Box::new(move || {
+            let mut slot_xi = String::new();
+            for affix in &self.slot_xi_affixes {
+                slot_xi.add_dashed(&affix.gloss(flags));
+            }
+            slot_xi
+        });
+
+        gloss_formative(data, additions, flags)
     }
 }
 
 impl FromTokens for CheckedFormative {
     fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
         let general: ShortcutCheckedFormative = stream.parse(flags)?;
-
-        general
-            .try_as_specific()
-            .ok_or(ParseError::InvalidFormative)
+        general.try_into()
     }
 }
 
 impl FromTokens for ShortcutCheckedFormative {
     fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
-        // This function is scary. Be warned.
+        // The functions this calls are scary. Be warned.
 
         // These are all possible formative structures:
         // 1. ((H)V)CVC(VC...)(VH)(V)
@@ -1071,6 +1256,21 @@ impl FromTokens for ShortcutCheckedFormative {
         // 3. HVCV(VC...')(VC...)(VH)(V)
         // 4. ((H)V)CVH(VC...)(V)
 
+        if flags.matches(FromTokenFlags::ALLOW_BARE_ROOT) {
+            let checkpoint = stream.checkpoint();
+            let strict_flags = flags ^ FromTokenFlags::ALLOW_BARE_ROOT;
+
+            // Bare roots are only tried when the word can't be parsed normally, so that enabling
+            // this flag never changes the meaning of a valid word.
+            return match Self::parse_volatile(stream, strict_flags) {
+                Err(error) => {
+                    stream.restore(checkpoint);
+                    parse_bare_root(stream, strict_flags).map_err(|_| error)
+                }
+                result => result,
+            };
+        }
+
         // We'll take care of parsing Vc/Vk first, because it's easy.
         // We'll leave it as a VowelForm though, because we don't want to interpret it
         // into a Vc or Vk form until the end of parsing.
@@ -1081,994 +1281,351 @@ impl FromTokens for ShortcutCheckedFormative {
         // constructed. So we'll parse those next. How convenient that they're at the
         // beginning of the word.
 
-        #[derive(Clone, Copy, Debug)]
-        enum CaShortcutMode {
-            W,
-            Y,
-            None,
-        }
-
-        #[derive(Clone, Copy, Debug)]
-        enum Concatenation {
-            T1,
-            T2,
-            None,
-        }
-
-        // The Cc form gives us the Ca shortcut and concatenation type.
-
-        let (ca_shortcut, concatenation_type, has_cc) = match stream.next() {
-            None => (CaShortcutMode::None, Concatenation::None, false),
-
-            Some(h) => match h {
-                HForm::W => (CaShortcutMode::W, Concatenation::None, true),
-                HForm::Y => (CaShortcutMode::Y, Concatenation::None, true),
-                HForm::H => (CaShortcutMode::None, Concatenation::T1, true),
-                HForm::HL => (CaShortcutMode::W, Concatenation::T1, true),
-                HForm::HR => (CaShortcutMode::W, Concatenation::T2, true),
-                HForm::HM => (CaShortcutMode::Y, Concatenation::T1, true),
-                HForm::HN => (CaShortcutMode::Y, Concatenation::T2, true),
-                HForm::HW => (CaShortcutMode::None, Concatenation::T2, true),
-                _ => return Err(ParseError::ExpectedCc),
-            },
-        };
+        let cc_vv = parse_cc_vv(stream, flags)?;
+        let relation_type = cc_vv.relation_type;
 
-        #[derive(Clone, Copy, Debug)]
-        enum RelationType {
-            /// The bool here indicates if the word has cases 37-68.
-            T1(bool),
-
-            /// The bool here indicates if the word has cases 37-68.
-            T2(bool),
-
-            Nominal,
-            Verbal,
-            Framed,
-        }
+        // Cr and Vr come next, and we can piece together most of the formative from them.
 
-        // Now we have enough information to figure out the relation of the word.
+        let cr_vr = parse_root(stream, cc_vv)?;
 
-        let relation_type = match concatenation_type {
-            Concatenation::None => match stream.stress() {
-                Some(Stress::Ultimate | Stress::Monosyllabic) => RelationType::Verbal,
-                Some(Stress::Antepenultimate) => RelationType::Framed,
-                _ => RelationType::Nominal,
-            },
+        // Then the hard part: slots V through VIII.
 
-            Concatenation::T1 => match stream.stress() {
-                Some(Stress::Ultimate | Stress::Monosyllabic) => RelationType::T1(true),
-                Some(Stress::Antepenultimate) => {
-                    if flags.matches(FromTokenFlags::PERMISSIVE) {
-                        RelationType::T1(false)
-                    } else {
-                        return Err(ParseError::AntepenultimateStress);
-                    }
-                }
-                _ => RelationType::T1(false),
-            },
+        let middle = parse_middle_segments(
+            stream,
+            flags,
+            cr_vr.shortcut,
+            cr_vr.does_vv_have_glottal_stop,
+        )?;
 
-            Concatenation::T2 => match stream.stress() {
-                Some(Stress::Ultimate | Stress::Monosyllabic) => RelationType::T2(true),
-                Some(Stress::Antepenultimate) => {
-                    if flags.matches(FromTokenFlags::PERMISSIVE) {
-                        RelationType::T2(false)
-                    } else {
-                        return Err(ParseError::AntepenultimateStress);
-                    }
-                }
-                _ => RelationType::T2(false),
-            },
-        };
+        assemble(vc_or_vk, relation_type, cr_vr, middle)
+    }
+}
 
-        // If we parse the Vv form too early, we'll be stuck having to deal with enum
-        // variants and stuff. So we'll just capture it for now, detect the word
-        // type, and leave it for later.
+impl FromTokens for UncheckedFormative {
+    fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
+        ShortcutCheckedFormative::parse_volatile(stream, flags)
+            .map(|formative| formative.as_general())
+    }
+}
 
-        let vv: VowelForm = if has_cc {
-            stream.next().ok_or(ParseError::ExpectedVv)?
-        } else {
-            stream.next().unwrap_or_default()
-        };
+/// An error returned when a constructed [`UncheckedFormative`] could not be parsed back after
+/// being romanized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The formative has a Cn shortcut, which leaves no room for slot V, but has slot V affixes.
+    ///
+    /// When romanized, a single such affix is read back as a slot VII affix of an unshortcut
+    /// formative, and two or more put a glottal stop in Vv which the parser then rejects with
+    /// [`ParseError::TooFewSlotVAffixes`].
+    SlotVAffixesWithCnShortcut,
+}
 
-        #[derive(Clone, Copy, Debug)]
-        enum WordType {
-            NormalOrNumeric {
-                version: Version,
-                stem: Stem,
-                sequence: VowelFormSequence,
-            },
-            Referential {
-                version: Version,
-            },
-            Affixual {
-                version: Version,
-                function: Function,
-            },
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SlotVAffixesWithCnShortcut => {
+                f.write_str("a formative with a Cn shortcut cannot have slot V affixes")
+            }
         }
+    }
+}
 
-        let word_type = match vv {
-            VowelForm {
-                has_glottal_stop: _,
-                sequence,
-                degree: VowelFormDegree::D5,
-            } => WordType::Affixual {
-                version: match sequence {
-                    VowelFormSequence::S1 | VowelFormSequence::S3 => Version::PRC,
-                    VowelFormSequence::S2 | VowelFormSequence::S4 => Version::CPT,
-                },
-                function: match sequence {
-                    VowelFormSequence::S1 | VowelFormSequence::S2 => Function::STA,
-                    VowelFormSequence::S3 | VowelFormSequence::S4 => Function::DYN,
-                },
-            },
-            VowelForm {
-                has_glottal_stop: _,
-                sequence,
-                degree: VowelFormDegree::D0,
-            } => WordType::Referential {
-                version: match sequence {
-                    VowelFormSequence::S1 => Version::PRC,
-                    VowelFormSequence::S2 => Version::CPT,
-                    VowelFormSequence::S3 | VowelFormSequence::S4 => {
-                        return Err(ParseError::ExpectedVv)
-                    }
-                },
-            },
-            VowelForm {
-                has_glottal_stop: _,
-                sequence,
-                degree,
-            } => WordType::NormalOrNumeric {
-                version: match degree {
-                    VowelFormDegree::D1
-                    | VowelFormDegree::D3
-                    | VowelFormDegree::D7
-                    | VowelFormDegree::D9 => Version::PRC,
-                    VowelFormDegree::D2
-                    | VowelFormDegree::D4
-                    | VowelFormDegree::D6
-                    | VowelFormDegree::D8 => Version::CPT,
-                    _ => unreachable!("we caught degrees 5 and 0 in earlier match arms"),
-                },
-                stem: match degree {
-                    VowelFormDegree::D1 | VowelFormDegree::D2 => Stem::S1,
-                    VowelFormDegree::D3 | VowelFormDegree::D4 => Stem::S2,
-                    VowelFormDegree::D9 | VowelFormDegree::D8 => Stem::S3,
-                    VowelFormDegree::D7 | VowelFormDegree::D6 => Stem::S0,
-                    _ => unreachable!("we caught degrees 5 and 0 in earlier match arms"),
-                },
-                sequence,
-            },
-        };
+impl Error for ValidationError {}
 
-        if !matches!(ca_shortcut, CaShortcutMode::None)
-            && matches!(word_type, WordType::Affixual { .. })
-        {
-            return Err(ParseError::AffixualFormativeWithCaShortcut);
+impl UncheckedFormative {
+    /// Gets the stress this formative carries when romanized.
+    ///
+    /// Nominal formatives have penultimate stress, verbal formatives have ultimate stress, and
+    /// framed formatives have antepenultimate stress. Concatenated formatives have ultimate stress
+    /// if their case has a glottal stop, which is shown by stress instead of being written, and
+    /// penultimate stress otherwise.
+    ///
+    /// This is the stress requested from the serializer, which places no accent mark if the word
+    /// turns out to be monosyllabic, so a verbal formative such as `mal` is written without one.
+    pub fn stress(&self) -> Stress {
+        match self.relation {
+            DatalessRelation::NOM => Stress::Penultimate,
+            DatalessRelation::VRB => Stress::Ultimate,
+            DatalessRelation::FRM => Stress::Antepenultimate,
+            DatalessRelation::T1 | DatalessRelation::T2 => {
+                if self.vc.into_vowel_form().has_glottal_stop {
+                    Stress::Ultimate
+                } else {
+                    Stress::Penultimate
+                }
+            }
         }
+    }
 
-        #[derive(Debug)]
-        enum Root {
-            C(OwnedConsonantForm),
-            N(NumeralForm),
+    /// Checks that the slot V affixes of this formative agree with the glottal stop its Vv will be
+    /// written with.
+    ///
+    /// The parser requires a glottal stop in Vv exactly when slot V holds two or more affixes,
+    /// counting an appositive referential affix as one, and rejects other words with
+    /// [`ParseError::TooFewSlotVAffixes`] or [`ParseError::TooManySlotVAffixes`] unless
+    /// [`FromTokenFlags::PERMISSIVE`] is passed. Romanization derives the glottal stop from the
+    /// same count, so the rule holds for formatives which are unshortcut or have a Ca shortcut.
+    /// Formatives with a Cn shortcut have no slot V, so they must have no slot V affixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tnil::{category::ShortcutType, word::{UncheckedFormative, ValidationError}};
+    /// let mut formative: UncheckedFormative = "a'marsakpallahla".parse().unwrap();
+    /// assert_eq!(formative.validate_slot_v(), Ok(()));
+    ///
+    /// formative.shortcut = ShortcutType::Cn;
+    /// assert_eq!(
+    ///     formative.validate_slot_v(),
+    ///     Err(ValidationError::SlotVAffixesWithCnShortcut)
+    /// );
+    /// ```
+    pub fn validate_slot_v(&self) -> Result<(), ValidationError> {
+        match self.shortcut {
+            ShortcutType::Cn if !self.slot_v_affixes.is_empty() => {
+                Err(ValidationError::SlotVAffixesWithCnShortcut)
+            }
+            _ => Ok(()),
         }
+    }
 
-        // Again, we'll leave these unparsed for now.
+    /// Picks the [`ShortcutType`] that writes this formative most compactly.
+    ///
+    /// A shortcut is only considered if it can represent every category of this formative; for
+    /// instance, a Ca shortcut requires a Ca with a shortcut form and default function,
+    /// specification, and context, and a Cn shortcut requires a default Ca and Vn and no slot V
+    /// affixes. Among the shortcuts which qualify, the one giving the shortest romanization is
+    /// chosen. Ties favor Ca shortcuts, then unshortcut forms, then Cn shortcuts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tnil::{prelude::*, category::ShortcutType, word::UncheckedFormative};
+    /// let mut formative: UncheckedFormative = "mala".parse().unwrap();
+    /// assert_eq!(formative.shortcut, ShortcutType::Normal);
+    /// assert_eq!(formative.optimal_shortcut(), ShortcutType::Ca);
+    ///
+    /// formative.normalize_shortcut();
+    /// assert_eq!(formative.to_romanized(), "wama");
+    /// ```
+    pub fn optimal_shortcut(&self) -> ShortcutType {
+        [ShortcutType::Ca, ShortcutType::Normal, ShortcutType::Cn]
+            .into_iter()
+            .filter_map(|shortcut| {
+                let candidate = UncheckedFormative {
+                    shortcut,
+                    ..self.clone()
+                };
+
+                let checked: ShortcutCheckedFormative = candidate.try_as_specific()?;
+                let general: UncheckedFormative = checked.as_general();
 
-        let root = match stream.next_any() {
-            Some(Token::C(value)) => Root::C(value.clone()),
-            Some(Token::N(value)) => {
-                if !matches!(word_type, WordType::NormalOrNumeric { .. }) {
-                    return Err(ParseError::ExpectedNonNumericRoot);
+                if !general.semantic_eq(self) {
+                    return None;
                 }
 
-                Root::N(*value)
-            }
-            _ => return Err(ParseError::ExpectedRoot),
-        };
+                Some((general.to_romanized().chars().count(), shortcut))
+            })
+            .min_by_key(|&(length, _)| length)
+            .map(|(_, shortcut)| shortcut)
+            .unwrap_or(self.shortcut)
+    }
 
-        // Still leaving it unparsed.
+    /// Rewrites this formative to use the shortcut returned by
+    /// [`optimal_shortcut`][Self::optimal_shortcut]. The meaning of the formative is unchanged.
+    pub fn normalize_shortcut(&mut self) {
+        let shortcut = self.optimal_shortcut();
 
-        let vr: Option<VowelForm> = match ca_shortcut {
-            CaShortcutMode::None => Some(stream.next().ok_or(ParseError::ExpectedVr)?),
-            _ => None,
-        };
+        if shortcut != self.shortcut {
+            let candidate = UncheckedFormative {
+                shortcut,
+                ..self.clone()
+            };
+
+            let checked: Option<ShortcutCheckedFormative> = candidate.try_as_specific();
 
-        // Now we've collected Cc, Vv, Cr, and Vr. That's the easy part done, and we can
-        // piece together most of the formative now.
-        //
-        // We still need to get slots V, VI, VII, and VIII, but we'll do that later in
-        // case there's an early error in the first four slots.
-        //
-        // The slots provide these values:
-        // Cc = concatenation type, Ca shortcut type
-        // Vv =
-        //      normal: stem, version, affix shortcut, Ca shortcut index
-        //    referent: version
-        //    affixual: version, function
-        // Cr = root
-        // Vr =
-        //      normal: specification, function, context
-        //    referent: specification, function, context
-        //    affixual: degree, context
-        // [stress] = relation
-
-        enum Shortcut {
-            None(AffixShortcut),
-            Ca(NormalCaShortcut),
+            if let Some(checked) = checked {
+                *self = checked.as_general();
+            }
         }
+    }
 
-        let does_vv_have_glottal_stop = vv.has_glottal_stop;
-        let does_vr_have_glottal_stop = vr.map(|x| x.has_glottal_stop).unwrap_or_default();
+    /// Moves this formative's Vn into a slot VII affix, leaving a default Vn behind.
+    ///
+    /// The affix uses the Cs form of the Vn's category (such as [`Valence::CS_FORM`]) with a
+    /// type-1 degree, which is how script writes a Vn as a tertiary character. Relative levels
+    /// become relative-level affixes, and absolute levels become the type-2 `řž` affixes which
+    /// show absolute levels. The affix is added to the end of slot VII.
+    ///
+    /// Returns `false` and leaves the formative unchanged if its Vn is already the default or its
+    /// slot VII holds an appositive referential affix.
+    pub fn vn_to_affix(&mut self) -> bool {
+        if self.vn == Vn::default() {
+            return false;
+        }
 
-        let (stem, version, shortcut, specification, function, context, root) = match word_type {
-            WordType::NormalOrNumeric {
-                version,
-                stem,
-                sequence,
-            } => (
-                stem,
-                version,
-                match ca_shortcut {
-                    CaShortcutMode::None => Shortcut::None(match sequence {
-                        VowelFormSequence::S1 => AffixShortcut::None,
-                        VowelFormSequence::S2 => AffixShortcut::NEG4,
-                        VowelFormSequence::S3 => AffixShortcut::DCD4,
-                        VowelFormSequence::S4 => AffixShortcut::DCD5,
-                    }),
-                    CaShortcutMode::W => Shortcut::Ca(match sequence {
-                        VowelFormSequence::S1 => NormalCaShortcut::Default,
-                        VowelFormSequence::S2 => NormalCaShortcut::G,
-                        VowelFormSequence::S3 => NormalCaShortcut::N,
-                        VowelFormSequence::S4 => NormalCaShortcut::G_RPV,
-                    }),
-                    CaShortcutMode::Y => Shortcut::Ca(match sequence {
-                        VowelFormSequence::S1 => NormalCaShortcut::PRX,
-                        VowelFormSequence::S2 => NormalCaShortcut::RPV,
-                        VowelFormSequence::S3 => NormalCaShortcut::A,
-                        VowelFormSequence::S4 => NormalCaShortcut::PRX_RPV,
-                    }),
-                },
-                match ca_shortcut {
-                    CaShortcutMode::None => match vr.ok_or(ParseError::ExpectedVr)?.degree {
-                        VowelFormDegree::D0 => unreachable!("D0 isn't a normal Vv form"),
-                        VowelFormDegree::D5 => unreachable!("D5 isn't a normal Vv form"),
-                        VowelFormDegree::D1 | VowelFormDegree::D9 => Specification::BSC,
-                        VowelFormDegree::D2 | VowelFormDegree::D8 => Specification::CTE,
-                        VowelFormDegree::D3 | VowelFormDegree::D7 => Specification::CSV,
-                        VowelFormDegree::D4 | VowelFormDegree::D6 => Specification::OBJ,
-                    },
-                    _ => Specification::BSC,
-                },
-                match ca_shortcut {
-                    CaShortcutMode::None => match vr.ok_or(ParseError::ExpectedVr)?.degree {
-                        VowelFormDegree::D0 => unreachable!("D0 isn't a normal Vv form"),
-                        VowelFormDegree::D5 => unreachable!("D5 isn't a normal Vv form"),
-                        VowelFormDegree::D1
-                        | VowelFormDegree::D2
-                        | VowelFormDegree::D3
-                        | VowelFormDegree::D4 => Function::STA,
-                        VowelFormDegree::D6
-                        | VowelFormDegree::D7
-                        | VowelFormDegree::D8
-                        | VowelFormDegree::D9 => Function::DYN,
-                    },
-                    _ => Function::STA,
-                },
-                match ca_shortcut {
-                    CaShortcutMode::None => match vr.ok_or(ParseError::ExpectedVr)?.sequence {
-                        VowelFormSequence::S1 => Context::EXS,
-                        VowelFormSequence::S2 => Context::FNC,
-                        VowelFormSequence::S3 => Context::RPS,
-                        VowelFormSequence::S4 => Context::AMG,
-                    },
-                    _ => Context::EXS,
-                },
-                match root {
-                    Root::C(cr) => ShortcutCheckedFormativeRoot::Normal(NormalFormativeRoot { cr }),
-                    Root::N(form) => {
-                        ShortcutCheckedFormativeRoot::Numeric(NumericFormativeRoot { form })
-                    }
-                },
-            ),
+        let affix = RegularAffix::Plain(vn_affix(self.vn));
 
-            WordType::Referential { version } => (
-                Stem::S1,
-                version,
-                Shortcut::None(AffixShortcut::None),
-                match ca_shortcut {
-                    CaShortcutMode::None => match vr.ok_or(ParseError::ExpectedVr)?.degree {
-                        VowelFormDegree::D0 => unreachable!("D0 isn't a normal Vv form"),
-                        VowelFormDegree::D5 => unreachable!("D5 isn't a normal Vv form"),
-                        VowelFormDegree::D1 | VowelFormDegree::D9 => Specification::BSC,
-                        VowelFormDegree::D2 | VowelFormDegree::D8 => Specification::CTE,
-                        VowelFormDegree::D3 | VowelFormDegree::D7 => Specification::CSV,
-                        VowelFormDegree::D4 | VowelFormDegree::D6 => Specification::OBJ,
-                    },
-                    _ => Specification::BSC,
-                },
-                match ca_shortcut {
-                    CaShortcutMode::None => match vr.ok_or(ParseError::ExpectedVr)?.degree {
-                        VowelFormDegree::D0 => unreachable!("D0 isn't a normal Vv form"),
-                        VowelFormDegree::D5 => unreachable!("D5 isn't a normal Vv form"),
-                        VowelFormDegree::D1
-                        | VowelFormDegree::D2
-                        | VowelFormDegree::D3
-                        | VowelFormDegree::D4 => Function::STA,
-                        VowelFormDegree::D6
-                        | VowelFormDegree::D7
-                        | VowelFormDegree::D8
-                        | VowelFormDegree::D9 => Function::DYN,
-                    },
-                    _ => Function::STA,
-                },
-                match ca_shortcut {
-                    CaShortcutMode::None => match vr.ok_or(ParseError::ExpectedVr)?.sequence {
-                        VowelFormSequence::S1 => Context::EXS,
-                        VowelFormSequence::S2 => Context::FNC,
-                        VowelFormSequence::S3 => Context::RPS,
-                        VowelFormSequence::S4 => Context::AMG,
-                    },
-                    _ => Context::EXS,
-                },
-                match root {
-                    Root::C(cr) => ShortcutCheckedFormativeRoot::Referential(cr.parse()?),
-                    Root::N(_) => return Err(ParseError::ExpectedReferentialRoot),
-                },
-            ),
+        if self.slot_vii_affixes.push(affix).is_err() {
+            return false;
+        }
 
-            WordType::Affixual { version, function } => (
-                Stem::S1,
-                version,
-                Shortcut::None(AffixShortcut::None),
-                Specification::BSC,
-                function,
-                match ca_shortcut {
-                    CaShortcutMode::None => match vr.ok_or(ParseError::ExpectedVr)?.sequence {
-                        VowelFormSequence::S1 => Context::EXS,
-                        VowelFormSequence::S2 => Context::FNC,
-                        VowelFormSequence::S3 => Context::RPS,
-                        VowelFormSequence::S4 => Context::AMG,
-                    },
-                    _ => return Err(ParseError::AffixualFormativeWithCaShortcut),
-                },
-                match root {
-                    Root::C(cr) => ShortcutCheckedFormativeRoot::Affixual(AffixualFormativeRoot {
-                        cs: cr.0,
-                        degree: vr.ok_or(ParseError::ExpectedVr)?.degree.into(),
-                    }),
-                    Root::N(_) => return Err(ParseError::ExpectedReferentialRoot),
-                },
-            ),
-        };
+        self.vn = Vn::default();
+        true
+    }
 
-        // We've officially completed the easy part of parsing.
-        //
-        // There are now quite a few options left for how the token stream can be
-        // parsed.
-        //
-        // For formatives with Ca shortcuts, there is only one option:
-        // 1. (VC...')(VC...)(VH)     (slot V affixes, then slot VII affixes, then VnCn)
-        //
-        // For other formatives, there are several choices:
-        // 1. C(VC...)(VH)            (an ungeminated Ca, then slot VII affixes, then VnCn)
-        // 2. (CV...)CC(VC...)(VH)    (slot V affixes, then a geminated Ca, then VnCn)
-        // 3. H(VC...)                (a Cn shortcut, then slot VII affixes)
-        //
-        // Time to get to work!
-
-        enum VnCn {
-            VnCn(Vn, ArbitraryMoodOrCaseScope),
-            JustCn(ArbitraryMoodOrCaseScope),
-            Nothing,
+    /// Moves the last slot VII affix which represents a Vn into this formative's Vn. This is the
+    /// inverse of [`vn_to_affix`][Self::vn_to_affix].
+    ///
+    /// Cn shortcuts can't show a Vn, so a formative using one is switched to its unshortcut form.
+    ///
+    /// Returns `false` and leaves the formative unchanged if its Vn is not the default or none of
+    /// its slot VII affixes represent a Vn.
+    pub fn affix_to_vn(&mut self) -> bool {
+        if self.vn != Vn::default() {
+            return false;
         }
 
-        let (vncn, does_vn_have_glottal_stop) = match stream.next_back::<HForm>() {
-            Some(cn) => match stream.next_back_any() {
-                Some(Token::V(vn)) => {
-                    let is_aspect = matches!(cn.sequence, HFormSequence::SW | HFormSequence::SY);
-
-                    let cn = match cn.degree {
-                        HFormDegree::D1 => ArbitraryMoodOrCaseScope::FAC_CCN,
-                        HFormDegree::D2 => ArbitraryMoodOrCaseScope::SUB_CCA,
-                        HFormDegree::D3 => ArbitraryMoodOrCaseScope::ASM_CCS,
-                        HFormDegree::D4 => ArbitraryMoodOrCaseScope::SPC_CCQ,
-                        HFormDegree::D5 => ArbitraryMoodOrCaseScope::COU_CCP,
-                        HFormDegree::D6 => ArbitraryMoodOrCaseScope::HYP_CCV,
-                    };
-
-                    (
-                        VnCn::VnCn(
-                            Vn::from_vowel_form(*vn, is_aspect).ok_or(ParseError::ExpectedVn)?,
-                            cn,
-                        ),
-                        vn.has_glottal_stop,
-                    )
-                }
-                None => (
-                    VnCn::JustCn(match cn.sequence {
-                        HFormSequence::S0 => match cn.degree {
-                            HFormDegree::D1 => return Err(ParseError::DefaultCnShortcut),
-                            HFormDegree::D2 => ArbitraryMoodOrCaseScope::SUB_CCA,
-                            HFormDegree::D3 => ArbitraryMoodOrCaseScope::ASM_CCS,
-                            HFormDegree::D4 => ArbitraryMoodOrCaseScope::SPC_CCQ,
-                            HFormDegree::D5 => ArbitraryMoodOrCaseScope::COU_CCP,
-                            HFormDegree::D6 => ArbitraryMoodOrCaseScope::HYP_CCV,
-                        },
-                        _ => return Err(ParseError::AspectualCnShortcut),
-                    }),
-                    false,
-                ),
-                _ => return Err(ParseError::ExpectedVn),
-            },
-            None => (VnCn::Nothing, false),
+        let AffixList::Normal(affixes) = &mut self.slot_vii_affixes else {
+            return false;
         };
 
-        let mut does_vx_have_glottal_stop = false;
+        let Some((index, vn)) =
+            affixes
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(index, affix)| match affix {
+                    RegularAffix::Plain(affix) => affix_vn(affix).map(|vn| (index, vn)),
+                    _ => None,
+                })
+        else {
+            return false;
+        };
 
-        enum MiddleSegments<AffixListType> {
-            Normal {
-                slot_v_affixes: AffixListType,
-                ca: Ca,
-                slot_vii_affixes: AffixListType,
-                vncn: Option<(Vn, ArbitraryMoodOrCaseScope)>,
-                affix_shortcut: AffixShortcut,
-            },
+        affixes.remove(index);
+        self.vn = vn;
 
-            Ca {
-                ca: NormalCaShortcut,
-                slot_v_affixes: AffixListType,
-                slot_vii_affixes: AffixListType,
-                vncn: Option<(Vn, ArbitraryMoodOrCaseScope)>,
-            },
-
-            Cn {
-                cn: ArbitraryMoodOrCaseScope,
-                slot_vii_affixes: AffixListType,
-                affix_shortcut: AffixShortcut,
-            },
+        if self.shortcut == ShortcutType::Cn {
+            self.shortcut = ShortcutType::Normal;
         }
 
-        type UnparsedMiddleSegments = MiddleSegments<Vec<(VowelForm, OwnedConsonantForm)>>;
-
-        let middle: UnparsedMiddleSegments = match shortcut {
-            Shortcut::Ca(ca) => {
-                let vncn = match vncn {
-                    VnCn::Nothing => None,
-                    VnCn::JustCn(_) => return Err(ParseError::ExpectedVn),
-                    VnCn::VnCn(vn, cn) => Some((vn, cn)),
-                };
+        true
+    }
+}
 
-                let mut slot_v_affixes = Vec::new();
-                let mut slot_vii_affixes = Vec::new();
-
-                loop {
-                    match stream.next_any() {
-                        None => break,
-                        Some(Token::V(vx)) => {
-                            let vx = *vx;
-                            match stream.next_any() {
-                                Some(Token::C(cs)) => {
-                                    slot_vii_affixes.push((vx, cs.clone()));
-
-                                    if cs.is_geminate() {
-                                        return Err(ParseError::GeminatedCs);
-                                    }
-
-                                    if vx.has_glottal_stop {
-                                        if slot_v_affixes.is_empty() {
-                                            slot_v_affixes = slot_vii_affixes;
-                                            slot_vii_affixes = Vec::new();
-                                        } else {
-                                            return Err(ParseError::MultipleEndOfSlotVMarkers);
-                                        }
-                                    }
-                                }
-                                _ => return Err(ParseError::ExpectedCs),
-                            }
-                        }
-                        _ => return Err(ParseError::ExpectedVx),
-                    }
-                }
+/// Gets the slot VII affix which represents a Vn.
+fn vn_affix(vn: Vn) -> PlainAffix {
+    let (cs, degree) = match vn {
+        Vn::Valence(value) => (Valence::CS_FORM, value.to_affix_degree()),
+        Vn::Phase(value) => (Phase::CS_FORM, value.to_affix_degree()),
+        Vn::Effect(value) => (Effect::CS_FORM, value.to_affix_degree()),
+        Vn::Level(value) => (Level::CS_FORM, value.to_affix_degree()),
+        Vn::AbsoluteLevel(value) => {
+            return PlainAffix::new(Level::CS_FORM, AffixType::T2, value.to_affix_degree())
+        }
+        Vn::Aspect(value) => value.to_cs_and_degree(),
+    };
 
-                MiddleSegments::Ca {
-                    ca,
-                    slot_v_affixes,
-                    slot_vii_affixes,
-                    vncn,
-                }
-            }
+    PlainAffix::new(cs, AffixType::T1, degree)
+}
 
-            Shortcut::None(affix_shortcut) => {
-                if let VnCn::JustCn(cn) = vncn {
-                    MiddleSegments::Cn {
-                        cn,
-                        slot_vii_affixes: Vec::new(),
-                        affix_shortcut,
-                    }
-                } else {
-                    // Quick reminder of possible forms at this point:
-                    // 1. C(VC...)            (an ungeminated Ca, then slot VII affixes)
-                    // 2. (CV...)CC(VC...)    (slot V affixes, then a geminated Ca)
-                    // 3. H(VC...)            (a Cn shortcut, then slot VII affixes)
-
-                    if let Some(HForm { sequence, degree }) = stream.next() {
-                        if sequence != HFormSequence::S0 {
-                            return Err(ParseError::AspectualCnShortcut);
-                        }
+/// Gets the Vn represented by a slot VII affix, if there is one.
+fn affix_vn(affix: &PlainAffix) -> Option<Vn> {
+    if affix.r#type == AffixType::T2 && affix.cs == Level::CS_FORM {
+        return Level::from_affix_degree(affix.degree).map(Vn::AbsoluteLevel);
+    }
 
-                        let cn = match degree {
-                            HFormDegree::D1 => return Err(ParseError::DefaultCnShortcut),
-                            HFormDegree::D2 => ArbitraryMoodOrCaseScope::SUB_CCA,
-                            HFormDegree::D3 => ArbitraryMoodOrCaseScope::ASM_CCS,
-                            HFormDegree::D4 => ArbitraryMoodOrCaseScope::SPC_CCQ,
-                            HFormDegree::D5 => ArbitraryMoodOrCaseScope::COU_CCP,
-                            HFormDegree::D6 => ArbitraryMoodOrCaseScope::HYP_CCV,
-                        };
-
-                        let mut slot_vii_affixes = Vec::new();
-
-                        loop {
-                            match stream.next_any() {
-                                None => break,
-                                Some(Token::V(vx)) => {
-                                    let vx = *vx;
-                                    match stream.next_any() {
-                                        Some(Token::C(cs)) => {
-                                            slot_vii_affixes.push((vx, cs.clone()));
-
-                                            if cs.is_geminate() {
-                                                return Err(ParseError::GeminatedCs);
-                                            }
-
-                                            if vx.has_glottal_stop {
-                                                if does_vx_have_glottal_stop {
-                                                    return Err(ParseError::DoublyGlottalizedVx);
-                                                } else {
-                                                    does_vx_have_glottal_stop = true;
-                                                }
-                                            }
-                                        }
-                                        _ => return Err(ParseError::ExpectedCs),
-                                    }
-                                }
-                                _ => return Err(ParseError::ExpectedVx),
-                            }
-                        }
+    if affix.r#type != AffixType::T1 {
+        return None;
+    }
 
-                        MiddleSegments::Cn {
-                            cn,
-                            slot_vii_affixes,
-                            affix_shortcut,
-                        }
-                    } else {
-                        if stream
-                            .remaining_tokens()
-                            .iter()
-                            .any(|x| matches!(x, Token::C(cs) if cs.is_geminate()))
-                        {
-                            let mut slot_v_affixes = Vec::new();
-
-                            'outer: loop {
-                                match stream.next_any() {
-                                    Some(Token::C(cs)) => {
-                                        let cs = cs.clone();
-
-                                        if cs.is_geminate() {
-                                            let ca = Ca::from_geminated_string(&cs)
-                                                .ok_or(ParseError::ExpectedCa)?;
-
-                                            let mut slot_vii_affixes = Vec::new();
-
-                                            // Do we really have 11 levels of nesting? So proud /hj
-
-                                            loop {
-                                                match stream.next_any() {
-                                                    None => {
-                                                        break 'outer MiddleSegments::Normal {
-                                                            slot_v_affixes,
-                                                            ca,
-                                                            slot_vii_affixes,
-                                                            vncn: match vncn {
-                                                                VnCn::VnCn(vn, cn) => {
-                                                                    Some((vn, cn))
-                                                                }
-                                                                VnCn::JustCn(_) => unreachable!(
-                                                                    // 68 indent characters?
-                                                                    // New world record!
-                                                                    "we caught this already"
-                                                                ),
-                                                                VnCn::Nothing => None,
-                                                            },
-                                                            affix_shortcut,
-                                                        };
-                                                    }
-
-                                                    Some(Token::V(vx)) => {
-                                                        let vx = *vx;
-
-                                                        let cs = stream
-                                                            .next_cs()
-                                                            .ok_or(ParseError::ExpectedCs)?;
-
-                                                        if cs.is_geminate() {
-                                                            return Err(ParseError::GeminatedCs);
-                                                        }
-
-                                                        if vx.has_glottal_stop {
-                                                            if does_vx_have_glottal_stop {
-                                                                return Err(
-                                                                    ParseError::DoublyGlottalizedVx,
-                                                                );
-                                                            } else {
-                                                                does_vx_have_glottal_stop = true;
-                                                            }
-                                                        }
-
-                                                        slot_vii_affixes.push((vx, cs.to_owned()));
-                                                    }
-
-                                                    _ => return Err(ParseError::ExpectedVx),
-                                                }
-                                            }
-                                        } else {
-                                            let vx: VowelForm =
-                                                stream.next().ok_or(ParseError::ExpectedVx)?;
-
-                                            if vx.has_glottal_stop {
-                                                if does_vx_have_glottal_stop {
-                                                    return Err(ParseError::DoublyGlottalizedVx);
-                                                } else {
-                                                    does_vx_have_glottal_stop = true;
-                                                }
-                                            }
-
-                                            slot_v_affixes.push((vx, cs));
-                                        }
-                                    }
-                                    _ => return Err(ParseError::ExpectedCs),
-                                }
-                            }
-                        } else {
-                            let ca = stream.next_cs().ok_or(ParseError::ExpectedCa)?;
-
-                            let ca =
-                                Ca::from_ungeminated_string(&ca).ok_or(ParseError::ExpectedCa)?;
-
-                            let mut slot_vii_affixes = Vec::new();
-
-                            loop {
-                                match stream.next_any() {
-                                    None => break,
-                                    Some(Token::V(vx)) => {
-                                        let vx = *vx;
-                                        match stream.next_any() {
-                                            Some(Token::C(cs)) => {
-                                                slot_vii_affixes.push((vx, cs.clone()));
-
-                                                if cs.is_geminate() {
-                                                    return Err(ParseError::GeminatedCs);
-                                                }
-
-                                                if vx.has_glottal_stop {
-                                                    if does_vx_have_glottal_stop {
-                                                        return Err(
-                                                            ParseError::DoublyGlottalizedVx,
-                                                        );
-                                                    } else {
-                                                        does_vx_have_glottal_stop = true;
-                                                    }
-                                                }
-                                            }
-                                            _ => return Err(ParseError::ExpectedCs),
-                                        }
-                                    }
-                                    _ => return Err(ParseError::ExpectedVx),
-                                }
-                            }
+    match &affix.cs[..] {
+        Valence::CS_FORM => Valence::from_affix_degree(affix.degree).map(Vn::Valence),
+        Phase::CS_FORM => Phase::from_affix_degree(affix.degree).map(Vn::Phase),
+        Effect::CS_FORM => Effect::from_affix_degree(affix.degree).map(Vn::Effect),
+        Level::CS_FORM => Level::from_affix_degree(affix.degree).map(Vn::Level),
+        cs => Aspect::from_cs_and_degree(cs, affix.degree).map(Vn::Aspect),
+    }
+}
 
-                            MiddleSegments::Normal {
-                                slot_v_affixes: Vec::new(),
-                                ca,
-                                slot_vii_affixes,
-                                vncn: match vncn {
-                                    VnCn::VnCn(vn, cn) => Some((vn, cn)),
-                                    VnCn::JustCn(_) => unreachable!("we caught this already"),
-                                    VnCn::Nothing => None,
-                                },
-                                affix_shortcut,
-                            }
-                        }
-                    }
-                }
+macro_rules! to_romanized {
+    ($($type:ident,)+) => {
+        $(impl $type {
+            /// Romanizes this formative, marking stress as required by its relation (ultimate for
+            /// verbal formatives, antepenultimate for framed ones, and so on).
+            ///
+            /// This is shorthand for `self.to_string_with(IntoTokensFlags::NONE)`.
+            pub fn to_romanized(&self) -> String {
+                self.to_string_with(IntoTokensFlags::NONE)
             }
-        };
 
-        // Time to parse affixes.
-
-        type ParsedMiddleSegments = MiddleSegments<AffixList>;
-
-        let middle_parsed: ParsedMiddleSegments = match middle {
-            MiddleSegments::Normal {
-                slot_v_affixes,
-                ca,
-                slot_vii_affixes,
-                vncn,
-                affix_shortcut,
-            } => MiddleSegments::Normal {
-                slot_v_affixes: {
-                    let slot_v_affixes = AffixList::from_vxcs_slice(&slot_v_affixes)?;
-
-                    if !flags.matches(FromTokenFlags::PERMISSIVE) {
-                        if does_vv_have_glottal_stop {
-                            if slot_v_affixes.len() <= 1 {
-                                return Err(ParseError::TooFewSlotVAffixes);
-                            }
-                        } else {
-                            if slot_v_affixes.len() > 1 {
-                                return Err(ParseError::TooManySlotVAffixes);
-                            }
-                        }
-                    }
+            /// Counts the syllables in the romanization of this formative. See
+            /// [`count_syllables`][crate::romanize::transform::count_syllables] for the exact
+            /// rules.
+            pub fn syllable_count(&self) -> usize {
+                self.into_tokens(IntoTokensFlags::NONE).syllable_count()
+            }
+        })+
+    };
+}
 
-                    slot_v_affixes
-                },
-                ca,
-                slot_vii_affixes: AffixList::from_vxcs_slice(&slot_vii_affixes)?,
-                vncn,
-                affix_shortcut,
-            },
+to_romanized!(
+    CheckedFormative,
+    ShortcutCheckedFormative,
+    UncheckedFormative,
+);
 
-            MiddleSegments::Ca {
-                ca,
-                slot_v_affixes,
-                slot_vii_affixes,
-                vncn,
-            } => MiddleSegments::Ca {
-                ca,
-                slot_v_affixes: {
-                    let slot_v_affixes = AffixList::from_vxcs_slice(&slot_v_affixes)?;
-
-                    if !flags.matches(FromTokenFlags::PERMISSIVE) {
-                        if does_vv_have_glottal_stop {
-                            if slot_v_affixes.len() <= 1 {
-                                return Err(ParseError::TooFewSlotVAffixes);
-                            }
-                        } else {
-                            if slot_v_affixes.len() > 1 {
-                                return Err(ParseError::TooManySlotVAffixes);
+macro_rules! parse_prefix {
+    ($($type:ident,)+) => {
+        $(impl $type {
+            /// Parses a formative from the front of a [`TokenStream`] which may contain more tokens
+            /// after it, returning the formative and the number of tokens it consumed.
+            ///
+            /// The formative parser reads Vc/Vk and Cn from the back of its stream, so it normally
+            /// assumes that the stream holds exactly one word. This method instead tries the
+            /// longest prefix of the remaining tokens first, then shorter ones, and returns the
+            /// first which parses as a complete formative. The stress of the stream is used as the
+            /// stress of the formative.
+            ///
+            /// If an [`Err`] is returned, the stream is left unchanged and the error is the one
+            /// from parsing the entire stream.
+            pub fn parse_prefix(
+                stream: &mut TokenStream,
+                flags: FromTokenFlags,
+            ) -> Result<(Self, usize), ParseError> {
+                let checkpoint = stream.checkpoint();
+                let mut first_error = None;
+
+                for len in (1..=stream.remaining()).rev() {
+                    stream.truncate(len);
+
+                    match stream.parse_entire::<Self>(flags) {
+                        Ok(value) => {
+                            stream.restore(checkpoint);
+                            for _ in 0..len {
+                                let _ = stream.next_any();
                             }
+                            return Ok((value, len));
+                        }
+                        Err(error) => {
+                            first_error.get_or_insert(error);
+                            stream.restore(checkpoint);
                         }
                     }
+                }
 
-                    slot_v_affixes
-                },
-                slot_vii_affixes: AffixList::from_vxcs_slice(&slot_vii_affixes)?,
-                vncn,
-            },
-
-            MiddleSegments::Cn {
-                cn,
-                slot_vii_affixes,
-                affix_shortcut,
-            } => MiddleSegments::Cn {
-                cn,
-                slot_vii_affixes: AffixList::from_vxcs_slice(&slot_vii_affixes)?,
-                affix_shortcut,
-            },
-        };
-
-        // We're free! Now we just have to put everything together
-
-        let (additions, slot_vii_affixes) = match middle_parsed {
-            MiddleSegments::Normal {
-                slot_v_affixes,
-                ca,
-                slot_vii_affixes,
-                vncn,
-                affix_shortcut,
-            } => (
-                ShortcutCheckedFormativeAdditions::Normal(NormalNonShortcutAdditions {
-                    relation: match relation_type {
-                        RelationType::Verbal => Relation::Verbal {
-                            mood: match vncn {
-                                Some(vncn) => vncn.1.as_specific(),
-                                _ => Mood::FAC,
-                            },
-                            ivl: {
-                                let mut vk = vc_or_vk.unwrap_or_default();
-                                vk.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                vk.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                vk.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                IllocutionOrValidation::from_vk(vk)?
-                            },
-                        },
-                        _ => Relation::Nominal {
-                            mode: match relation_type {
-                                RelationType::Nominal => NominalMode::NOM,
-                                RelationType::T1(_) => NominalMode::T1,
-                                RelationType::T2(_) => NominalMode::T2,
-                                RelationType::Framed => NominalMode::FRM,
-                                RelationType::Verbal => unreachable!(),
-                            },
-                            case_scope: vncn.map(|x| x.1.as_specific()).unwrap_or_default(),
-                            case: match relation_type {
-                                RelationType::Nominal | RelationType::Framed => {
-                                    let mut vc = vc_or_vk.unwrap_or_default();
-                                    vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                    Case::from_vc(vc)?
-                                }
-                                RelationType::T1(is_high) | RelationType::T2(is_high) => {
-                                    let mut vc = vc_or_vk.unwrap_or_default();
-                                    vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                    if vc.has_glottal_stop {
-                                        return Err(ParseError::GlottalizedVc);
-                                    }
-                                    vc.has_glottal_stop = is_high;
-                                    Case::from_vc(vc)?
-                                }
-                                RelationType::Verbal => unreachable!(),
-                            },
-                        },
-                    },
-                    affix_shortcut,
-                    function,
-                    specification: specification,
-                    context,
-                    slot_v_affixes,
-                    ca,
-                    vn: match vncn {
-                        Some(vncn) => vncn.0,
-                        _ => Vn::Valence(Valence::MNO),
-                    },
-                }),
-                slot_vii_affixes,
-            ),
-
-            MiddleSegments::Cn {
-                cn,
-                slot_vii_affixes,
-                affix_shortcut,
-            } => (
-                ShortcutCheckedFormativeAdditions::CnShortcut(NormalCnShortcutAdditions {
-                    relation: match relation_type {
-                        RelationType::Verbal => Relation::Verbal {
-                            mood: cn
-                                .try_as_specific()
-                                .ok_or(ParseError::ExpectedNonDefaultCn)?,
-                            ivl: {
-                                let mut vk = vc_or_vk.unwrap_or_default();
-                                vk.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                vk.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                vk.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                IllocutionOrValidation::from_vk(vk)?
-                            },
-                        },
-                        _ => Relation::Nominal {
-                            mode: match relation_type {
-                                RelationType::Nominal => NominalMode::NOM,
-                                RelationType::T1(_) => NominalMode::T1,
-                                RelationType::T2(_) => NominalMode::T2,
-                                RelationType::Framed => NominalMode::FRM,
-                                RelationType::Verbal => unreachable!(),
-                            },
-                            case_scope: cn
-                                .try_as_specific()
-                                .ok_or(ParseError::ExpectedNonDefaultCn)?,
-                            case: match relation_type {
-                                RelationType::Nominal | RelationType::Framed => {
-                                    let mut vc = vc_or_vk.unwrap_or_default();
-                                    vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                    Case::from_vc(vc)?
-                                }
-                                RelationType::T1(is_high) | RelationType::T2(is_high) => {
-                                    let mut vc = vc_or_vk.unwrap_or_default();
-                                    vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                    if vc.has_glottal_stop {
-                                        return Err(ParseError::GlottalizedVc);
-                                    }
-                                    vc.has_glottal_stop = is_high;
-                                    Case::from_vc(vc)?
-                                }
-                                RelationType::Verbal => unreachable!(),
-                            },
-                        },
-                    },
-                    affix_shortcut,
-                    function,
-                    specification,
-                    context,
-                }),
-                slot_vii_affixes,
-            ),
-
-            MiddleSegments::Ca {
-                ca,
-                slot_v_affixes,
-                slot_vii_affixes,
-                vncn,
-            } => (
-                ShortcutCheckedFormativeAdditions::CaShortcut(NormalCaShortcutAdditions {
-                    relation: match relation_type {
-                        RelationType::Verbal => Relation::Verbal {
-                            mood: match vncn {
-                                Some(vncn) => vncn.1.as_specific(),
-                                _ => Mood::FAC,
-                            },
-                            ivl: {
-                                let mut vk = vc_or_vk.unwrap_or_default();
-                                vk.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                vk.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                vk.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                IllocutionOrValidation::from_vk(vk)?
-                            },
-                        },
-                        _ => Relation::Nominal {
-                            mode: match relation_type {
-                                RelationType::Nominal => NominalMode::NOM,
-                                RelationType::T1(_) => NominalMode::T1,
-                                RelationType::T2(_) => NominalMode::T2,
-                                RelationType::Framed => NominalMode::FRM,
-                                RelationType::Verbal => unreachable!(),
-                            },
-                            case_scope: vncn.map(|x| x.1.as_specific()).unwrap_or_default(),
-                            case: match relation_type {
-                                RelationType::Nominal | RelationType::Framed => {
-                                    let mut vc = vc_or_vk.unwrap_or_default();
-                                    vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                    Case::from_vc(vc)?
-                                }
-                                RelationType::T1(is_high) | RelationType::T2(is_high) => {
-                                    let mut vc = vc_or_vk.unwrap_or_default();
-                                    vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
-                                    vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
-                                    if vc.has_glottal_stop {
-                                        return Err(ParseError::GlottalizedVc);
-                                    }
-                                    vc.has_glottal_stop = is_high;
-                                    Case::from_vc(vc)?
-                                }
-                                RelationType::Verbal => unreachable!(),
-                            },
-                        },
-                    },
-                    slot_v_affixes,
-                    ca,
-                    vn: vncn.map(|x| x.0).unwrap_or_default(),
-                }),
-                slot_vii_affixes,
-            ),
-        };
-
-        Ok(ShortcutCheckedFormative(
-            ShortcutCheckedFormativeCore {
-                root,
-                slot_vii_affixes,
-                stem,
-                version,
-            },
-            additions,
-        ))
-    }
+                Err(first_error.unwrap_or(ParseError::WordEmpty))
+            }
+        })+
+    };
 }
 
-impl FromTokens for UncheckedFormative {
-    fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
-        ShortcutCheckedFormative::parse_volatile(stream, flags)
-            .map(|formative| formative.as_general())
-    }
-}
+parse_prefix!(
+    CheckedFormative,
+    ShortcutCheckedFormative,
+    UncheckedFormative,
+);
 
 impl IntoTokens for CheckedFormative {
     fn append_tokens_to(&self, list: &mut TokenList, flags: IntoTokensFlags) {
@@ -2089,6 +1646,14 @@ impl IntoTokens for UncheckedFormative {
         // The implementation here is guaranteed to work on properly constructed formatives and will
         // likely fail for everything else. It's _not intended_ to handle edge cases.
 
+        if self.vn.is_absolute_level() {
+            let mut formative = self.clone();
+
+            if formative.vn_to_affix() {
+                return formative.append_tokens_to(list, flags);
+            }
+        }
+
         let ca_shortcut_mode = match self.shortcut {
             ShortcutType::Ca => match self.ca {
                 ca_pat!(PRX) | ca_pat!(RPV) | ca_pat!(A) | ca_pat!(PRX, RPV) => Some(true),
@@ -2119,17 +1684,17 @@ impl IntoTokens for UncheckedFormative {
             list.push(cc);
         }
 
+        // Glottal stops can come from three places, which the parser tells apart by position: Vv
+        // marks two or more slot V affixes, the last slot V Vx marks the end of slot V in Ca
+        // shortcut forms, and Vc keeps its own unless `MOVE_VC_GLOTTAL_STOP` moves it to Vr, or to
+        // Vn if there is no Vr.
         let does_vv_need_glottal_stop = self.slot_v_affixes.len() >= 2;
 
         let mut vc = self.vc.into_vowel_form();
 
+        // Concatenated formatives mark a glottal stop in Vc with ultimate stress instead.
         if matches!(self.relation, DatalessRelation::T1 | DatalessRelation::T2) {
-            if vc.has_glottal_stop {
-                vc.has_glottal_stop = false;
-                list.set_stress(Stress::Ultimate);
-            } else {
-                list.set_stress(Stress::Penultimate);
-            }
+            vc.has_glottal_stop = false;
         }
 
         let vv = match self.root {
@@ -2161,12 +1726,7 @@ impl IntoTokens for UncheckedFormative {
                         _ => VowelFormSequence::S1,
                     }
                 } else {
-                    match self.affix_shortcut {
-                        AffixShortcut::None => VowelFormSequence::S1,
-                        AffixShortcut::NEG4 => VowelFormSequence::S2,
-                        AffixShortcut::DCD4 => VowelFormSequence::S3,
-                        AffixShortcut::DCD5 => VowelFormSequence::S4,
-                    }
+                    self.affix_shortcut.to_vv_sequence()
                 },
                 degree: match (self.stem, self.version) {
                     (Stem::S1, Version::PRC) => VowelFormDegree::D1,
@@ -2413,13 +1973,6 @@ impl IntoTokens for UncheckedFormative {
             list.push(vc);
         }
 
-        match self.relation {
-            DatalessRelation::NOM => list.set_stress(Stress::Penultimate),
-            DatalessRelation::VRB => list.set_stress(Stress::Ultimate),
-            DatalessRelation::FRM => list.set_stress(Stress::Antepenultimate),
-            DatalessRelation::T1 | DatalessRelation::T2 => {
-                // We already set stress for concatenated formatives.
-            }
-        }
+        list.set_stress(self.stress());
     }
 }
@@ -42,8 +42,13 @@
 
 pub mod additions;
 pub mod core;
+pub mod diff;
 pub(super) mod extended;
 pub(super) mod formative;
+#[cfg(feature = "unstable-internals")]
+pub mod parse;
+#[cfg(not(feature = "unstable-internals"))]
+mod parse;
 pub mod relation;
 pub mod root;
 
@@ -0,0 +1,1256 @@
+//! Provides the individual stages used to parse a [`ShortcutCheckedFormative`].
+//!
+//! Parsing a formative happens in five steps:
+//!
+//! 1. Vc/Vk is taken off the end of the stream with [`TokenStream::next_back`].
+//! 2. [`parse_cc_vv`] parses the Cc and Vv slots.
+//! 3. [`parse_root`] parses the Cr and Vr slots.
+//! 4. [`parse_middle_segments`] parses slots V through VIII.
+//! 5. [`assemble`] puts everything together.
+//!
+//! These are exactly the steps taken by [`FromTokens::parse_volatile`] for
+//! [`ShortcutCheckedFormative`], so they may be used to build parsers for extended formatives. When
+//! those steps fail and [`FromTokenFlags::ALLOW_BARE_ROOT`] is set, [`parse_bare_root`] is tried
+//! instead. They are only public when the `unstable-internals` feature is enabled, and may change
+//! between minor versions.
+
+use super::formative::ShortcutCheckedFormative;
+use crate::{
+    affix::AffixList,
+    category::{
+        AffixShortcut, ArbitraryMoodOrCaseScope, Ca, Case, Context, Function, HFormDegree,
+        HFormSequence, IllocutionOrValidation, Mood, NominalMode, NormalCaShortcut, Specification,
+        Stem, Stress, Valence, Version, Vn, VowelFormDegree, VowelFormSequence,
+    },
+    prelude::*,
+    romanize::{
+        stream::TokenStream,
+        token::{ConsonantForm, HForm, NumeralForm, OwnedConsonantForm, Token, VowelForm},
+    },
+    word::formative::{
+        additions::{
+            NormalCaShortcutAdditions, NormalCnShortcutAdditions, NormalNonShortcutAdditions,
+            ShortcutCheckedFormativeAdditions,
+        },
+        core::ShortcutCheckedFormativeCore,
+        relation::Relation,
+        root::{
+            AffixualFormativeRoot, NormalFormativeRoot, NumericFormativeRoot,
+            ShortcutCheckedFormativeRoot,
+        },
+    },
+};
+
+/// The Ca shortcut mode of a formative, as given by its Cc slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaShortcutMode {
+    /// A Ca shortcut from the w-series.
+    W,
+
+    /// A Ca shortcut from the y-series.
+    Y,
+
+    /// No Ca shortcut.
+    None,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Concatenation {
+    T1,
+    T2,
+    None,
+}
+
+/// The relation of a formative, as given by its Cc slot and stress.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelationType {
+    /// A type-1 concatenated formative. The bool here indicates if the word has cases 37-68.
+    T1(bool),
+
+    /// A type-2 concatenated formative. The bool here indicates if the word has cases 37-68.
+    T2(bool),
+
+    /// A nominal formative.
+    Nominal,
+
+    /// An unframed verbal formative.
+    Verbal,
+
+    /// A framed verbal formative.
+    Framed,
+}
+
+/// The kind of root a formative has, along with the values its Vv slot provides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordType {
+    /// A normal or numeric formative.
+    NormalOrNumeric {
+        /// The version of the formative.
+        version: Version,
+
+        /// The stem of the formative.
+        stem: Stem,
+
+        /// The series of the Vv slot, which picks an affix or Ca shortcut.
+        sequence: VowelFormSequence,
+    },
+
+    /// A referential formative.
+    Referential {
+        /// The version of the formative.
+        version: Version,
+    },
+
+    /// An affixual formative.
+    Affixual {
+        /// The version of the formative.
+        version: Version,
+
+        /// The function of the formative.
+        function: Function,
+    },
+}
+
+/// The shortcut a formative uses, as given by its Cc and Vv slots.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shortcut {
+    /// No Ca shortcut, with an optional affix shortcut.
+    None(AffixShortcut),
+
+    /// A Ca shortcut.
+    Ca(NormalCaShortcut),
+}
+
+/// The values provided by the Cc and Vv slots of a formative.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CcVv {
+    /// The Ca shortcut mode of the formative.
+    pub ca_shortcut: CaShortcutMode,
+
+    /// The relation of the formative.
+    pub relation_type: RelationType,
+
+    /// The Vv slot, left uninterpreted.
+    pub vv: VowelForm,
+
+    /// The kind of root the formative has.
+    pub word_type: WordType,
+}
+
+#[derive(Debug)]
+enum Root {
+    C(OwnedConsonantForm),
+    N(NumeralForm),
+}
+
+/// The values provided by the first four slots of a formative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrVr {
+    /// The stem of the formative.
+    pub stem: Stem,
+
+    /// The version of the formative.
+    pub version: Version,
+
+    /// The shortcut the formative uses.
+    pub shortcut: Shortcut,
+
+    /// The specification of the formative.
+    pub specification: Specification,
+
+    /// The function of the formative.
+    pub function: Function,
+
+    /// The context of the formative.
+    pub context: Context,
+
+    /// The root of the formative.
+    pub root: ShortcutCheckedFormativeRoot,
+
+    /// Whether the Vv slot had a glottal stop.
+    pub does_vv_have_glottal_stop: bool,
+
+    /// Whether the Vr slot had a glottal stop.
+    pub does_vr_have_glottal_stop: bool,
+}
+
+enum VnCn {
+    VnCn(Vn, ArbitraryMoodOrCaseScope),
+    JustCn(ArbitraryMoodOrCaseScope),
+    Nothing,
+}
+
+/// Slots V through VIII of a formative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MiddleSegments<AffixListType> {
+    /// A formative without a shortcut.
+    Normal {
+        /// The slot V affixes of the formative.
+        slot_v_affixes: AffixListType,
+
+        /// The Ca of the formative.
+        ca: Ca,
+
+        /// The slot VII affixes of the formative.
+        slot_vii_affixes: AffixListType,
+
+        /// The VnCn of the formative, if one was present.
+        vncn: Option<(Vn, ArbitraryMoodOrCaseScope)>,
+
+        /// The affix shortcut of the formative.
+        affix_shortcut: AffixShortcut,
+    },
+
+    /// A formative with a Ca shortcut.
+    Ca {
+        /// The Ca shortcut of the formative.
+        ca: NormalCaShortcut,
+
+        /// The slot V affixes of the formative.
+        slot_v_affixes: AffixListType,
+
+        /// The slot VII affixes of the formative.
+        slot_vii_affixes: AffixListType,
+
+        /// The VnCn of the formative, if one was present.
+        vncn: Option<(Vn, ArbitraryMoodOrCaseScope)>,
+    },
+
+    /// A formative with a Cn shortcut.
+    Cn {
+        /// The Cn of the formative.
+        cn: ArbitraryMoodOrCaseScope,
+
+        /// The slot VII affixes of the formative.
+        slot_vii_affixes: AffixListType,
+
+        /// The affix shortcut of the formative.
+        affix_shortcut: AffixShortcut,
+    },
+}
+
+/// The values provided by slots V through VIII of a formative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Middle {
+    /// The parsed segments.
+    pub segments: MiddleSegments<AffixList>,
+
+    /// Whether any Vx slot had a glottal stop.
+    pub does_vx_have_glottal_stop: bool,
+
+    /// Whether the Vn slot had a glottal stop.
+    pub does_vn_have_glottal_stop: bool,
+}
+
+/// Parses the Cc and Vv slots of a formative, which determine its relation (along with the stress
+/// of the word), its Ca shortcut mode, and what kind of root it has.
+pub fn parse_cc_vv(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<CcVv, ParseError> {
+    // The Cc and Vv slots tell us pretty much everything else about how the word is
+    // constructed. So we'll parse those next. How convenient that they're at the
+    // beginning of the word.
+
+    // The Cc form gives us the Ca shortcut and concatenation type.
+
+    let (ca_shortcut, concatenation_type, has_cc) = match stream.next() {
+        None => (CaShortcutMode::None, Concatenation::None, false),
+
+        Some(h) => match h {
+            HForm::W => (CaShortcutMode::W, Concatenation::None, true),
+            HForm::Y => (CaShortcutMode::Y, Concatenation::None, true),
+            HForm::H => (CaShortcutMode::None, Concatenation::T1, true),
+            HForm::HL => (CaShortcutMode::W, Concatenation::T1, true),
+            HForm::HR => (CaShortcutMode::W, Concatenation::T2, true),
+            HForm::HM => (CaShortcutMode::Y, Concatenation::T1, true),
+            HForm::HN => (CaShortcutMode::Y, Concatenation::T2, true),
+            HForm::HW => (CaShortcutMode::None, Concatenation::T2, true),
+            _ => return Err(ParseError::ExpectedCc),
+        },
+    };
+
+    // Now we have enough information to figure out the relation of the word.
+
+    let relation_type = match concatenation_type {
+        Concatenation::None => match stream.stress() {
+            Some(Stress::Ultimate | Stress::Monosyllabic) => RelationType::Verbal,
+            Some(Stress::Antepenultimate) => RelationType::Framed,
+            _ => RelationType::Nominal,
+        },
+
+        // The stress of a concatenated formative marks a glottal stop in Vc rather than its
+        // relation, so unmarked monosyllabic stress says nothing about Vc; only an explicit accent
+        // does.
+        Concatenation::T1 => match stream.stress() {
+            Some(Stress::Ultimate) => RelationType::T1(true),
+            Some(Stress::Antepenultimate) => {
+                if flags.matches(FromTokenFlags::PERMISSIVE) {
+                    RelationType::T1(false)
+                } else {
+                    return Err(ParseError::AntepenultimateStress);
+                }
+            }
+            _ => RelationType::T1(false),
+        },
+
+        Concatenation::T2 => match stream.stress() {
+            Some(Stress::Ultimate) => RelationType::T2(true),
+            Some(Stress::Antepenultimate) => {
+                if flags.matches(FromTokenFlags::PERMISSIVE) {
+                    RelationType::T2(false)
+                } else {
+                    return Err(ParseError::AntepenultimateStress);
+                }
+            }
+            _ => RelationType::T2(false),
+        },
+    };
+
+    // If we parse the Vv form too early, we'll be stuck having to deal with enum
+    // variants and stuff. So we'll just capture it for now, detect the word
+    // type, and leave it for later.
+
+    let vv: VowelForm = if has_cc {
+        stream.next().ok_or(ParseError::ExpectedVv)?
+    } else {
+        stream.next().unwrap_or_default()
+    };
+
+    let word_type = match vv {
+        VowelForm {
+            has_glottal_stop: _,
+            sequence,
+            degree: VowelFormDegree::D5,
+        } => WordType::Affixual {
+            version: match sequence {
+                VowelFormSequence::S1 | VowelFormSequence::S3 => Version::PRC,
+                VowelFormSequence::S2 | VowelFormSequence::S4 => Version::CPT,
+            },
+            function: match sequence {
+                VowelFormSequence::S1 | VowelFormSequence::S2 => Function::STA,
+                VowelFormSequence::S3 | VowelFormSequence::S4 => Function::DYN,
+            },
+        },
+        VowelForm {
+            has_glottal_stop: _,
+            sequence,
+            degree: VowelFormDegree::D0,
+        } => WordType::Referential {
+            version: match sequence {
+                VowelFormSequence::S1 => Version::PRC,
+                VowelFormSequence::S2 => Version::CPT,
+                VowelFormSequence::S3 | VowelFormSequence::S4 => {
+                    return Err(ParseError::ExpectedVv)
+                }
+            },
+        },
+        VowelForm {
+            has_glottal_stop: _,
+            sequence,
+            degree,
+        } => WordType::NormalOrNumeric {
+            version: match degree {
+                VowelFormDegree::D1
+                | VowelFormDegree::D3
+                | VowelFormDegree::D7
+                | VowelFormDegree::D9 => Version::PRC,
+                VowelFormDegree::D2
+                | VowelFormDegree::D4
+                | VowelFormDegree::D6
+                | VowelFormDegree::D8 => Version::CPT,
+                _ => unreachable!("we caught degrees 5 and 0 in earlier match arms"),
+            },
+            stem: match degree {
+                VowelFormDegree::D1 | VowelFormDegree::D2 => Stem::S1,
+                VowelFormDegree::D3 | VowelFormDegree::D4 => Stem::S2,
+                VowelFormDegree::D9 | VowelFormDegree::D8 => Stem::S3,
+                VowelFormDegree::D7 | VowelFormDegree::D6 => Stem::S0,
+                _ => unreachable!("we caught degrees 5 and 0 in earlier match arms"),
+            },
+            sequence,
+        },
+    };
+
+    if !matches!(ca_shortcut, CaShortcutMode::None)
+        && matches!(word_type, WordType::Affixual { .. })
+    {
+        return Err(ParseError::AffixualFormativeWithCaShortcut);
+    }
+
+    Ok(CcVv {
+        ca_shortcut,
+        relation_type,
+        vv,
+        word_type,
+    })
+}
+
+/// Parses the Cr and Vr slots of a formative and interprets them alongside the already-parsed Cc
+/// and Vv slots.
+pub fn parse_root(stream: &mut TokenStream, cc_vv: CcVv) -> Result<CrVr, ParseError> {
+    let CcVv {
+        ca_shortcut,
+        relation_type: _,
+        vv,
+        word_type,
+    } = cc_vv;
+
+    // Again, we'll leave these unparsed for now.
+
+    let root = match stream.next_any() {
+        Some(Token::C(value)) => Root::C(value.clone()),
+        Some(Token::N(value)) => {
+            if !matches!(word_type, WordType::NormalOrNumeric { .. }) {
+                return Err(ParseError::ExpectedNonNumericRoot);
+            }
+
+            Root::N(*value)
+        }
+        _ => return Err(ParseError::ExpectedRoot),
+    };
+
+    // Still leaving it unparsed.
+
+    let vr: Option<VowelForm> = match ca_shortcut {
+        CaShortcutMode::None => Some(stream.next().ok_or(ParseError::ExpectedVr)?),
+        _ => None,
+    };
+    // Now we've collected Cc, Vv, Cr, and Vr. That's the easy part done, and we can
+    // piece together most of the formative now.
+    //
+    // We still need to get slots V, VI, VII, and VIII, but we'll do that later in
+    // case there's an early error in the first four slots.
+    //
+    // The slots provide these values:
+    // Cc = concatenation type, Ca shortcut type
+    // Vv =
+    //      normal: stem, version, affix shortcut, Ca shortcut index
+    //    referent: version
+    //    affixual: version, function
+    // Cr = root
+    // Vr =
+    //      normal: specification, function, context
+    //    referent: specification, function, context
+    //    affixual: degree, context
+    // [stress] = relation
+
+    let does_vv_have_glottal_stop = vv.has_glottal_stop;
+    let does_vr_have_glottal_stop = vr.map(|x| x.has_glottal_stop).unwrap_or_default();
+
+    let (stem, version, shortcut, specification, function, context, root) = match word_type {
+        WordType::NormalOrNumeric {
+            version,
+            stem,
+            sequence,
+        } => (
+            stem,
+            version,
+            match ca_shortcut {
+                CaShortcutMode::None => Shortcut::None(AffixShortcut::from_vv_sequence(sequence)),
+                CaShortcutMode::W => Shortcut::Ca(match sequence {
+                    VowelFormSequence::S1 => NormalCaShortcut::Default,
+                    VowelFormSequence::S2 => NormalCaShortcut::G,
+                    VowelFormSequence::S3 => NormalCaShortcut::N,
+                    VowelFormSequence::S4 => NormalCaShortcut::G_RPV,
+                }),
+                CaShortcutMode::Y => Shortcut::Ca(match sequence {
+                    VowelFormSequence::S1 => NormalCaShortcut::PRX,
+                    VowelFormSequence::S2 => NormalCaShortcut::RPV,
+                    VowelFormSequence::S3 => NormalCaShortcut::A,
+                    VowelFormSequence::S4 => NormalCaShortcut::PRX_RPV,
+                }),
+            },
+            match ca_shortcut {
+                CaShortcutMode::None => {
+                    Specification::from_vr_degree(vr.ok_or(ParseError::ExpectedVr)?.degree)
+                        .ok_or(ParseError::ExpectedVr)?
+                }
+                _ => Specification::BSC,
+            },
+            match ca_shortcut {
+                CaShortcutMode::None => {
+                    Function::from_vr_degree(vr.ok_or(ParseError::ExpectedVr)?.degree)
+                        .ok_or(ParseError::ExpectedVr)?
+                }
+                _ => Function::STA,
+            },
+            match ca_shortcut {
+                CaShortcutMode::None => {
+                    Context::from_vr_sequence(vr.ok_or(ParseError::ExpectedVr)?.sequence)
+                }
+                _ => Context::EXS,
+            },
+            match root {
+                Root::C(cr) => ShortcutCheckedFormativeRoot::Normal(NormalFormativeRoot { cr }),
+                Root::N(form) => {
+                    ShortcutCheckedFormativeRoot::Numeric(NumericFormativeRoot { form })
+                }
+            },
+        ),
+
+        WordType::Referential { version } => (
+            Stem::S1,
+            version,
+            Shortcut::None(AffixShortcut::None),
+            match ca_shortcut {
+                CaShortcutMode::None => {
+                    Specification::from_vr_degree(vr.ok_or(ParseError::ExpectedVr)?.degree)
+                        .ok_or(ParseError::ExpectedVr)?
+                }
+                _ => Specification::BSC,
+            },
+            match ca_shortcut {
+                CaShortcutMode::None => {
+                    Function::from_vr_degree(vr.ok_or(ParseError::ExpectedVr)?.degree)
+                        .ok_or(ParseError::ExpectedVr)?
+                }
+                _ => Function::STA,
+            },
+            match ca_shortcut {
+                CaShortcutMode::None => {
+                    Context::from_vr_sequence(vr.ok_or(ParseError::ExpectedVr)?.sequence)
+                }
+                _ => Context::EXS,
+            },
+            match root {
+                Root::C(cr) => ShortcutCheckedFormativeRoot::Referential(cr.parse()?),
+                Root::N(_) => return Err(ParseError::ExpectedReferentialRoot),
+            },
+        ),
+
+        WordType::Affixual { version, function } => (
+            Stem::S1,
+            version,
+            Shortcut::None(AffixShortcut::None),
+            Specification::BSC,
+            function,
+            match ca_shortcut {
+                CaShortcutMode::None => {
+                    Context::from_vr_sequence(vr.ok_or(ParseError::ExpectedVr)?.sequence)
+                }
+                _ => return Err(ParseError::AffixualFormativeWithCaShortcut),
+            },
+            match root {
+                Root::C(cr) => ShortcutCheckedFormativeRoot::Affixual(AffixualFormativeRoot {
+                    cs: cr.0,
+                    degree: vr.ok_or(ParseError::ExpectedVr)?.degree.into(),
+                }),
+                Root::N(_) => return Err(ParseError::ExpectedReferentialRoot),
+            },
+        ),
+    };
+
+    Ok(CrVr {
+        stem,
+        version,
+        shortcut,
+        specification,
+        function,
+        context,
+        root,
+        does_vv_have_glottal_stop,
+        does_vr_have_glottal_stop,
+    })
+}
+
+/// Parses slots V through VIII of a formative, which are everything between its Vr and Vc/Vk
+/// slots.
+///
+/// This expects Vc/Vk to have been taken off the end of the stream already, and leaves the stream
+/// empty on success.
+pub fn parse_middle_segments(
+    stream: &mut TokenStream,
+    flags: FromTokenFlags,
+    shortcut: Shortcut,
+    does_vv_have_glottal_stop: bool,
+) -> Result<Middle, ParseError> {
+    // We've officially completed the easy part of parsing.
+    //
+    // There are now quite a few options left for how the token stream can be
+    // parsed.
+    //
+    // For formatives with Ca shortcuts, there is only one option:
+    // 1. (VC...')(VC...)(VH)     (slot V affixes, then slot VII affixes, then VnCn)
+    //
+    // For other formatives, there are several choices:
+    // 1. C(VC...)(VH)            (an ungeminated Ca, then slot VII affixes, then VnCn)
+    // 2. (CV...)CC(VC...)(VH)    (slot V affixes, then a geminated Ca, then VnCn)
+    // 3. H(VC...)                (a Cn shortcut, then slot VII affixes)
+    //
+    // Time to get to work!
+
+    let (vncn, does_vn_have_glottal_stop) = match stream.next_back::<HForm>() {
+        Some(cn) => match stream.next_back_any() {
+            Some(Token::V(vn)) => {
+                let is_aspect = matches!(cn.sequence, HFormSequence::SW | HFormSequence::SY);
+
+                let cn = match cn.degree {
+                    HFormDegree::D1 => ArbitraryMoodOrCaseScope::FAC_CCN,
+                    HFormDegree::D2 => ArbitraryMoodOrCaseScope::SUB_CCA,
+                    HFormDegree::D3 => ArbitraryMoodOrCaseScope::ASM_CCS,
+                    HFormDegree::D4 => ArbitraryMoodOrCaseScope::SPC_CCQ,
+                    HFormDegree::D5 => ArbitraryMoodOrCaseScope::COU_CCP,
+                    HFormDegree::D6 => ArbitraryMoodOrCaseScope::HYP_CCV,
+                };
+
+                (
+                    VnCn::VnCn(
+                        Vn::from_vowel_form(*vn, is_aspect).ok_or(ParseError::ExpectedVn)?,
+                        cn,
+                    ),
+                    vn.has_glottal_stop,
+                )
+            }
+            None => (
+                VnCn::JustCn(match cn.sequence {
+                    HFormSequence::S0 => match cn.degree {
+                        HFormDegree::D1 => return Err(ParseError::DefaultCnShortcut),
+                        HFormDegree::D2 => ArbitraryMoodOrCaseScope::SUB_CCA,
+                        HFormDegree::D3 => ArbitraryMoodOrCaseScope::ASM_CCS,
+                        HFormDegree::D4 => ArbitraryMoodOrCaseScope::SPC_CCQ,
+                        HFormDegree::D5 => ArbitraryMoodOrCaseScope::COU_CCP,
+                        HFormDegree::D6 => ArbitraryMoodOrCaseScope::HYP_CCV,
+                    },
+                    _ => return Err(ParseError::AspectualCnShortcut),
+                }),
+                false,
+            ),
+            _ => return Err(ParseError::ExpectedVn),
+        },
+        None => (VnCn::Nothing, false),
+    };
+
+    let mut does_vx_have_glottal_stop = false;
+
+    type UnparsedMiddleSegments<'a> = MiddleSegments<Vec<(VowelForm, &'a ConsonantForm)>>;
+
+    // A VxCs pair has two tokens, so this is an upper bound on how many affixes remain.
+    let max_affix_count = stream.remaining_tokens().len() / 2;
+
+    let middle: UnparsedMiddleSegments = match shortcut {
+        Shortcut::Ca(ca) => {
+            let vncn = match vncn {
+                VnCn::Nothing => None,
+                VnCn::JustCn(_) => return Err(ParseError::ExpectedVn),
+                VnCn::VnCn(vn, cn) => Some((vn, cn)),
+            };
+
+            let mut slot_v_affixes = Vec::new();
+            let mut slot_vii_affixes = Vec::with_capacity(max_affix_count);
+
+            loop {
+                match stream.next_any() {
+                    None => break,
+                    Some(Token::V(vx)) => {
+                        let vx = *vx;
+                        match stream.next_any() {
+                            Some(Token::C(cs)) => {
+                                slot_vii_affixes.push((vx, &**cs));
+
+                                if cs.is_geminate() {
+                                    return Err(ParseError::GeminatedCs);
+                                }
+
+                                if vx.has_glottal_stop {
+                                    if slot_v_affixes.is_empty() {
+                                        slot_v_affixes = slot_vii_affixes;
+                                        slot_vii_affixes = Vec::with_capacity(max_affix_count);
+                                    } else {
+                                        return Err(ParseError::MultipleEndOfSlotVMarkers);
+                                    }
+                                }
+                            }
+                            _ => return Err(ParseError::ExpectedCs),
+                        }
+                    }
+                    _ => return Err(ParseError::ExpectedVx),
+                }
+            }
+
+            MiddleSegments::Ca {
+                ca,
+                slot_v_affixes,
+                slot_vii_affixes,
+                vncn,
+            }
+        }
+
+        Shortcut::None(affix_shortcut) => {
+            if let VnCn::JustCn(cn) = vncn {
+                MiddleSegments::Cn {
+                    cn,
+                    slot_vii_affixes: Vec::new(),
+                    affix_shortcut,
+                }
+            } else {
+                // Quick reminder of possible forms at this point:
+                // 1. C(VC...)            (an ungeminated Ca, then slot VII affixes)
+                // 2. (CV...)CC(VC...)    (slot V affixes, then a geminated Ca)
+                // 3. H(VC...)            (a Cn shortcut, then slot VII affixes)
+
+                if let Some(HForm { sequence, degree }) = stream.next() {
+                    if sequence != HFormSequence::S0 {
+                        return Err(ParseError::AspectualCnShortcut);
+                    }
+
+                    let cn = match degree {
+                        HFormDegree::D1 => return Err(ParseError::DefaultCnShortcut),
+                        HFormDegree::D2 => ArbitraryMoodOrCaseScope::SUB_CCA,
+                        HFormDegree::D3 => ArbitraryMoodOrCaseScope::ASM_CCS,
+                        HFormDegree::D4 => ArbitraryMoodOrCaseScope::SPC_CCQ,
+                        HFormDegree::D5 => ArbitraryMoodOrCaseScope::COU_CCP,
+                        HFormDegree::D6 => ArbitraryMoodOrCaseScope::HYP_CCV,
+                    };
+
+                    let mut slot_vii_affixes = Vec::with_capacity(max_affix_count);
+
+                    loop {
+                        match stream.next_any() {
+                            None => break,
+                            Some(Token::V(vx)) => {
+                                let vx = *vx;
+                                match stream.next_any() {
+                                    Some(Token::C(cs)) => {
+                                        slot_vii_affixes.push((vx, &**cs));
+
+                                        if cs.is_geminate() {
+                                            return Err(ParseError::GeminatedCs);
+                                        }
+
+                                        if vx.has_glottal_stop {
+                                            if does_vx_have_glottal_stop {
+                                                return Err(ParseError::DoublyGlottalizedVx);
+                                            } else {
+                                                does_vx_have_glottal_stop = true;
+                                            }
+                                        }
+                                    }
+                                    _ => return Err(ParseError::ExpectedCs),
+                                }
+                            }
+                            _ => return Err(ParseError::ExpectedVx),
+                        }
+                    }
+
+                    MiddleSegments::Cn {
+                        cn,
+                        slot_vii_affixes,
+                        affix_shortcut,
+                    }
+                } else {
+                    // Forms 1 and 2 both start with alternating C and V forms, so instead of
+                    // scanning ahead for a geminated Ca, we read pairs until we either find
+                    // one (form 2) or run out of tokens (form 1). The checks below are then
+                    // run in the same order they would be if we had known the form upfront,
+                    // so that errors are reported identically either way.
+
+                    enum Stop<'a> {
+                        End,
+                        Geminate(VowelForm, &'a ConsonantForm),
+                        ExpectedVx,
+                        ExpectedCs(VowelForm),
+                    }
+
+                    let middle_tokens = stream.remaining_tokens();
+
+                    let has_geminate = || {
+                        middle_tokens
+                            .iter()
+                            .any(|x| matches!(x, Token::C(cs) if cs.is_geminate()))
+                    };
+
+                    let mut check_glottal_stop = |vx: VowelForm| {
+                        if vx.has_glottal_stop {
+                            if does_vx_have_glottal_stop {
+                                return Err(ParseError::DoublyGlottalizedVx);
+                            } else {
+                                does_vx_have_glottal_stop = true;
+                            }
+                        }
+
+                        Ok(())
+                    };
+
+                    let vncn = match vncn {
+                        VnCn::VnCn(vn, cn) => Some((vn, cn)),
+                        VnCn::JustCn(_) => unreachable!("we caught this already"),
+                        VnCn::Nothing => None,
+                    };
+
+                    let first = match stream.next_any() {
+                        Some(Token::C(cs)) => &**cs,
+                        _ if has_geminate() => return Err(ParseError::ExpectedCs),
+                        _ => return Err(ParseError::ExpectedCa),
+                    };
+
+                    // The VC pairs following the first consonant.
+                    let mut pairs = Vec::with_capacity(max_affix_count);
+
+                    let stop = if first.is_geminate() {
+                        None
+                    } else {
+                        Some(loop {
+                            match stream.next_any() {
+                                None => break Stop::End,
+                                Some(Token::V(vx)) => match stream.next_any() {
+                                    Some(Token::C(cs)) if cs.is_geminate() => {
+                                        break Stop::Geminate(*vx, &**cs)
+                                    }
+                                    Some(Token::C(cs)) => pairs.push((*vx, &**cs)),
+                                    _ => break Stop::ExpectedCs(*vx),
+                                },
+                                _ => break Stop::ExpectedVx,
+                            }
+                        })
+                    };
+
+                    let is_form_2 = match stop {
+                        None | Some(Stop::Geminate(..)) => true,
+                        Some(Stop::End) => false,
+                        Some(Stop::ExpectedVx | Stop::ExpectedCs(_)) => has_geminate(),
+                    };
+
+                    if is_form_2 {
+                        let mut slot_v_affixes = Vec::with_capacity(pairs.len() + 1);
+                        let mut cs = first;
+
+                        for &(vx, next_cs) in &pairs {
+                            check_glottal_stop(vx)?;
+                            slot_v_affixes.push((vx, cs));
+                            cs = next_cs;
+                        }
+
+                        let ca = match stop {
+                            None => first,
+                            Some(Stop::Geminate(vx, ca)) => {
+                                check_glottal_stop(vx)?;
+                                slot_v_affixes.push((vx, cs));
+                                ca
+                            }
+                            Some(Stop::ExpectedVx) => return Err(ParseError::ExpectedVx),
+                            Some(Stop::ExpectedCs(vx)) => {
+                                check_glottal_stop(vx)?;
+                                return Err(ParseError::ExpectedCs);
+                            }
+                            Some(Stop::End) => unreachable!("form 2 contains a geminate"),
+                        };
+
+                        let ca = Ca::from_geminated_string(ca).ok_or(ParseError::ExpectedCa)?;
+
+                        let mut slot_vii_affixes =
+                            Vec::with_capacity(stream.remaining_tokens().len() / 2);
+
+                        loop {
+                            match stream.next_any() {
+                                None => break,
+
+                                Some(Token::V(vx)) => {
+                                    let vx = *vx;
+
+                                    let cs = stream.next_cs().ok_or(ParseError::ExpectedCs)?;
+
+                                    if cs.is_geminate() {
+                                        return Err(ParseError::GeminatedCs);
+                                    }
+
+                                    check_glottal_stop(vx)?;
+
+                                    slot_vii_affixes.push((vx, cs));
+                                }
+
+                                _ => return Err(ParseError::ExpectedVx),
+                            }
+                        }
+
+                        MiddleSegments::Normal {
+                            slot_v_affixes,
+                            ca,
+                            slot_vii_affixes,
+                            vncn,
+                            affix_shortcut,
+                        }
+                    } else {
+                        let ca =
+                            Ca::from_ungeminated_string(first).ok_or(ParseError::ExpectedCa)?;
+
+                        for &(vx, _) in &pairs {
+                            check_glottal_stop(vx)?;
+                        }
+
+                        match stop {
+                            Some(Stop::ExpectedVx) => return Err(ParseError::ExpectedVx),
+                            Some(Stop::ExpectedCs(_)) => return Err(ParseError::ExpectedCs),
+                            _ => {}
+                        }
+
+                        MiddleSegments::Normal {
+                            slot_v_affixes: Vec::new(),
+                            ca,
+                            slot_vii_affixes: pairs,
+                            vncn,
+                            affix_shortcut,
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    // Time to parse affixes.
+
+    let segments = match middle {
+        MiddleSegments::Normal {
+            slot_v_affixes,
+            ca,
+            slot_vii_affixes,
+            vncn,
+            affix_shortcut,
+        } => MiddleSegments::Normal {
+            slot_v_affixes: {
+                let slot_v_affixes = AffixList::from_vxcs_slice(&slot_v_affixes)?;
+
+                if !flags.matches(FromTokenFlags::PERMISSIVE) {
+                    if does_vv_have_glottal_stop {
+                        if slot_v_affixes.len() <= 1 {
+                            return Err(ParseError::TooFewSlotVAffixes);
+                        }
+                    } else {
+                        if slot_v_affixes.len() > 1 {
+                            return Err(ParseError::TooManySlotVAffixes);
+                        }
+                    }
+                }
+
+                slot_v_affixes
+            },
+            ca,
+            slot_vii_affixes: AffixList::from_vxcs_slice(&slot_vii_affixes)?,
+            vncn,
+            affix_shortcut,
+        },
+
+        MiddleSegments::Ca {
+            ca,
+            slot_v_affixes,
+            slot_vii_affixes,
+            vncn,
+        } => MiddleSegments::Ca {
+            ca,
+            slot_v_affixes: {
+                let slot_v_affixes = AffixList::from_vxcs_slice(&slot_v_affixes)?;
+
+                if !flags.matches(FromTokenFlags::PERMISSIVE) {
+                    if does_vv_have_glottal_stop {
+                        if slot_v_affixes.len() <= 1 {
+                            return Err(ParseError::TooFewSlotVAffixes);
+                        }
+                    } else {
+                        if slot_v_affixes.len() > 1 {
+                            return Err(ParseError::TooManySlotVAffixes);
+                        }
+                    }
+                }
+
+                slot_v_affixes
+            },
+            slot_vii_affixes: AffixList::from_vxcs_slice(&slot_vii_affixes)?,
+            vncn,
+        },
+
+        MiddleSegments::Cn {
+            cn,
+            slot_vii_affixes,
+            affix_shortcut,
+        } => MiddleSegments::Cn {
+            cn,
+            slot_vii_affixes: AffixList::from_vxcs_slice(&slot_vii_affixes)?,
+            affix_shortcut,
+        },
+    };
+
+    Ok(Middle {
+        segments,
+        does_vx_have_glottal_stop,
+        does_vn_have_glottal_stop,
+    })
+}
+
+/// Parses a formative which ends right after its Vr slot, as allowed by
+/// [`FromTokenFlags::ALLOW_BARE_ROOT`].
+///
+/// Unlike the other stages, this expects the entire word to still be in the stream, since the
+/// final vowel of a bare root is its Vr rather than its Vc/Vk.
+pub fn parse_bare_root(
+    stream: &mut TokenStream,
+    flags: FromTokenFlags,
+) -> Result<ShortcutCheckedFormative, ParseError> {
+    let mut cc_vv = parse_cc_vv(stream, flags)?;
+
+    // Monosyllabic words get ultimate stress without it being marked, so a bare root like `ma`
+    // shouldn't be read as verbal.
+    if cc_vv.relation_type == RelationType::Verbal && stream.stress() == Some(Stress::Monosyllabic)
+    {
+        cc_vv.relation_type = RelationType::Nominal;
+    }
+
+    let relation_type = cc_vv.relation_type;
+    let cr_vr = parse_root(stream, cc_vv)?;
+
+    if !stream.is_done() {
+        return Err(ParseError::TooManyTokens);
+    }
+
+    let segments = match cr_vr.shortcut {
+        Shortcut::None(affix_shortcut) => MiddleSegments::Normal {
+            slot_v_affixes: AffixList::default(),
+            ca: Ca::default(),
+            slot_vii_affixes: AffixList::default(),
+            vncn: None,
+            affix_shortcut,
+        },
+        Shortcut::Ca(ca) => MiddleSegments::Ca {
+            ca,
+            slot_v_affixes: AffixList::default(),
+            slot_vii_affixes: AffixList::default(),
+            vncn: None,
+        },
+    };
+
+    let middle = Middle {
+        segments,
+        does_vx_have_glottal_stop: false,
+        does_vn_have_glottal_stop: false,
+    };
+
+    // A bare root has no Vc/Vk at all, so we explicitly give it the default THM case (or OBS
+    // validation, for verbal formatives).
+    assemble(Some(VowelForm::default()), relation_type, cr_vr, middle)
+}
+
+/// Puts together a formative from its already-parsed pieces, merging the glottal stops collected
+/// along the way into its Vc/Vk slot.
+pub fn assemble(
+    vc_or_vk: Option<VowelForm>,
+    relation_type: RelationType,
+    cr_vr: CrVr,
+    middle: Middle,
+) -> Result<ShortcutCheckedFormative, ParseError> {
+    let CrVr {
+        stem,
+        version,
+        shortcut: _,
+        specification,
+        function,
+        context,
+        root,
+        does_vv_have_glottal_stop: _,
+        does_vr_have_glottal_stop,
+    } = cr_vr;
+
+    let Middle {
+        segments,
+        does_vx_have_glottal_stop,
+        does_vn_have_glottal_stop,
+    } = middle;
+
+    // We're free! Now we just have to put everything together
+
+    let (additions, slot_vii_affixes) = match segments {
+        MiddleSegments::Normal {
+            slot_v_affixes,
+            ca,
+            slot_vii_affixes,
+            vncn,
+            affix_shortcut,
+        } => (
+            ShortcutCheckedFormativeAdditions::Normal(NormalNonShortcutAdditions {
+                relation: match relation_type {
+                    RelationType::Verbal => Relation::Verbal {
+                        mood: match vncn {
+                            Some(vncn) => vncn.1.as_specific(),
+                            _ => Mood::FAC,
+                        },
+                        ivl: {
+                            let mut vk = vc_or_vk.unwrap_or_default();
+                            vk.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                            vk.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                            vk.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                            IllocutionOrValidation::from_vk(vk)?
+                        },
+                    },
+                    _ => Relation::Nominal {
+                        mode: match relation_type {
+                            RelationType::Nominal => NominalMode::NOM,
+                            RelationType::T1(_) => NominalMode::T1,
+                            RelationType::T2(_) => NominalMode::T2,
+                            RelationType::Framed => NominalMode::FRM,
+                            RelationType::Verbal => unreachable!(),
+                        },
+                        case_scope: vncn.map(|x| x.1.as_specific()).unwrap_or_default(),
+                        case: match relation_type {
+                            RelationType::Nominal | RelationType::Framed => {
+                                let mut vc = vc_or_vk.unwrap_or_default();
+                                vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                                Case::from_vc(vc)?
+                            }
+                            RelationType::T1(is_high) | RelationType::T2(is_high) => {
+                                let mut vc = vc_or_vk.unwrap_or_default();
+                                vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                                if vc.has_glottal_stop {
+                                    return Err(ParseError::GlottalizedVc);
+                                }
+                                vc.has_glottal_stop = is_high;
+                                Case::from_vc(vc)?
+                            }
+                            RelationType::Verbal => unreachable!(),
+                        },
+                    },
+                },
+                affix_shortcut,
+                function,
+                specification: specification,
+                context,
+                slot_v_affixes,
+                ca,
+                vn: match vncn {
+                    Some(vncn) => vncn.0,
+                    _ => Vn::Valence(Valence::MNO),
+                },
+            }),
+            slot_vii_affixes,
+        ),
+
+        MiddleSegments::Cn {
+            cn,
+            slot_vii_affixes,
+            affix_shortcut,
+        } => (
+            ShortcutCheckedFormativeAdditions::CnShortcut(NormalCnShortcutAdditions {
+                relation: match relation_type {
+                    RelationType::Verbal => Relation::Verbal {
+                        mood: cn
+                            .try_as_specific()
+                            .ok_or(ParseError::ExpectedNonDefaultCn)?,
+                        ivl: {
+                            let mut vk = vc_or_vk.unwrap_or_default();
+                            vk.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                            vk.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                            vk.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                            IllocutionOrValidation::from_vk(vk)?
+                        },
+                    },
+                    _ => Relation::Nominal {
+                        mode: match relation_type {
+                            RelationType::Nominal => NominalMode::NOM,
+                            RelationType::T1(_) => NominalMode::T1,
+                            RelationType::T2(_) => NominalMode::T2,
+                            RelationType::Framed => NominalMode::FRM,
+                            RelationType::Verbal => unreachable!(),
+                        },
+                        case_scope: cn
+                            .try_as_specific()
+                            .ok_or(ParseError::ExpectedNonDefaultCn)?,
+                        case: match relation_type {
+                            RelationType::Nominal | RelationType::Framed => {
+                                let mut vc = vc_or_vk.unwrap_or_default();
+                                vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                                Case::from_vc(vc)?
+                            }
+                            RelationType::T1(is_high) | RelationType::T2(is_high) => {
+                                let mut vc = vc_or_vk.unwrap_or_default();
+                                vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                                if vc.has_glottal_stop {
+                                    return Err(ParseError::GlottalizedVc);
+                                }
+                                vc.has_glottal_stop = is_high;
+                                Case::from_vc(vc)?
+                            }
+                            RelationType::Verbal => unreachable!(),
+                        },
+                    },
+                },
+                affix_shortcut,
+                function,
+                specification,
+                context,
+            }),
+            slot_vii_affixes,
+        ),
+
+        MiddleSegments::Ca {
+            ca,
+            slot_v_affixes,
+            slot_vii_affixes,
+            vncn,
+        } => (
+            ShortcutCheckedFormativeAdditions::CaShortcut(NormalCaShortcutAdditions {
+                relation: match relation_type {
+                    RelationType::Verbal => Relation::Verbal {
+                        mood: match vncn {
+                            Some(vncn) => vncn.1.as_specific(),
+                            _ => Mood::FAC,
+                        },
+                        ivl: {
+                            let mut vk = vc_or_vk.unwrap_or_default();
+                            vk.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                            vk.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                            vk.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                            IllocutionOrValidation::from_vk(vk)?
+                        },
+                    },
+                    _ => Relation::Nominal {
+                        mode: match relation_type {
+                            RelationType::Nominal => NominalMode::NOM,
+                            RelationType::T1(_) => NominalMode::T1,
+                            RelationType::T2(_) => NominalMode::T2,
+                            RelationType::Framed => NominalMode::FRM,
+                            RelationType::Verbal => unreachable!(),
+                        },
+                        case_scope: vncn.map(|x| x.1.as_specific()).unwrap_or_default(),
+                        case: match relation_type {
+                            RelationType::Nominal | RelationType::Framed => {
+                                let mut vc = vc_or_vk.unwrap_or_default();
+                                vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                                Case::from_vc(vc)?
+                            }
+                            RelationType::T1(is_high) | RelationType::T2(is_high) => {
+                                let mut vc = vc_or_vk.unwrap_or_default();
+                                vc.merge_vcvk_glottal_stop(does_vr_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vx_have_glottal_stop)?;
+                                vc.merge_vcvk_glottal_stop(does_vn_have_glottal_stop)?;
+                                if vc.has_glottal_stop {
+                                    return Err(ParseError::GlottalizedVc);
+                                }
+                                vc.has_glottal_stop = is_high;
+                                Case::from_vc(vc)?
+                            }
+                            RelationType::Verbal => unreachable!(),
+                        },
+                    },
+                },
+                slot_v_affixes,
+                ca,
+                vn: vncn.map(|x| x.0).unwrap_or_default(),
+            }),
+            slot_vii_affixes,
+        ),
+    };
+
+    Ok(ShortcutCheckedFormative(
+        ShortcutCheckedFormativeCore {
+            root,
+            slot_vii_affixes,
+            stem,
+            version,
+        },
+        additions,
+    ))
+}
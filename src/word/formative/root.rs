@@ -4,7 +4,7 @@ use std::str::FromStr;
 
 use crate::{
     category::{AffixDegree, PerspectivelessReferentList},
-    gloss::{Gloss, GlossFlags, GlossStatic},
+    gloss::{escape_if_requested, Gloss, GlossFlags, GlossStatic},
     prelude::token::NumeralForm,
     romanize::{stream::ParseError, token::OwnedConsonantForm},
     specificity::{AsGeneral, TryAsSpecific},
@@ -31,11 +31,12 @@ impl Gloss for NormalFormativeRoot {
     fn gloss(&self, flags: GlossFlags) -> String {
         if flags.matches(GlossFlags::FORMAT_MARKDOWN) {
             let mut output = "**".to_owned();
-            output += &self.cr; // We assume the Cr form doesn't need Markdown escaping.
+            // We assume the Cr form doesn't need Markdown escaping.
+            output += &escape_if_requested(&self.cr, flags);
             output += "**";
             output
         } else {
-            self.cr.0.to_owned()
+            escape_if_requested(&self.cr, flags).into_owned()
         }
     }
 }
@@ -117,14 +118,15 @@ impl Gloss for AffixualFormativeRoot {
     fn gloss(&self, flags: GlossFlags) -> String {
         if flags.matches(GlossFlags::FORMAT_MARKDOWN) {
             let mut output = "**".to_owned();
-            output += &self.cs; // We assume the Cs form doesn't need Markdown escaping.
+            // We assume the Cs form doesn't need Markdown escaping.
+            output += &escape_if_requested(&self.cs, flags);
             output += "**/";
             output += self.degree.gloss_static(GlossFlags::NONE);
             output += "-D";
             output += self.degree.gloss_static(GlossFlags::NONE);
             output
         } else {
-            let mut output = self.cs.clone();
+            let mut output = escape_if_requested(&self.cs, flags).into_owned();
             output += "/";
             output += self.degree.gloss_static(GlossFlags::NONE);
             output += "-D";
@@ -157,6 +159,28 @@ impl ShortcutCheckedFormativeRoot {
             cr: OwnedConsonantForm(source.into()),
         })
     }
+
+    /// Creates a new [`ShortcutCheckedFormativeRoot::Numeric`] from the integer it represents.
+    pub fn new_numeric(integer_part: u64) -> ShortcutCheckedFormativeRoot {
+        ShortcutCheckedFormativeRoot::Numeric(NumericFormativeRoot {
+            form: NumeralForm { integer_part },
+        })
+    }
+
+    /// Creates a new [`ShortcutCheckedFormativeRoot::Referential`] by parsing a Cr form as a list
+    /// of referents, exactly as the formative parser does. Fails if `cr` is not a valid list of
+    /// referents.
+    pub fn new_referential(cr: &str) -> Result<ShortcutCheckedFormativeRoot, ParseError> {
+        Ok(ShortcutCheckedFormativeRoot::Referential(cr.parse()?))
+    }
+
+    /// Creates a new [`ShortcutCheckedFormativeRoot::Affixual`] from a Cs form and affix degree.
+    pub fn new_affixual(
+        cs: impl Into<String>,
+        degree: AffixDegree,
+    ) -> ShortcutCheckedFormativeRoot {
+        ShortcutCheckedFormativeRoot::Affixual(AffixualFormativeRoot::new(cs, degree))
+    }
 }
 
 impl Default for ShortcutCheckedFormativeRoot {
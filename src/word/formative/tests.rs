@@ -1,13 +1,13 @@
 use crate::{
-    affix::{AffixList, PlainAffix, RegularAffix},
+    affix::{AffixList, NumericAffix, PlainAffix, RegularAffix},
     ca,
     category::{
         AffixDegree, AffixShortcut, AffixType, Case, CaseScope, Context, Function,
-        IllocutionOrValidation, Mood, NominalMode, NormalCaShortcut, Phase, Specification, Stem,
-        Valence, Version, Vn,
+        IllocutionOrValidation, Mood, NominalMode, NormalCaShortcut, Phase, ShortcutType,
+        Specification, Stem, Valence, Version, Vn,
     },
-    gloss::{Gloss, GlossFlags},
-    prelude::{IntoTokens, IntoTokensFlags},
+    gloss::{unescape_gloss_text, Gloss, GlossFlags, GlossToken},
+    prelude::{token::NumeralForm, IntoTokens, IntoTokensFlags},
     relation,
     romanize::{stream::ParseError, transform::normalize},
     word::{
@@ -20,7 +20,7 @@ use crate::{
             relation::NormalRelation,
             root::{NormalFormativeRoot, ShortcutCheckedFormativeRoot},
         },
-        CheckedFormative, ShortcutCheckedFormative, UncheckedFormative,
+        CheckedFormative, Extended, ShortcutCheckedFormative, UncheckedFormative,
     },
 };
 
@@ -77,6 +77,14 @@ fn glosses() {
         gloss_with_defaults,
         "S3.CPT-rr-STA.CTE.RPS-CSL.UPX.DEL.M.NRM-FRE.CCN-ALL\\UNF"
     );
+
+    let gloss_without_suffix =
+        formative.gloss(GlossFlags::SHOW_DEFAULTS | GlossFlags::NO_RELATION_SUFFIX);
+
+    assert_eq!(
+        gloss_without_suffix,
+        "S3.CPT-rr-STA.CTE.RPS-CSL.UPX.DEL.M.NRM-FRE.CCN-ALL"
+    );
 }
 
 #[test]
@@ -176,3 +184,1454 @@ fn parse_and_gloss() -> Result<(), ParseError> {
 
     Ok(())
 }
+
+#[test]
+fn gloss_affix_cs() -> Result<(), ParseError> {
+    let formative: UncheckedFormative = "ašflaleče".parse()?;
+    assert_eq!(formative.gloss(GlossFlags::NONE), "S1-šfl-č/3₁-ABS");
+    assert_eq!(
+        formative.gloss(GlossFlags::SHOW_AFFIX_CS),
+        "S1-šfl-č/3₁[č]-ABS"
+    );
+
+    let formative: UncheckedFormative = "second".parse()?;
+    assert_eq!(
+        formative.gloss(GlossFlags::SHOW_AFFIX_CS),
+        "S1-s-CSV-DSS-nd/7₁[nd]"
+    );
+
+    let affix = NumericAffix {
+        form: NumeralForm { integer_part: 27 },
+        r#type: AffixType::T1,
+        degree: AffixDegree::D3,
+    };
+    assert_eq!(affix.gloss(GlossFlags::SHOW_AFFIX_CS), "‘27’/3₁");
+
+    Ok(())
+}
+
+#[test]
+fn extended() -> Result<(), ParseError> {
+    let base: UncheckedFormative = "las".parse()?;
+
+    let extended = Extended::new(base.clone());
+    assert!(extended.slot_xi_affixes.is_empty());
+    assert_eq!(
+        extended.gloss(GlossFlags::NONE),
+        base.gloss(GlossFlags::NONE)
+    );
+
+    let affix = RegularAffix::Plain(PlainAffix::new("c", AffixType::T1, AffixDegree::D4));
+
+    let mut extended = extended.with_slot_xi_affixes(vec![affix.clone()]);
+    assert_eq!(extended.gloss(GlossFlags::NONE), "S1-l-DPX-OBS-c/4₁");
+
+    extended.push_slot_xi_affix(affix.clone());
+    assert_eq!(extended.slot_xi_affixes, vec![affix.clone(), affix]);
+    assert_eq!(extended.into_base(), base);
+
+    Ok(())
+}
+
+#[test]
+fn extended_round_trip() -> Result<(), ParseError> {
+    let extended: Extended<UncheckedFormative> = "las".parse()?;
+    assert!(extended.slot_xi_adjunct().is_none());
+    assert_eq!(extended.to_string(), "las");
+
+    let c = RegularAffix::Plain(PlainAffix::new("c", AffixType::T1, AffixDegree::D4));
+    let r = RegularAffix::Plain(PlainAffix::new("r", AffixType::T2, AffixDegree::D1));
+
+    let extended = extended.with_slot_xi_affixes(vec![c.clone()]);
+    let text = extended.to_string();
+    assert_eq!(text, "ico las");
+    assert_eq!(text.parse::<Extended<UncheckedFormative>>()?, extended);
+
+    let extended = extended.with_slot_xi_affixes(vec![c.clone(), r, c]);
+    let text = extended.to_string();
+    assert_eq!(text, "cihwairic las");
+    assert_eq!(text.parse::<Extended<UncheckedFormative>>()?, extended);
+
+    let checked: Extended<CheckedFormative> = text.parse()?;
+    assert_eq!(checked.to_string(), text);
+
+    let shortcut: Extended<ShortcutCheckedFormative> = text.parse()?;
+    assert_eq!(shortcut.to_string(), text);
+
+    assert_eq!(
+        "".parse::<Extended<UncheckedFormative>>(),
+        Err(ParseError::WordEmpty)
+    );
+    assert_eq!(
+        "a a las".parse::<Extended<UncheckedFormative>>(),
+        Err(ParseError::TooManyWords)
+    );
+    assert_eq!(
+        "ic las".parse::<Extended<UncheckedFormative>>(),
+        Err(ParseError::ExpectedSlotXiAdjunct)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn diff() -> Result<(), ParseError> {
+    use super::diff::{FieldChange, FieldDiff, FormativeField};
+
+    let old: UncheckedFormative = "second".parse()?;
+    assert!(old.diff(&old).is_empty());
+
+    let mut new = old.clone();
+    new.stem = Stem::S2;
+    new.ca = ca!(DPX);
+
+    let AffixList::Normal(affixes) = &mut new.slot_vii_affixes else {
+        panic!("\"second\" should have normal slot VII affixes");
+    };
+
+    let RegularAffix::Plain(affix) = &mut affixes[0] else {
+        panic!("\"second\" should have a plain affix");
+    };
+
+    affix.degree = AffixDegree::D2;
+    affixes.insert(
+        0,
+        RegularAffix::Plain(PlainAffix::new("c", AffixType::T1, AffixDegree::D4)),
+    );
+
+    assert_eq!(
+        old.diff(&new),
+        vec![
+            FieldDiff {
+                field: FormativeField::Stem,
+                change: FieldChange::Changed {
+                    old: "S1".to_owned(),
+                    new: "S2".to_owned(),
+                },
+            },
+            FieldDiff {
+                field: FormativeField::Ca,
+                change: FieldChange::Changed {
+                    old: "CSL.DSS.DEL.M.NRM".to_owned(),
+                    new: "CSL.DPX.DEL.M.NRM".to_owned(),
+                },
+            },
+            FieldDiff {
+                field: FormativeField::SlotVIIAffixes,
+                change: FieldChange::AffixAdded {
+                    index: 0,
+                    affix: "c/4₁".to_owned(),
+                },
+            },
+            FieldDiff {
+                field: FormativeField::SlotVIIAffixes,
+                change: FieldChange::AffixDegreeChanged {
+                    old_index: 0,
+                    new_index: 1,
+                    cs: "nd".to_owned(),
+                    old: AffixDegree::D7,
+                    new: AffixDegree::D2,
+                },
+            },
+        ],
+    );
+
+    Ok(())
+}
+
+#[test]
+fn to_romanized() -> Result<(), ParseError> {
+    for (source, romanized) in [
+        ("hliosulţe", "hliosulţe"),
+        ("mala", "mala"),
+        ("malá", "mal"),
+        ("málala", "málala"),
+        ("malalá", "malál"),
+        ("ırburučpaızya", "irburučpaizya"),
+    ] {
+        let formative: UncheckedFormative = source.parse()?;
+        assert_eq!(formative.to_romanized(), romanized, "source was {source:?}");
+
+        let formative: ShortcutCheckedFormative = source.parse()?;
+        assert_eq!(formative.to_romanized(), romanized, "source was {source:?}");
+
+        let formative: CheckedFormative = source.parse()?;
+        assert_eq!(formative.to_romanized(), romanized, "source was {source:?}");
+        assert_eq!(romanized.parse::<CheckedFormative>()?, formative);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_prefix() -> Result<(), ParseError> {
+    use crate::romanize::{flags::FromTokenFlags, token::Token, token_list::TokenList};
+
+    let mut list: TokenList = "mala".parse()?;
+    list.tokens.push(Token::ÜA);
+
+    let mut stream = list.stream();
+    let (formative, len) = UncheckedFormative::parse_prefix(&mut stream, FromTokenFlags::NONE)?;
+    assert_eq!(formative, "mala".parse()?);
+    assert_eq!(len, 4);
+    assert_eq!(stream.remaining(), 1);
+    assert_eq!(stream.next_any(), Some(&Token::ÜA));
+
+    let mut stream = list.stream();
+    stream.truncate(4);
+    assert_eq!(
+        stream.parse_entire::<UncheckedFormative>(FromTokenFlags::NONE),
+        Ok(formative)
+    );
+
+    let list: TokenList = "üa".parse()?;
+    let mut stream = list.stream();
+    assert!(UncheckedFormative::parse_prefix(&mut stream, FromTokenFlags::NONE).is_err());
+    assert_eq!(stream.remaining(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn semantic_eq() -> Result<(), ParseError> {
+    let base: UncheckedFormative = "las".parse()?;
+
+    let mut other = base.clone();
+    other.shortcut = ShortcutType::Ca;
+    assert_ne!(base, other);
+    assert!(base.semantic_eq(&other));
+
+    let mut other = base.clone();
+    other.vc = Case::ERG;
+    assert!(!base.semantic_eq(&other));
+
+    let mut other = base.clone();
+    other.stem = Stem::S2;
+    assert!(!base.semantic_eq(&other));
+
+    let affixual: UncheckedFormative = "oëtil".parse()?;
+
+    let mut other = affixual.clone();
+    other.stem = Stem::S2;
+    other.specification = Specification::OBJ;
+    assert_ne!(affixual, other);
+    assert!(affixual.semantic_eq(&other));
+    assert_eq!(other.to_romanized(), affixual.to_romanized());
+
+    let forward: UncheckedFormative = "lalarlat".parse()?;
+    let mut backward = forward.clone();
+    if let AffixList::Normal(affixes) = &mut backward.slot_vii_affixes {
+        affixes.reverse();
+    }
+    assert_ne!(forward.slot_vii_affixes, backward.slot_vii_affixes);
+    assert!(!forward.semantic_eq(&backward));
+
+    Ok(())
+}
+
+#[test]
+fn stress() -> Result<(), ParseError> {
+    use crate::{category::Stress, romanize::token_list::TokenList};
+
+    for (source, stress) in [
+        ("mala", Stress::Penultimate),
+        ("malá", Stress::Ultimate),
+        ("mal", Stress::Ultimate),
+        ("málala", Stress::Antepenultimate),
+        ("hlamala", Stress::Penultimate),
+        ("hlamalá", Stress::Ultimate),
+    ] {
+        let formative: UncheckedFormative = source.parse()?;
+        assert_eq!(formative.stress(), stress, "source was {source:?}");
+
+        let list: TokenList = formative.to_romanized().parse()?;
+        match list.stress {
+            Some(Stress::Monosyllabic) => assert_eq!(stress, Stress::Ultimate),
+            written => assert_eq!(
+                written.unwrap_or(Stress::Penultimate),
+                stress,
+                "source was {source:?}"
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn mood_and_case_scope_glosses() -> Result<(), ParseError> {
+    use crate::{
+        category::{ArbitraryMoodOrCaseScope, DatalessRelation, ShortcutType},
+        specificity::AsSpecific,
+    };
+
+    let base: UncheckedFormative = "mala".parse()?;
+
+    let segments = |formative: &UncheckedFormative, flags| -> Vec<String> {
+        formative
+            .gloss(flags)
+            .split(['-', '.', '\\'])
+            .map(str::to_owned)
+            .collect()
+    };
+
+    for cn in ArbitraryMoodOrCaseScope::ALL_ITEMS {
+        let mood = AsSpecific::<Mood>::as_specific(cn).gloss(GlossFlags::NONE);
+        let case_scope = AsSpecific::<CaseScope>::as_specific(cn).gloss(GlossFlags::NONE);
+
+        let shortcuts: &[ShortcutType] = if cn == ArbitraryMoodOrCaseScope::FAC_CCN {
+            &[ShortcutType::Normal]
+        } else {
+            &[ShortcutType::Normal, ShortcutType::Cn]
+        };
+
+        for &shortcut in shortcuts {
+            for flags in [GlossFlags::NONE, GlossFlags::SHOW_DEFAULTS] {
+                let is_shown = cn != ArbitraryMoodOrCaseScope::FAC_CCN
+                    || flags.matches(GlossFlags::SHOW_DEFAULTS);
+
+                let verbal = UncheckedFormative {
+                    relation: DatalessRelation::VRB,
+                    shortcut,
+                    cn,
+                    ..base.clone()
+                };
+                let glossed = segments(&verbal, flags);
+                assert_eq!(glossed.contains(&mood), is_shown, "{glossed:?}");
+                assert!(!glossed.contains(&case_scope), "{glossed:?}");
+
+                let nominal = UncheckedFormative {
+                    relation: DatalessRelation::NOM,
+                    shortcut,
+                    cn,
+                    ..base.clone()
+                };
+                let glossed = segments(&nominal, flags);
+                assert_eq!(glossed.contains(&case_scope), is_shown, "{glossed:?}");
+                assert!(!glossed.contains(&mood), "{glossed:?}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn optimal_shortcut() -> Result<(), ParseError> {
+    #[track_caller]
+    fn check(source: &str, shortcut: ShortcutType, normalized: &str) -> Result<(), ParseError> {
+        let original: UncheckedFormative = source.parse()?;
+        assert_eq!(original.optimal_shortcut(), shortcut, "{source}");
+
+        let mut formative = original.clone();
+        formative.normalize_shortcut();
+        assert_eq!(formative.shortcut, shortcut, "{source}");
+        assert_eq!(formative.to_romanized(), normalized, "{source}");
+        assert!(formative.semantic_eq(&original), "{source}");
+
+        let reparsed: UncheckedFormative = normalized.parse()?;
+        assert!(reparsed.semantic_eq(&original), "{source}");
+
+        Ok(())
+    }
+
+    check("mala", ShortcutType::Ca, "wama")?;
+    check("mada", ShortcutType::Ca, "yama")?;
+    check("malahwá", ShortcutType::Ca, "wamahwá")?;
+    check("malahla", ShortcutType::Cn, "mahla")?;
+
+    // G perspective has a Ca shortcut, but it is longer than the plain form.
+    check("mara", ShortcutType::Normal, "mara")?;
+
+    // A non-default function cannot be written with a Ca shortcut.
+    check("mäla", ShortcutType::Normal, "mäla")?;
+
+    Ok(())
+}
+
+#[test]
+fn glottal_stop_round_trip() -> Result<(), ParseError> {
+    use crate::{
+        category::{ArbitraryMoodOrCaseScope, DatalessRelation},
+        specificity::{AsGeneral, TryAsSpecific},
+    };
+
+    let affix = |cs: &str, degree| RegularAffix::Plain(PlainAffix::new(cs, AffixType::T1, degree));
+
+    let base: UncheckedFormative = "mala".parse()?;
+
+    let relations = [
+        DatalessRelation::NOM,
+        DatalessRelation::VRB,
+        DatalessRelation::FRM,
+        DatalessRelation::T1,
+        DatalessRelation::T2,
+    ];
+
+    // THM, ERG, and PAR have no glottal stop; the rest do.
+    let cases = [0, 6, 17, 36, 44, 53, 67].map(|index| Case::ALL_ITEMS[index]);
+
+    let shortcuts = [
+        (ShortcutType::Normal, ca!()),
+        (ShortcutType::Ca, ca!()),
+        (ShortcutType::Ca, ca!(PRX)),
+        (ShortcutType::Cn, ca!()),
+    ];
+
+    let affix_lists = [
+        vec![],
+        vec![affix("c", AffixDegree::D1)],
+        vec![affix("c", AffixDegree::D1), affix("lk", AffixDegree::D0)],
+    ];
+
+    let vncns = [
+        (Vn::Valence(Valence::MNO), ArbitraryMoodOrCaseScope::FAC_CCN),
+        (Vn::Valence(Valence::MNO), ArbitraryMoodOrCaseScope::SUB_CCA),
+        (Vn::Phase(Phase::REP), ArbitraryMoodOrCaseScope::FAC_CCN),
+    ];
+
+    for relation in relations {
+        for vc in cases {
+            for (shortcut, ca) in shortcuts {
+                for slot_v_affixes in &affix_lists {
+                    for slot_vii_affixes in &affix_lists {
+                        for (vn, cn) in vncns {
+                            let formative = UncheckedFormative {
+                                relation,
+                                shortcut,
+                                slot_v_affixes: AffixList::Normal(slot_v_affixes.clone()),
+                                ca,
+                                slot_vii_affixes: AffixList::Normal(slot_vii_affixes.clone()),
+                                vn,
+                                cn,
+                                vc,
+                                ..base.clone()
+                            };
+
+                            // Skip combinations which a `CheckedFormative` can't represent.
+                            let Some(checked): Option<CheckedFormative> =
+                                formative.clone().try_as_specific()
+                            else {
+                                continue;
+                            };
+
+                            if AsGeneral::<UncheckedFormative>::as_general(checked.clone())
+                                != formative
+                            {
+                                continue;
+                            }
+
+                            for flags in
+                                [IntoTokensFlags::NONE, IntoTokensFlags::MOVE_VC_GLOTTAL_STOP]
+                            {
+                                let source = checked.to_string_with(flags);
+                                let parsed: CheckedFormative = source.parse()?;
+                                assert_eq!(parsed, checked, "source was {source:?}");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn vn_affixes() -> Result<(), ParseError> {
+    use crate::{
+        category::Aspect,
+        prelude::IntoScriptFlags,
+        script::{repr::IthkuilBasicEncoding, traits::ScriptRepr},
+    };
+
+    let affix = |cs: &str, degree| RegularAffix::Plain(PlainAffix::new(cs, AffixType::T1, degree));
+
+    let mut formative: UncheckedFormative = "mala".parse()?;
+    assert!(!formative.vn_to_affix());
+    assert!(!formative.affix_to_vn());
+
+    formative.slot_vii_affixes = AffixList::Normal(vec![affix("c", AffixDegree::D1)]);
+    formative.vn = Vn::Phase(Phase::REP);
+    let original = formative.clone();
+
+    assert!(formative.vn_to_affix());
+    assert_eq!(formative.vn, Vn::Valence(Valence::MNO));
+    assert_eq!(
+        formative.slot_vii_affixes,
+        AffixList::Normal(vec![
+            affix("c", AffixDegree::D1),
+            affix(Phase::CS_FORM, AffixDegree::D3),
+        ]),
+    );
+
+    // Script writes both forms with the same tertiary character.
+    assert_eq!(
+        IthkuilBasicEncoding::encode(&formative, IntoScriptFlags::NONE),
+        IthkuilBasicEncoding::encode(&original, IntoScriptFlags::NONE),
+    );
+
+    assert!(formative.affix_to_vn());
+    assert_eq!(formative, original);
+
+    let mut formative = UncheckedFormative {
+        shortcut: ShortcutType::Cn,
+        slot_vii_affixes: AffixList::Normal(vec![
+            affix(Aspect::CS_FORM_2, AffixDegree::D2),
+            affix("c", AffixDegree::D1),
+            affix(Aspect::CS_FORM_1, AffixDegree::D0),
+        ]),
+        ..original.clone()
+    };
+    formative.vn = Vn::default();
+
+    assert!(formative.affix_to_vn());
+    assert_eq!(formative.vn, Vn::Aspect(Aspect::CSS));
+    assert_eq!(formative.shortcut, ShortcutType::Normal);
+    assert_eq!(
+        formative.slot_vii_affixes,
+        AffixList::Normal(vec![
+            affix("c", AffixDegree::D1),
+            affix(Aspect::CS_FORM_1, AffixDegree::D0),
+        ]),
+    );
+    assert!(!formative.affix_to_vn());
+
+    Ok(())
+}
+
+#[test]
+fn absolute_level() -> Result<(), ParseError> {
+    use crate::{
+        category::Level,
+        prelude::IntoScriptFlags,
+        script::{repr::IthkuilBasicEncoding, traits::ScriptRepr},
+    };
+
+    let mut formative: UncheckedFormative = "malarl".parse()?;
+    formative.vn = Vn::AbsoluteLevel(Level::SUR);
+    assert_eq!(formative.gloss(GlossFlags::NONE), "S1-m-rl/1₁-SURₐ");
+    assert_eq!(
+        formative.gloss(GlossFlags::LONG),
+        "stem_one-m-rl/1₁-absolute surpassive"
+    );
+
+    let mut with_affix = formative.clone();
+    assert!(with_affix.vn_to_affix());
+    assert_eq!(
+        with_affix.slot_vii_affixes,
+        AffixList::Normal(vec![
+            RegularAffix::Plain(PlainAffix::new("rl", AffixType::T1, AffixDegree::D1)),
+            RegularAffix::Plain(PlainAffix::new(
+                Level::CS_FORM,
+                AffixType::T2,
+                AffixDegree::D6
+            )),
+        ]),
+    );
+
+    // Absolute levels are romanized and written as their affixes.
+    let source = formative.to_romanized();
+    assert_eq!(source, with_affix.to_romanized());
+    assert_eq!(
+        IthkuilBasicEncoding::encode(&formative, IntoScriptFlags::NONE),
+        IthkuilBasicEncoding::encode(&with_affix, IntoScriptFlags::NONE),
+    );
+
+    let mut parsed: UncheckedFormative = source.parse()?;
+    assert_eq!(parsed, with_affix);
+    assert!(parsed.affix_to_vn());
+    assert_eq!(parsed, formative);
+
+    // Relative levels still use Vn.
+    formative.vn = Vn::Level(Level::SUR);
+    assert_ne!(formative.to_romanized(), source);
+    assert_eq!(
+        formative.to_romanized().parse::<UncheckedFormative>()?,
+        formative
+    );
+
+    Ok(())
+}
+
+#[test]
+fn verbal_formative_without_vk() -> Result<(), ParseError> {
+    use crate::{
+        category::DatalessRelation,
+        prelude::IntoScriptFlags,
+        script::{repr::IthkuilBasicEncoding, traits::ScriptRepr},
+    };
+
+    let base = UncheckedFormative {
+        relation: DatalessRelation::VRB,
+        .."mala".parse()?
+    };
+
+    // PDC has no Vk equivalent, so it is treated as ASR with USP validation, which is also the
+    // Vk written with the vowel form of STM.
+    assert_eq!(Case::PDC.as_vk(), None);
+    assert_eq!(Case::PDC.as_vk_or_usp(), IllocutionOrValidation::USP);
+    assert_eq!(Case::STM.as_vk(), Some(IllocutionOrValidation::USP));
+
+    let without_vk = UncheckedFormative {
+        vc: Case::PDC,
+        ..base.clone()
+    };
+
+    let usp = UncheckedFormative {
+        vc: Case::STM,
+        ..base
+    };
+
+    for flags in [GlossFlags::NONE, GlossFlags::SHOW_DEFAULTS] {
+        assert_eq!(without_vk.gloss(flags), usp.gloss(flags));
+    }
+
+    assert!(usp.gloss(GlossFlags::NONE).ends_with("-USP"));
+
+    for flags in [IntoScriptFlags::NONE, IntoScriptFlags::KEEP_QUATERNARIES] {
+        assert_eq!(
+            IthkuilBasicEncoding::encode(&without_vk, flags),
+            IthkuilBasicEncoding::encode(&usp, flags),
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn syllable_count() -> Result<(), ParseError> {
+    use crate::romanize::{token_list::TokenList, transform::count_syllables};
+
+    #[track_caller]
+    fn check(source: &str, syllables: usize) -> Result<(), ParseError> {
+        let formative: UncheckedFormative = source.parse()?;
+        assert_eq!(formative.syllable_count(), syllables, "{source}");
+        assert_eq!(
+            formative
+                .to_romanized()
+                .parse::<TokenList>()?
+                .syllable_count(),
+            syllables,
+            "{source}"
+        );
+        Ok(())
+    }
+
+    check("mal", 1)?;
+    check("mala", 2)?;
+    check("wala", 2)?;
+    check("malai", 2)?;
+    check("malëuţřait", 3)?;
+    check("mala'i", 3)?;
+    check("mala'u", 3)?;
+    check("malá", 1)?;
+
+    assert_eq!(count_syllables("ëlmala"), 3);
+    assert_eq!(count_syllables("a'i"), 2);
+    assert_eq!(count_syllables("ai"), 1);
+    assert_eq!(count_syllables("iu"), 1);
+    assert_eq!(count_syllables("ia"), 2);
+    assert_eq!(count_syllables("üa"), 2);
+    assert_eq!(count_syllables("ëi"), 1);
+    assert_eq!(count_syllables("áu"), 1);
+    assert_eq!(count_syllables("pļļ"), 0);
+    assert_eq!(count_syllables(""), 0);
+
+    Ok(())
+}
+
+#[test]
+fn affixual_root_contexts() -> Result<(), ParseError> {
+    #[track_caller]
+    fn check(source: &str, short: &str, full: &str, long: &str) -> Result<(), ParseError> {
+        let formative: CheckedFormative = source.parse()?;
+        assert!(
+            matches!(formative, CheckedFormative::Affixual(..)),
+            "{source} was not affixual"
+        );
+        assert_eq!(formative.gloss(GlossFlags::NONE), short, "{source}");
+        assert_eq!(formative.gloss(GlossFlags::SHOW_DEFAULTS), full, "{source}");
+        assert_eq!(formative.gloss(GlossFlags::LONG), long, "{source}");
+
+        let unchecked: UncheckedFormative = source.parse()?;
+        assert_eq!(unchecked.gloss(GlossFlags::NONE), short, "{source}");
+        Ok(())
+    }
+
+    // The degree of an affixual root is separated by a dash, like the Vr of a normal root, so a
+    // context is joined to it with a dot.
+    check(
+        "oëtal",
+        "CPT.DYN-t/1-D1",
+        "CPT.DYN-t/1-D1.EXS-CSL.UPX.DEL.M.NRM-MNO.CCN-THM\\UNF",
+        "completive.dynamic-t/1-D1",
+    )?;
+    check(
+        "oëtail",
+        "CPT.DYN-t/1-D1.FNC",
+        "CPT.DYN-t/1-D1.FNC-CSL.UPX.DEL.M.NRM-MNO.CCN-THM\\UNF",
+        "completive.dynamic-t/1-D1.functional",
+    )?;
+    check(
+        "oëtial",
+        "CPT.DYN-t/1-D1.RPS",
+        "CPT.DYN-t/1-D1.RPS-CSL.UPX.DEL.M.NRM-MNO.CCN-THM\\UNF",
+        "completive.dynamic-t/1-D1.representational",
+    )?;
+    check(
+        "oëtaol",
+        "CPT.DYN-t/1-D1.AMG",
+        "CPT.DYN-t/1-D1.AMG-CSL.UPX.DEL.M.NRM-MNO.CCN-THM\\UNF",
+        "completive.dynamic-t/1-D1.amalgamative",
+    )?;
+
+    let normal: CheckedFormative = "mial".parse()?;
+    assert_eq!(normal.gloss(GlossFlags::NONE), "S1-m-RPS");
+    assert_eq!(
+        normal.gloss(GlossFlags::LONG),
+        "stem_one-m-representational"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn root_constructors() -> Result<(), ParseError> {
+    #[track_caller]
+    fn check(source: &str, root: ShortcutCheckedFormativeRoot) -> Result<(), ParseError> {
+        let formative: UncheckedFormative = source.parse()?;
+        assert_eq!(formative.root, root, "{source}");
+        Ok(())
+    }
+
+    check("mal", ShortcutCheckedFormativeRoot::new_normal("m"))?;
+    check(
+        "aesmlal",
+        ShortcutCheckedFormativeRoot::new_referential("sml")?,
+    )?;
+    check(
+        "ëichacočau",
+        ShortcutCheckedFormativeRoot::new_affixual("ch", AffixDegree::D1),
+    )?;
+    check("a35al", ShortcutCheckedFormativeRoot::new_numeric(35))?;
+
+    assert!(ShortcutCheckedFormativeRoot::new_referential("").is_err());
+    assert!(ShortcutCheckedFormativeRoot::new_referential("w").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn ca_shortcut_round_trip() -> Result<(), ParseError> {
+    use crate::specificity::{AsGeneral, TryAsSpecific};
+
+    for base in ["mala", "malo", "mal", "malahwá"] {
+        let original: UncheckedFormative = base.parse()?;
+
+        for shortcut in NormalCaShortcut::ALL_ITEMS {
+            let ca = shortcut.as_general();
+            assert_eq!(ca.try_as_specific(), Some(shortcut));
+
+            let mut formative = original.clone();
+            formative.ca = ca;
+            formative.shortcut = ShortcutType::Ca;
+
+            let source = formative.to_romanized();
+            let reparsed: UncheckedFormative = source.parse()?;
+            assert_eq!(reparsed.shortcut, ShortcutType::Ca, "{source}");
+            assert_eq!(reparsed.ca, ca, "{source}");
+            assert!(reparsed.semantic_eq(&formative), "{source}");
+        }
+    }
+
+    // Each shortcut is chosen whenever it is no longer than the unshortened form, including the
+    // ones bearing RPV essence.
+    for (shortcut, expected, normalized) in [
+        (NormalCaShortcut::Default, ShortcutType::Ca, "wama"),
+        (NormalCaShortcut::PRX, ShortcutType::Ca, "yama"),
+        (NormalCaShortcut::G, ShortcutType::Normal, "mara"),
+        (NormalCaShortcut::RPV, ShortcutType::Ca, "yaima"),
+        (NormalCaShortcut::N, ShortcutType::Normal, "mava"),
+        (NormalCaShortcut::A, ShortcutType::Normal, "maja"),
+        (NormalCaShortcut::G_RPV, ShortcutType::Normal, "mařa"),
+        (NormalCaShortcut::PRX_RPV, ShortcutType::Ca, "yaoma"),
+    ] {
+        let mut formative: UncheckedFormative = "mala".parse()?;
+        formative.ca = shortcut.as_general();
+        assert_eq!(formative.optimal_shortcut(), expected, "{shortcut:?}");
+
+        formative.normalize_shortcut();
+        assert_eq!(formative.to_romanized(), normalized, "{shortcut:?}");
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "unstable-internals")]
+#[test]
+fn parse_stages() -> Result<(), ParseError> {
+    use crate::{
+        prelude::{FromTokenFlags, FromTokens},
+        romanize::{token::VowelForm, token_list::TokenList},
+        word::formative::parse::{
+            assemble, parse_cc_vv, parse_middle_segments, parse_root, RelationType, WordType,
+        },
+    };
+
+    for source in [
+        "malá",
+        "hlamröé",
+        "úçtļořëi",
+        "wëilaihmá",
+        "ahnaxë",
+        "ëuxtá",
+        "lalu",
+    ] {
+        let list: TokenList = source.parse()?;
+        let expected =
+            ShortcutCheckedFormative::parse_volatile(&mut list.stream(), FromTokenFlags::NONE);
+
+        let mut stream = list.stream();
+        let vc_or_vk: Option<VowelForm> = stream.next_back();
+        let actual = parse_cc_vv(&mut stream, FromTokenFlags::NONE).and_then(|cc_vv| {
+            let relation_type = cc_vv.relation_type;
+            let cr_vr = parse_root(&mut stream, cc_vv)?;
+            let middle = parse_middle_segments(
+                &mut stream,
+                FromTokenFlags::NONE,
+                cr_vr.shortcut,
+                cr_vr.does_vv_have_glottal_stop,
+            )?;
+            assemble(vc_or_vk, relation_type, cr_vr, middle)
+        });
+
+        assert_eq!(actual, expected, "{source}");
+    }
+
+    let list: TokenList = "hlamröé".parse()?;
+    let cc_vv = parse_cc_vv(&mut list.stream(), FromTokenFlags::NONE)?;
+    assert_eq!(cc_vv.relation_type, RelationType::T1(true));
+    assert!(matches!(cc_vv.word_type, WordType::NormalOrNumeric { .. }));
+
+    Ok(())
+}
+
+#[test]
+fn numeric_roots() -> Result<(), ParseError> {
+    // Covers each shortcut mode, plus affixes, cases, and numerals that can't start a word.
+    for (source, romanized, gloss) in [
+        ("a5ala", "5ala", "S1-“5”"),
+        ("eo5ala", "eo5al", "S2-“5”"),
+        ("5alarsai", "5alarsai", "S1-“5”-rs/1₁-POS"),
+        ("wa5e", "wa5e", "S1-“5”-ABS"),
+        ("ya7ihnu", "ya7ihnu", "S1.PRX-“7”-RCP.CCP-IND"),
+        ("5ahlá", "5ahlá", "S1-“5”-SUB-OBS"),
+        ("hla5a", "hla5a", "T1-S1-“5”"),
+        ("hma5al", "hma5al", "T1-S1.PRX-“5”-l/1₁"),
+        ("20ala", "a20al", "S1-“20”"),
+        ("a12alarsai", "12alarsai", "S1-“12”-rs/1₁-POS"),
+    ] {
+        let formative: UncheckedFormative = source.parse()?;
+        assert!(
+            matches!(formative.root, ShortcutCheckedFormativeRoot::Numeric(_)),
+            "{source}"
+        );
+        assert_eq!(formative.gloss(GlossFlags::NONE), gloss, "{source}");
+        assert_eq!(formative.to_romanized(), romanized, "{source}");
+
+        let reparsed: UncheckedFormative = romanized.parse()?;
+        assert!(reparsed.semantic_eq(&formative), "{source}");
+    }
+
+    assert_eq!(
+        "99999999999999999999ala".parse::<UncheckedFormative>(),
+        Err(ParseError::SourceNumeralInvalid),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn std_conversions() -> Result<(), ParseError> {
+    let checked: CheckedFormative = "malá".parse()?;
+
+    // Widening conversions use `From`.
+    let shortcut_checked: ShortcutCheckedFormative = checked.clone().into();
+    let unchecked: UncheckedFormative = shortcut_checked.clone().into();
+    assert_eq!(UncheckedFormative::from(checked.clone()), unchecked);
+
+    // Narrowing conversions use `TryFrom`, with `ParseError` so that `?` works while parsing.
+    assert_eq!(
+        CheckedFormative::try_from(shortcut_checked.clone())?,
+        checked
+    );
+    assert_eq!(
+        ShortcutCheckedFormative::try_from(unchecked.clone())?,
+        shortcut_checked
+    );
+    let narrowed: CheckedFormative = unchecked.clone().try_into()?;
+    assert_eq!(narrowed, checked);
+
+    // Only Ca values with a shortcut form can be used with Ca shortcuts.
+    let mut invalid = unchecked.clone();
+    invalid.ca = ca!(ASO);
+    invalid.shortcut = ShortcutType::Ca;
+    assert_eq!(
+        ShortcutCheckedFormative::try_from(invalid.clone()),
+        Err(ParseError::InvalidFormative)
+    );
+    assert_eq!(
+        CheckedFormative::try_from(invalid),
+        Err(ParseError::InvalidFormative)
+    );
+
+    // Affixual formatives can't use Ca shortcuts, which only checked formatives enforce.
+    let mut affixual = unchecked;
+    affixual.root = ShortcutCheckedFormativeRoot::new_affixual("rs", AffixDegree::D1);
+    affixual.shortcut = ShortcutType::Ca;
+    let affixual = ShortcutCheckedFormative::try_from(affixual)?;
+    assert_eq!(
+        CheckedFormative::try_from(affixual),
+        Err(ParseError::AffixualFormativeWithCaShortcut)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn slot_labels() -> Result<(), ParseError> {
+    for (source, plain, labeled) in [
+        ("hlamröé", "T1-S1-mr-PCR", "[I:T1][II:S1][III:mr][IX:PCR]"),
+        (
+            "ačpaihnu",
+            "S1-čp-FNC-CCP-IND",
+            "[II:S1][III:čp][IV:FNC][VI:CCP][IX:IND]",
+        ),
+        (
+            "ma'lalarsa",
+            "S1-m-l/1₁-rs/1₁-PRN",
+            "[II:S1][III:m][VII:l/1₁-rs/1₁][IX:PRN]",
+        ),
+        (
+            "oëtail",
+            "CPT.DYN-t/1-D1.FNC",
+            "[II:CPT.DYN][III:t/1-D1][IV:FNC]",
+        ),
+    ] {
+        let formative: UncheckedFormative = source.parse()?;
+        assert_eq!(formative.gloss(GlossFlags::NONE), plain, "{source}");
+        assert_eq!(formative.gloss(GlossFlags::SHOW_SLOTS), labeled, "{source}");
+    }
+
+    let formative: UncheckedFormative = "malá".parse()?;
+    assert_eq!(
+        formative.gloss(GlossFlags::SHOW_SLOTS | GlossFlags::SHOW_DEFAULTS),
+        "[II:S1.PRC][III:m][IV:STA.BSC.EXS][VI:CSL.UPX.DEL.M.NRM][VIII:MNO.FAC][IX:OBS][X:\\UNF]",
+    );
+
+    Ok(())
+}
+
+#[test]
+fn checked_conversion_errors() -> Result<(), ParseError> {
+    let referential: ShortcutCheckedFormative = "aelal".parse()?;
+    let affixual: ShortcutCheckedFormative = "ëirsal".parse()?;
+
+    // `UncheckedFormative` drops these fields for referential and affixual formatives, so they
+    // can only be set on a `ShortcutCheckedFormative` directly.
+    let mut with_stem = referential.clone();
+    with_stem.0.stem = Stem::S2;
+
+    let mut with_affix_shortcut = affixual.clone();
+    let mut with_specification = affixual.clone();
+    let ShortcutCheckedFormativeAdditions::Normal(additions) = &mut with_affix_shortcut.1 else {
+        unreachable!()
+    };
+    additions.affix_shortcut = AffixShortcut::NEG4;
+    let ShortcutCheckedFormativeAdditions::Normal(additions) = &mut with_specification.1 else {
+        unreachable!()
+    };
+    additions.specification = Specification::CTE;
+
+    let mut with_ca_shortcut = referential.clone();
+    with_ca_shortcut.1 = ShortcutCheckedFormativeAdditions::CaShortcut(NormalCaShortcutAdditions {
+        relation: NormalRelation::default(),
+        vn: Vn::default(),
+        ca: NormalCaShortcut::G,
+        slot_v_affixes: AffixList::default(),
+    });
+
+    for (formative, error) in [
+        (with_stem, ParseError::SpecialFormativeWithStem),
+        (
+            with_affix_shortcut,
+            ParseError::SpecialFormativeWithAffixShortcut,
+        ),
+        (
+            with_specification,
+            ParseError::AffixualFormativeWithSpecification,
+        ),
+        (
+            with_ca_shortcut,
+            ParseError::ReferentialFormativeWithCaShortcut,
+        ),
+    ] {
+        assert_eq!(CheckedFormative::try_from(formative), Err(error));
+    }
+
+    // Referential formatives may have any specification.
+    for source in ["aelal", "aelel", "aelil", "aelol"] {
+        let checked: CheckedFormative = source.parse()?;
+        assert_eq!(checked.to_romanized(), source);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn referential_affixes() -> Result<(), ParseError> {
+    use crate::category::{AppositiveCase, ThematicCase};
+
+    let base: UncheckedFormative = "malá".parse()?;
+
+    for (slot_vii_affixes, source, gloss, long) in [
+        (
+            AffixList::Normal(vec![RegularAffix::referential(
+                "l".parse()?,
+                ThematicCase::THM,
+            )]),
+            "malaól",
+            "S1-m-(1m-THM)-OBS",
+            "stem_one-m-(speaker-thematic)-observational",
+        ),
+        (
+            AffixList::Normal(vec![
+                RegularAffix::referential("lk".parse()?, ThematicCase::ERG),
+                RegularAffix::referential("ţ".parse()?, ThematicCase::IND),
+            ]),
+            "maloelkoáţ",
+            "S1-m-([1m+pa.BEN]-ERG)-(mi.BEN-IND)-OBS",
+            "stem_one-m-([speaker+polyadic_animate.beneficial]-ergative)-(monadic_inanimate.beneficial-inducive)-observational",
+        ),
+        (
+            AffixList::appositive_referential("lk".parse()?, AppositiveCase::GEN),
+            "maliólk",
+            "S1-m-([1m+pa.BEN]-GEN)-OBS",
+            "stem_one-m-([speaker+polyadic_animate.beneficial]-genitive)-observational",
+        ),
+    ] {
+        let formative = UncheckedFormative {
+            slot_vii_affixes,
+            ..base.clone()
+        };
+
+        assert_eq!(formative.to_romanized(), source);
+        assert_eq!(formative.gloss(GlossFlags::NONE), gloss);
+        assert_eq!(formative.gloss(GlossFlags::LONG), long);
+        assert_eq!(source.parse::<UncheckedFormative>()?, formative);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn bare_roots() -> Result<(), ParseError> {
+    use crate::romanize::{flags::FromTokenFlags, traits::FromTokens};
+
+    for (source, gloss, romanized) in [
+        ("ma", "S1-m", "mala"),
+        ("aima", "S1-m", "aimal"),
+        ("aimé", "S1-m-CSV-OBS", "aimél"),
+        ("hwama", "T2-S1-m", "hwamal"),
+        ("aela", "1m", "aelal"),
+        ("ëirsa", "rs/1-D1", "ëirsal"),
+    ] {
+        // Bare roots are rejected as truncated by default...
+        assert_eq!(
+            UncheckedFormative::parse_str(source, FromTokenFlags::NONE),
+            Err(ParseError::ExpectedVr),
+            "{source}"
+        );
+
+        // ...but are accepted with `ALLOW_BARE_ROOT`, with a default Ca and THM case.
+        let formative = UncheckedFormative::parse_str(source, FromTokenFlags::ALLOW_BARE_ROOT)?;
+        assert_eq!(formative.gloss(GlossFlags::NONE), gloss, "{source}");
+        assert_eq!(formative.to_romanized(), romanized, "{source}");
+
+        assert_eq!(romanized.parse::<UncheckedFormative>()?, formative);
+    }
+
+    // Words which are already valid mean the same thing with the flag enabled, and words which
+    // are invalid either way keep their original error.
+    for source in ["mal", "mala", "wama", "malëuţřait"] {
+        assert_eq!(
+            UncheckedFormative::parse_str(source, FromTokenFlags::ALLOW_BARE_ROOT),
+            UncheckedFormative::parse_str(source, FromTokenFlags::NONE),
+        );
+    }
+
+    for (source, error) in [
+        ("m", ParseError::ExpectedVr),
+        ("ml", ParseError::ExpectedVr),
+        ("ma'", ParseError::ExpectedCa),
+        ("ae", ParseError::ExpectedRoot),
+    ] {
+        assert_eq!(
+            UncheckedFormative::parse_str(source, FromTokenFlags::ALLOW_BARE_ROOT),
+            Err(error),
+            "{source}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn gloss_iter() -> Result<(), ParseError> {
+    #[track_caller]
+    fn check(source: &str, slots: &[(&str, &str, &str)]) -> Result<(), ParseError> {
+        let formative: UncheckedFormative = source.parse()?;
+
+        let tokens: Vec<_> = formative
+            .gloss_iter(GlossFlags::NONE)
+            .map(|token| (token.slot, token.separator, token.text))
+            .collect();
+        let expected: Vec<_> = slots
+            .iter()
+            .map(|&(slot, separator, text)| (slot, separator, text.to_owned()))
+            .collect();
+        assert_eq!(tokens, expected, "{source}");
+
+        for flags in [
+            GlossFlags::NONE,
+            GlossFlags::LONG,
+            GlossFlags::SHOW_DEFAULTS,
+            GlossFlags::SHOW_SLOTS,
+        ] {
+            assert_eq!(
+                formative.gloss_iter(flags).collect::<String>(),
+                formative.gloss(flags),
+                "{source}"
+            );
+        }
+
+        Ok(())
+    }
+
+    check(
+        "hliosulţe",
+        &[
+            ("I", "", "T1"),
+            ("II", "-", "S2.N"),
+            ("III", "-", "s"),
+            ("VII", "-", "lţ/9₁"),
+            ("IX", "-", "ABS"),
+        ],
+    )?;
+    check(
+        "las",
+        &[
+            ("II", "", "S1"),
+            ("III", "-", "l"),
+            ("VI", "-", "DPX"),
+            ("IX", "-", "OBS"),
+        ],
+    )?;
+    check(
+        "mial",
+        &[("II", "", "S1"), ("III", "-", "m"), ("IV", "-", "RPS")],
+    )?;
+    check(
+        "oëtail",
+        &[
+            ("II", "", "CPT.DYN"),
+            ("III", "-", "t/1-D1"),
+            ("IV", ".", "FNC"),
+        ],
+    )?;
+    check(
+        "ırburučpaızya",
+        &[
+            ("II", "", "S2.CPT"),
+            ("III", "-", "rb"),
+            ("IV", "-", "DYN"),
+            ("VI", "-", "G"),
+            ("VII", "-", "čp/9₁-(acc:ACT)₂"),
+        ],
+    )?;
+
+    let framed: UncheckedFormative = "malëuţřa".parse()?;
+    let framed = UncheckedFormative {
+        relation: crate::category::DatalessRelation::FRM,
+        ..framed
+    };
+    assert_eq!(
+        framed.gloss_iter(GlossFlags::NONE).last(),
+        Some(GlossToken {
+            slot: "X",
+            separator: "",
+            text: "\\FRM".to_owned()
+        })
+    );
+    assert_eq!(
+        framed.gloss_iter(GlossFlags::NONE).collect::<String>(),
+        framed.gloss(GlossFlags::NONE)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn monosyllabic_stress() -> Result<(), ParseError> {
+    use crate::{
+        category::{DatalessRelation, IllocutionOrValidation, Stress},
+        romanize::token_list::TokenList,
+    };
+
+    // Monosyllabic words can't mark stress positionally, so they count as ultimate and are read as
+    // verbal.
+    for source in ["mal", "wal", "lal"] {
+        let formative: UncheckedFormative = source.parse()?;
+        assert_eq!(formative.relation, DatalessRelation::VRB, "{source}");
+        assert_eq!(formative.stress(), Stress::Ultimate, "{source}");
+        assert_eq!(formative.to_romanized(), source);
+
+        let list: TokenList = formative.to_romanized().parse()?;
+        assert_eq!(list.stress, Some(Stress::Monosyllabic), "{source}");
+
+        // A nominal formative keeps its Vc so that it isn't monosyllabic, and framed formatives
+        // gain enough syllables to mark antepenultimate stress.
+        for (relation, stress) in [
+            (DatalessRelation::NOM, Stress::Penultimate),
+            (DatalessRelation::FRM, Stress::Antepenultimate),
+        ] {
+            let formative = UncheckedFormative {
+                relation,
+                ..formative.clone()
+            };
+            assert!(formative.syllable_count() > 1, "{source}");
+
+            let romanized = formative.to_romanized();
+            let list: TokenList = romanized.parse()?;
+            assert_eq!(list.stress.unwrap_or(Stress::Penultimate), stress);
+            assert_eq!(romanized.parse::<UncheckedFormative>()?, formative);
+        }
+
+        // Verbal formatives stay verbal with any Vk.
+        for ivl in [IllocutionOrValidation::REC, IllocutionOrValidation::DIR] {
+            let formative = UncheckedFormative {
+                vc: ivl.as_vc(),
+                ..formative.clone()
+            };
+            let romanized = formative.to_romanized();
+            assert_eq!(romanized.parse::<UncheckedFormative>()?, formative);
+        }
+    }
+
+    // The stress of a concatenated formative only marks a glottal stop in Vc, so a monosyllabic
+    // one has THM unless it is explicitly accented.
+    for (source, case, romanized) in [
+        ("hmal", Case::THM, "hmala"),
+        ("hmál", Case::PRN, "hmalá"),
+        ("hlal", Case::THM, "hlala"),
+        ("hral", Case::THM, "hrala"),
+    ] {
+        let formative: UncheckedFormative = source.parse()?;
+        assert_eq!(formative.vc, case, "{source}");
+        assert_eq!(formative.to_romanized(), romanized, "{source}");
+        assert_eq!(romanized.parse::<UncheckedFormative>()?, formative);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn validate_slot_v() -> Result<(), ParseError> {
+    use crate::word::ValidationError;
+
+    // A formative passes validation exactly when its romanization parses back unchanged.
+    for source in ["mal", "malahla", "5alahra"] {
+        let base: UncheckedFormative = source.parse()?;
+
+        for count in 0..=3 {
+            let affixes: Vec<_> = ["rs", "kp", "lţ"][..count]
+                .iter()
+                .map(|&cs| {
+                    RegularAffix::Plain(PlainAffix {
+                        cs: cs.into(),
+                        r#type: AffixType::T1,
+                        degree: AffixDegree::D1,
+                    })
+                })
+                .collect();
+
+            for shortcut in [ShortcutType::Normal, ShortcutType::Ca, ShortcutType::Cn] {
+                if shortcut == ShortcutType::Cn && base.cn == Default::default() {
+                    continue;
+                }
+
+                let formative = UncheckedFormative {
+                    shortcut,
+                    slot_v_affixes: AffixList::Normal(affixes.clone()),
+                    ..base.clone()
+                };
+
+                let round_trips = formative.to_romanized().parse().ok() == Some(formative.clone());
+
+                match formative.validate_slot_v() {
+                    Ok(()) => assert!(round_trips, "{source} {count} {shortcut:?}"),
+                    Err(ValidationError::SlotVAffixesWithCnShortcut) => {
+                        assert!(!round_trips, "{source} {count} {shortcut:?}");
+                        assert_eq!(shortcut, ShortcutType::Cn);
+                        assert_ne!(count, 0);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn always_show_case() -> Result<(), ParseError> {
+    let flags = GlossFlags::ALWAYS_SHOW_CASE;
+
+    let formative: UncheckedFormative = "lala".parse()?;
+    assert_eq!(formative.gloss(GlossFlags::NONE), "S1-l");
+    assert_eq!(formative.gloss(flags), "S1-l-THM");
+    assert_eq!(
+        formative.gloss(flags | GlossFlags::LONG),
+        "stem_one-l-thematic"
+    );
+    assert_eq!(
+        formative.gloss(flags | GlossFlags::SHOW_SLOTS),
+        "[II:S1][III:l][IX:THM]"
+    );
+
+    let formative: UncheckedFormative = "lalu".parse()?;
+    assert_eq!(formative.gloss(flags), formative.gloss(GlossFlags::NONE));
+
+    let formative: UncheckedFormative = "laláu".parse()?;
+    assert_eq!(formative.gloss(flags), "S1-l-DEC");
+
+    Ok(())
+}
+
+#[test]
+fn escaped_gloss() -> Result<(), ParseError> {
+    let mut formative: UncheckedFormative = "malarlat".parse()?;
+    formative.root = ShortcutCheckedFormativeRoot::new_normal("m-l");
+    if let AffixList::Normal(affixes) = &mut formative.slot_vii_affixes {
+        affixes[0] = RegularAffix::Plain(PlainAffix::new("r.%", AffixType::T1, AffixDegree::D1));
+    }
+
+    assert_eq!(formative.gloss(GlossFlags::NONE), "S1-m-l-r.%/1₁-t/1₁");
+    assert_eq!(
+        formative.gloss(GlossFlags::ESCAPE),
+        "S1-m%2Dl-r%2E%25/1₁-t/1₁"
+    );
+    assert_eq!(
+        formative.gloss(GlossFlags::ESCAPE | GlossFlags::SHOW_AFFIX_CS),
+        "S1-m%2Dl-r%2E%25/1₁[r%2E%25]-t/1₁[t]"
+    );
+
+    let gloss = formative.gloss(GlossFlags::ESCAPE);
+    let pieces: Vec<_> = gloss.split('-').map(unescape_gloss_text).collect();
+    assert_eq!(
+        pieces,
+        [Some("S1"), Some("m-l"), Some("r.%/1₁"), Some("t/1₁")]
+            .map(|piece| piece.map(String::from)),
+    );
+
+    let parsed: UncheckedFormative = "malarlat".parse()?;
+    assert_eq!(
+        parsed.gloss(GlossFlags::ESCAPE),
+        parsed.gloss(GlossFlags::NONE)
+    );
+
+    assert_eq!(unescape_gloss_text("%"), None);
+    assert_eq!(unescape_gloss_text("%2"), None);
+    assert_eq!(unescape_gloss_text("%41"), None);
+
+    Ok(())
+}
+
+#[test]
+fn shortcut_checked_accessors() -> Result<(), ParseError> {
+    use crate::word::ShortcutCheckedFormative;
+
+    let mut shortcuts = Vec::new();
+
+    for source in ["malarlahwá", "wamarlahwá", "yadalala", "mahla", "mahlarla"] {
+        let formative: ShortcutCheckedFormative = source.parse()?;
+        let unchecked = UncheckedFormative::from(formative.clone());
+        shortcuts.push(unchecked.shortcut);
+
+        assert_eq!(formative.root(), &unchecked.root, "{source}");
+        assert_eq!(formative.stem(), unchecked.stem, "{source}");
+        assert_eq!(formative.version(), unchecked.version, "{source}");
+        assert_eq!(
+            formative.slot_vii_affixes(),
+            &unchecked.slot_vii_affixes,
+            "{source}"
+        );
+        assert_eq!(
+            formative.relation().split_as_dataless_cn_vc(),
+            (unchecked.relation, unchecked.cn, unchecked.vc),
+            "{source}"
+        );
+
+        if unchecked.shortcut == ShortcutType::Cn {
+            assert_eq!(formative.ca(), None, "{source}");
+            assert_eq!(formative.vn(), None, "{source}");
+        } else {
+            assert_eq!(formative.ca(), Some(unchecked.ca), "{source}");
+            assert_eq!(formative.vn(), Some(unchecked.vn), "{source}");
+        }
+    }
+
+    for shortcut in [ShortcutType::Normal, ShortcutType::Ca, ShortcutType::Cn] {
+        assert!(shortcuts.contains(&shortcut));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn normalized_gloss() -> Result<(), ParseError> {
+    use crate::gloss::normalize;
+
+    // Roots and affixes which spell a category abbreviation in lowercase are left alone.
+    for source in [
+        "lala",
+        "lalu",
+        "laláu",
+        "hlamröé",
+        "malarlat",
+        "lalwehá",
+        "aprsal",
+        "aclmal",
+        "atmpal",
+        "malapcla",
+    ] {
+        let formative: UncheckedFormative = source.parse()?;
+        let short = formative.gloss(GlossFlags::NONE);
+
+        assert_eq!(normalize(&short), short, "{source}");
+        assert_eq!(
+            normalize(&formative.gloss(GlossFlags::LONG)),
+            short,
+            "{source}"
+        );
+    }
+
+    assert_eq!(normalize("s1-N-aso.n"), "s1-N-aso.n");
+    assert_eq!(normalize("Stem_One-N-Associative.n"), "S1-N-ASO.n");
+    assert_eq!(normalize("S1-m-pcl/1₁"), "S1-m-pcl/1₁");
+    assert_eq!(normalize("—S1 ·  l—"), "S1 . l");
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::prelude::{word::*, *};
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
 macro_rules! impls {
     ($($ty:ty,)+) => {
@@ -11,9 +11,10 @@ macro_rules! impls {
             }
         }
 
-        impl ToString for $ty {
-            fn to_string(&self) -> String {
-                self.to_string_with(IntoTokensFlags::NONE)
+        /// Writes the canonical romanization of this word, with stress marked where required.
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.to_string_with(IntoTokensFlags::NONE))
             }
         })+
     };
@@ -4,7 +4,7 @@ use crate::{
         Case, Essence, NormalReferentList, Specification, Stress, SuppletiveAdjunctMode,
         VowelFormDegree, VowelFormSequence,
     },
-    prelude::{token::WYForm, IntoTokens, TokenList},
+    prelude::{token::WYForm, AsGeneral, IntoToken, IntoTokens, TokenList, TryAsSpecific},
     romanize::{
         flags::{FromTokenFlags, IntoTokensFlags},
         segment::{Vc2, VxCs},
@@ -154,13 +154,13 @@ from_token_stream_impl!(
                 has_glottal_stop: false,
                 sequence: VowelFormSequence::S1,
                 degree: VowelFormDegree::D1,
-            }) => false,
+            }) => true,
 
             Some(VowelForm {
                 has_glottal_stop: false,
                 sequence: VowelFormSequence::S3,
                 degree: VowelFormDegree::D0,
-            }) => true,
+            }) => false,
 
             _ => return Err(ParseError::ExpectedSuppletiveReferential),
         };
@@ -171,57 +171,6 @@ from_token_stream_impl!(
     is_combo,
 );
 
-from_token_stream_impl!(
-    GeneralReferential,
-    stream,
-    flags,
-    first_referent,
-    {
-        let schwa: Option<Schwa> = stream.next();
-
-        let mut can_be_single_dual = true;
-        let mut can_be_combo = true;
-
-        let first_referent = match stream.next_any() {
-            Some(Token::C(first)) => {
-                let mut consonants = first.0.clone();
-
-                while let Some(Schwa) = stream.next() {
-                    if !flags.matches(FromTokenFlags::PERMISSIVE) {
-                        can_be_combo = false;
-                    }
-
-                    consonants += stream.next_cs().ok_or(ParseError::ReferentExpected)?;
-                }
-
-                GeneralReferent::Normal(consonants.parse()?)
-            }
-
-            Some(Token::V(VowelForm {
-                has_glottal_stop: false,
-                sequence: VowelFormSequence::S1,
-                degree: VowelFormDegree::D1,
-            })) if schwa.is_none() => {
-                can_be_single_dual = false;
-                GeneralReferent::Suppletive(stream.parse(flags)?)
-            }
-
-            Some(Token::V(VowelForm {
-                has_glottal_stop: false,
-                sequence: VowelFormSequence::S3,
-                degree: VowelFormDegree::D0,
-            })) if schwa.is_none() => {
-                can_be_combo = false;
-                GeneralReferent::Suppletive(stream.parse(flags)?)
-            }
-
-            _ => return Err(ParseError::ExpectedSuppletiveReferential),
-        };
-    },
-    can_be_single_dual,
-    can_be_combo,
-);
-
 macro_rules! into_tokens_impl {
     (
         $ty:ident,
@@ -400,76 +349,63 @@ into_tokens_impl!(
     }
 );
 
-into_tokens_impl!(
-    GeneralReferential,
-    list,
-    flags,
-    vowels,
-    referent,
-    essence,
-    second_case,
-    {
-        match referent {
-            GeneralReferent::Normal(referent) => {
-                // TODO: Build referents with interconsonantal schwas.
-                let cs = OwnedConsonantForm(referent.to_string());
-                if flags.matches(IntoTokensFlags::WORD_INITIAL_VOWEL)
-                    || !cs.is_valid_word_initial()
-                    || (*essence == Essence::RPV && second_case.is_none())
-                {
-                    list.push(Schwa);
-                }
-                list.push(cs);
-            }
-            GeneralReferent::Suppletive(referent) => {
-                list.push(VowelForm {
-                    has_glottal_stop: false,
-                    sequence: VowelFormSequence::S3,
-                    degree: VowelFormDegree::D0,
-                });
-                list.push(*referent);
-            }
+impl IntoToken for GeneralReferent {
+    fn into_token(self) -> Token {
+        match self {
+            Self::Normal(referents) => Token::C(OwnedConsonantForm(referents.to_string())),
+            Self::Suppletive(mode) => mode.into_token(),
         }
-    },
-    {
-        match referent {
-            GeneralReferent::Normal(referent) => {
-                // TODO: Build referents with interconsonantal schwas.
-                let cs = OwnedConsonantForm(referent.to_string());
-                if flags.matches(IntoTokensFlags::WORD_INITIAL_VOWEL) || !cs.is_valid_word_initial()
-                {
-                    list.push(Schwa);
-                }
-                list.push(cs);
-            }
-            GeneralReferent::Suppletive(referent) => {
-                list.push(VowelForm {
-                    has_glottal_stop: false,
-                    sequence: VowelFormSequence::S3,
-                    degree: VowelFormDegree::D0,
-                });
-                list.push(*referent);
+    }
+}
+
+impl FromTokens for GeneralReferent {
+    fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
+        // Suppletive adjunct modes are H-forms, while normal referents are consonant clusters.
+        match stream.peek() {
+            Some(Token::H(_)) => Ok(Self::Suppletive(stream.parse(flags)?)),
+            _ => {
+                let cr: OwnedConsonantForm = stream.next().ok_or(ParseError::ReferentExpected)?;
+                Ok(Self::Normal(cr.parse()?))
             }
         }
-    },
-    {
-        let mut vowels = 0;
+    }
+}
+
+impl FromTokens for GeneralReferential {
+    fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
+        // Suppletive referentials start with a vowel form, while normal referentials start with a
+        // referent, which is optionally preceded by a schwa.
+        match stream.peek() {
+            Some(Token::V(_)) => Ok(stream.parse::<SuppletiveReferential>(flags)?.as_general()),
+            _ => Ok(stream.parse::<NormalReferential>(flags)?.as_general()),
+        }
+    }
+}
+
+impl IntoTokens for GeneralReferential {
+    fn append_tokens_to(&self, list: &mut TokenList, flags: IntoTokensFlags) {
+        let (Self::Single { referent, .. }
+        | Self::Dual {
+            first_referent: referent,
+            ..
+        }
+        | Self::Combination { referent, .. }) = self;
 
         match referent {
-            GeneralReferent::Normal(referent) => {
-                let cs = OwnedConsonantForm(referent.to_string());
-                if flags.matches(IntoTokensFlags::WORD_INITIAL_VOWEL) || !cs.is_valid_word_initial()
-                {
-                    list.push(Schwa);
-                    vowels += 1;
-                }
-                list.push(cs);
+            GeneralReferent::Normal(_) => {
+                let referential: NormalReferential = self
+                    .clone()
+                    .try_as_specific()
+                    .expect("a referential with a normal referent is a normal referential");
+                referential.append_tokens_to(list, flags)
             }
-            GeneralReferent::Suppletive(referent) => {
-                list.push(VowelForm::default());
-                vowels = 1;
-                list.push(*referent);
+            GeneralReferent::Suppletive(_) => {
+                let referential: SuppletiveReferential = self
+                    .clone()
+                    .try_as_specific()
+                    .expect("a referential with a suppletive referent is a suppletive referential");
+                referential.append_tokens_to(list, flags)
             }
         }
     }
-);
+}
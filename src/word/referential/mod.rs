@@ -3,7 +3,7 @@
 pub(super) mod aliases;
 
 use crate::{
-    affix::{AffixList, CaseStackingAffix, RegularAffix},
+    affix::{AffixList, RegularAffix},
     category::{
         Ca, Case, Essence, NormalReferentList, ReferentList, Specification, SuppletiveAdjunctMode,
     },
@@ -79,6 +79,105 @@ pub enum Referential<T> {
     },
 }
 
+impl<T> Referential<T> {
+    /// Gets the affixes of this referential. Single and dual referentials have no affixes, so an
+    /// empty slice is returned for them.
+    pub fn affixes(&self) -> &[RegularAffix] {
+        match self {
+            Self::Combination { affixes, .. } => affixes,
+            Self::Single { .. } | Self::Dual { .. } => &[],
+        }
+    }
+
+    /// Gets the affixes of this referential mutably. Single and dual referentials have no
+    /// affixes, so an empty slice is returned for them.
+    pub fn affixes_mut(&mut self) -> &mut [RegularAffix] {
+        match self {
+            Self::Combination { affixes, .. } => affixes,
+            Self::Single { .. } | Self::Dual { .. } => &mut [],
+        }
+    }
+}
+
+impl<T: PartialEq> Referential<T> {
+    /// Checks whether `self` and `other` have the same meaning, even if they differ structurally.
+    ///
+    /// Unlike the derived [`PartialEq`] implementation, this ignores:
+    ///
+    /// - an explicit THM second case on a referential whose first case is also THM, which is
+    ///   treated the same as no second case at all, as stacking THM onto THM adds nothing; and
+    /// - the order of the affixes of combination referentials, which are compared as a multiset.
+    ///
+    /// The essence of a referential is always significant. It has no absent form, so an NRM
+    /// essence is already explicit and compares equal to other NRM essences. Other second cases
+    /// are significant too, since they stack the first case instead of replacing it.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Single {
+                    referent: referent_a,
+                    first_case: first_case_a,
+                    second_case: second_case_a,
+                    essence: essence_a,
+                },
+                Self::Single {
+                    referent: referent_b,
+                    first_case: first_case_b,
+                    second_case: second_case_b,
+                    essence: essence_b,
+                },
+            ) => {
+                referent_a == referent_b
+                    && first_case_a == first_case_b
+                    && normalized_second_case(*first_case_a, *second_case_a)
+                        == normalized_second_case(*first_case_b, *second_case_b)
+                    && essence_a == essence_b
+            }
+
+            (
+                Self::Combination {
+                    referent: referent_a,
+                    first_case: first_case_a,
+                    specification: specification_a,
+                    affixes: affixes_a,
+                    second_case: second_case_a,
+                    essence: essence_a,
+                },
+                Self::Combination {
+                    referent: referent_b,
+                    first_case: first_case_b,
+                    specification: specification_b,
+                    affixes: affixes_b,
+                    second_case: second_case_b,
+                    essence: essence_b,
+                },
+            ) => {
+                referent_a == referent_b
+                    && first_case_a == first_case_b
+                    && specification_a == specification_b
+                    && affixes_a.len() == affixes_b.len()
+                    && affixes_a.iter().all(|affix| {
+                        affixes_a.iter().filter(|other| *other == affix).count()
+                            == affixes_b.iter().filter(|other| *other == affix).count()
+                    })
+                    && normalized_second_case(*first_case_a, *second_case_a)
+                        == normalized_second_case(*first_case_b, *second_case_b)
+                    && essence_a == essence_b
+            }
+
+            _ => self == other,
+        }
+    }
+}
+
+/// Drops a THM second case stacked onto a THM first case. Used by [`Referential::semantic_eq`].
+fn normalized_second_case(first_case: Case, second_case: Option<Case>) -> Option<Case> {
+    match (first_case, second_case) {
+        (Case::THM, Some(Case::THM)) => None,
+        _ => second_case,
+    }
+}
+
 impl NormalReferential {
     /// Converts this referential into a formative.
     ///
@@ -105,9 +204,7 @@ impl NormalReferential {
                     slot_vii_affixes: if second_case.is_none() {
                         AffixList::Normal(Vec::new())
                     } else {
-                        AffixList::Normal(vec![RegularAffix::CaseStacking(CaseStackingAffix {
-                            case: *first_case,
-                        })])
+                        AffixList::Normal(vec![RegularAffix::case_stacking(*first_case)])
                     },
                     ca: Ca {
                         perspective: *perspective,
@@ -196,9 +293,7 @@ impl NormalReferential {
                         let mut affixes = affixes.clone();
 
                         if second_case.is_some() {
-                            affixes.push(RegularAffix::CaseStacking(CaseStackingAffix {
-                                case: *first_case,
-                            }));
+                            affixes.push(RegularAffix::case_stacking(*first_case));
                         }
 
                         AffixList::Normal(affixes)
@@ -212,6 +307,16 @@ impl NormalReferential {
     }
 }
 
+/// Glosses the essence of a referential, which is elided if it is NRM unless `SHOW_DEFAULTS` or
+/// `EXPLICIT_MONADIC` is passed.
+fn gloss_essence(essence: Essence, flags: GlossFlags) -> &'static str {
+    if flags.matches(GlossFlags::EXPLICIT_MONADIC) {
+        essence.gloss_static(flags)
+    } else {
+        essence.gloss_static_non_default(flags)
+    }
+}
+
 impl<T: Gloss> Gloss for Referential<T> {
     fn gloss(&self, flags: GlossFlags) -> String {
         match self {
@@ -228,7 +333,7 @@ impl<T: Gloss> Gloss for Referential<T> {
                 } else {
                     output.add_dashed(first_case.gloss_static_non_default(flags));
                 }
-                output.add_dashed(essence.gloss_static_non_default(flags));
+                output.add_dashed(gloss_essence(*essence, flags));
                 output
             }
 
@@ -243,7 +348,7 @@ impl<T: Gloss> Gloss for Referential<T> {
                 output.add_dashed(first_case.gloss_static(flags));
                 output.add_dashed(second_case.gloss_static(flags));
                 output.add_dashed(&second_referent.gloss(flags));
-                output.add_dashed(essence.gloss_static_non_default(flags));
+                output.add_dashed(gloss_essence(*essence, flags));
                 output
             }
 
@@ -264,7 +369,7 @@ impl<T: Gloss> Gloss for Referential<T> {
                 if let Some(second_case) = second_case {
                     output.add_dashed(&second_case.gloss(flags));
                 }
-                output.add_dashed(essence.gloss_static_non_default(flags));
+                output.add_dashed(gloss_essence(*essence, flags));
                 output
             }
         }
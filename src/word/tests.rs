@@ -1,9 +1,12 @@
-use crate::prelude::*;
-use std::str::FromStr;
+use crate::{
+    affix::{AffixList, RegularAffix},
+    prelude::*,
+};
+use std::{fmt::Display, str::FromStr};
 
 #[test]
 fn traits() {
-    fn check<T: FromStr + FromTokens + IntoTokens + Gloss>() {}
+    fn check<T: FromStr + FromTokens + IntoTokens + Gloss + Display>() {}
 
     check::<Word>();
 
@@ -75,3 +78,1078 @@ fn parsing_and_glossing() -> Result<(), ParseError> {
 
     Ok(())
 }
+
+#[test]
+fn alternate_notations() -> Result<(), ParseError> {
+    fn check(alternate: &str, canonical: &str) -> Result<(), ParseError> {
+        assert!(
+            Word::parse_str(alternate, FromTokenFlags::NONE).is_err(),
+            "original word was {alternate:?}"
+        );
+
+        let expected = Word::parse_str(canonical, FromTokenFlags::NONE)?;
+        let actual = Word::parse_str(alternate, FromTokenFlags::ACCEPT_ALTERNATES)?;
+
+        assert_eq!(actual, expected, "original word was {alternate:?}");
+        assert_eq!(
+            actual.to_string(),
+            expected.to_string(),
+            "original word was {alternate:?}"
+        );
+
+        Ok(())
+    }
+
+    check("lala`a", "lala'a")?;
+    check("lalaʻa", "lala'a")?;
+    check("lalaʽa", "lala'a")?;
+    check("lalaʹa", "lala'a")?;
+    check("lala′a", "lala'a")?;
+    check("ìrburučpa`zya", "irburučpa'zya")?;
+
+    // Stress marks are not glottal stops.
+    assert!(Word::parse_str("lalaˈa", FromTokenFlags::ACCEPT_ALTERNATES).is_err());
+    assert!(Word::parse_str("lala´a", FromTokenFlags::ACCEPT_ALTERNATES).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn word_kinds() -> Result<(), ParseError> {
+    fn check(input_word: &str, expected_kind: WordKind) -> Result<(), ParseError> {
+        let word: Word = input_word.parse()?;
+        assert_eq!(
+            word.kind(),
+            expected_kind,
+            "original word was {input_word:?}"
+        );
+        Ok(())
+    }
+
+    check("ašflaleče", WordKind::Formative)?;
+    check("lawe", WordKind::Referential)?;
+    check("er", WordKind::Affixual)?;
+
+    let list: TokenList = "kkkkkkkk".parse()?;
+    let error = Word::parse_any(&mut list.stream(), FromTokenFlags::NONE).unwrap_err();
+    let kinds: Vec<_> = error.attempts.iter().map(|(kind, _)| *kind).collect();
+    assert_eq!(
+        kinds,
+        WordKind::candidates(&list.tokens[0]),
+        "every candidate should be attempted",
+    );
+    assert_eq!(error.error, error.attempts.last().unwrap().1);
+
+    Ok(())
+}
+
+#[test]
+fn referential_affixes() -> Result<(), ParseError> {
+    let mut referential: word::NormalReferential = "laxelt".parse()?;
+    assert_eq!(referential.affixes().len(), 1);
+
+    if let RegularAffix::Plain(affix) = &mut referential.affixes_mut()[0] {
+        affix.degree = category::AffixDegree::D7;
+    }
+
+    let (formative, _) = referential.to_formative();
+    assert_eq!(
+        formative.slot_vii_affixes,
+        AffixList::Normal(referential.affixes().to_vec()),
+    );
+    assert_eq!(referential.gloss(GlossFlags::NONE), "1m-BSC-lt/7₁");
+
+    let referential: word::NormalReferential = "la".parse()?;
+    assert!(referential.affixes().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn referential_semantic_eq() -> Result<(), ParseError> {
+    fn check(a: &str, b: &str, expected: bool) -> Result<(), ParseError> {
+        let a: word::GeneralReferential = a.parse()?;
+        let b: word::GeneralReferential = b.parse()?;
+        assert_eq!(a.semantic_eq(&b), expected, "{a:?} and {b:?}");
+        assert_eq!(b.semantic_eq(&a), expected, "{b:?} and {a:?}");
+        Ok(())
+    }
+
+    check("la", "la", true)?;
+    check("la", "lawa", true)?;
+    check("lax", "laxüa", true)?;
+    check("lá", "lawá", true)?;
+    check("laxaskal", "laxalask", true)?;
+
+    check("la", "lá", false)?;
+    check("la", "lawe", false)?;
+    check("lo", "lowa", false)?;
+    check("lax", "laxt", false)?;
+    check("lexüa", "lex", false)?;
+    check("laxaskal", "laxaskel", false)?;
+    check("laxaskal", "laxaskalask", false)?;
+    check("ahňaxüa", "ahňax", true)?;
+    check("ahňax", "lax", false)?;
+
+    Ok(())
+}
+
+#[test]
+fn suppletive_adjunct_round_trip() -> Result<(), ParseError> {
+    for mode in category::SuppletiveAdjunctMode::ALL_ITEMS {
+        for case in category::Case::ALL_ITEMS {
+            let adjunct = word::SuppletiveAdjunct { mode, case };
+            let source = adjunct.to_string();
+
+            let parsed: word::SuppletiveAdjunct = source.parse()?;
+            assert_eq!(parsed, adjunct, "source was {source:?}");
+
+            let parsed: Word = source.parse()?;
+            assert_eq!(parsed, Word::Suppletive(adjunct), "source was {source:?}");
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn vncn_aspect_coupling() -> Result<(), ParseError> {
+    use crate::romanize::segment::{Cn, VnCn};
+
+    let vns = category::Valence::ALL_ITEMS
+        .into_iter()
+        .map(category::Vn::Valence)
+        .chain(
+            category::Aspect::ALL_ITEMS
+                .into_iter()
+                .map(category::Vn::Aspect),
+        );
+
+    for vn in vns {
+        for mcs in category::ArbitraryMoodOrCaseScope::ALL_ITEMS {
+            let vncn = VnCn::new(vn, mcs);
+            let cn = vncn.cn_form();
+            assert_eq!(cn.is_aspect, vn.is_aspect());
+            assert!(vncn.is_consistent_with(cn));
+            assert!(!vncn.is_consistent_with(Cn {
+                is_aspect: !cn.is_aspect,
+                ..cn
+            }));
+
+            let tokens = vncn.into_tokens(IntoTokensFlags::NONE);
+            let parsed: VnCn = tokens.stream().parse(FromTokenFlags::NONE)?;
+            assert_eq!(parsed, vncn);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn register_nesting() -> Result<(), ParseError> {
+    use word::{validate_register_nesting, RegisterError};
+
+    fn words(source: &str) -> Result<Vec<Word>, ParseError> {
+        source.split(' ').map(str::parse).collect()
+    }
+
+    // ha = DSV, hai = DSV_END, he = PNT, hei = PNT_END, hü = END
+    assert_eq!(
+        validate_register_nesting(&words("ha malëuţřait hai")?),
+        Ok(())
+    );
+    assert_eq!(validate_register_nesting(&words("ha he hei hü")?), Ok(()));
+    assert_eq!(validate_register_nesting(&words("malëuţřait")?), Ok(()));
+
+    assert_eq!(
+        validate_register_nesting(&words("malëuţřait hai")?),
+        Err(RegisterError::UnmatchedEnd {
+            index: 1,
+            register: category::RegisterType::DSV_END,
+        }),
+    );
+
+    assert_eq!(
+        validate_register_nesting(&words("ha he hai")?),
+        Err(RegisterError::MismatchedEnd {
+            start_index: 1,
+            start: category::RegisterType::PNT,
+            end_index: 2,
+            end: category::RegisterType::DSV_END,
+        }),
+    );
+
+    assert_eq!(
+        validate_register_nesting(&words("ha he hü")?),
+        Err(RegisterError::Unclosed {
+            index: 0,
+            register: category::RegisterType::DSV,
+        }),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn modular_adjunct_round_trip() -> Result<(), ParseError> {
+    use category::{
+        ArbitraryMoodOrCaseScope, Aspect, Level, ModularAdjunctMode, ModularAdjunctScope,
+        NonAspectualVn, Phase, Valence, Vn,
+    };
+    use word::ModularAdjunct;
+
+    let vns = [
+        Vn::Valence(Valence::MNO),
+        Vn::Valence(Valence::PRL),
+        Vn::Phase(Phase::RCT),
+        Vn::Level(Level::SPL),
+        Vn::Aspect(Aspect::RTR),
+        Vn::Aspect(Aspect::CSS),
+    ];
+
+    let vn3s = [
+        NonAspectualVn::Valence(Valence::MNO),
+        NonAspectualVn::Valence(Valence::DEM),
+        NonAspectualVn::Phase(Phase::REP),
+        NonAspectualVn::Level(Level::EQU),
+    ];
+
+    let mut adjuncts = Vec::new();
+
+    for mode in ModularAdjunctMode::ALL_ITEMS {
+        for aspect in Aspect::ALL_ITEMS {
+            adjuncts.push(ModularAdjunct::Aspect { mode, aspect });
+        }
+
+        for vn1 in vns {
+            for cn in ArbitraryMoodOrCaseScope::ALL_ITEMS {
+                for vn2 in [None, Some(Vn::Valence(Valence::MNO)), Some(vns[4])] {
+                    for vn3 in vn3s {
+                        adjuncts.push(ModularAdjunct::NonScoped {
+                            mode,
+                            vn1,
+                            cn,
+                            vn2,
+                            vn3,
+                        });
+                    }
+
+                    for scope in ModularAdjunctScope::ALL_ITEMS {
+                        adjuncts.push(ModularAdjunct::Scoped {
+                            mode,
+                            vn1,
+                            cn,
+                            vn2,
+                            scope,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    for adjunct in adjuncts {
+        let source = adjunct.to_string();
+
+        let parsed: ModularAdjunct = source.parse()?;
+        assert_eq!(parsed, adjunct, "source was {source:?}");
+
+        let parsed: Word = source.parse()?;
+        assert_eq!(parsed, Word::Modular(adjunct), "source was {source:?}");
+
+        assert_eq!(
+            parsed.gloss(GlossFlags::NONE),
+            adjunct.gloss(GlossFlags::NONE),
+            "source was {source:?}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn general_referential_round_trip() -> Result<(), ParseError> {
+    use crate::affix::PlainAffix;
+    use category::{AffixDegree, AffixType, Case, Essence, Specification, SuppletiveAdjunctMode};
+    use word::{referential::GeneralReferent, GeneralReferential};
+
+    let referents = [
+        GeneralReferent::Normal("l".parse()?),
+        GeneralReferent::Normal("sm".parse()?),
+        GeneralReferent::Suppletive(SuppletiveAdjunctMode::CAR),
+        GeneralReferent::Suppletive(SuppletiveAdjunctMode::PHR),
+    ];
+
+    let mut referentials = Vec::new();
+
+    for referent in referents {
+        for essence in [Essence::NRM, Essence::RPV] {
+            for first_case in [Case::THM, Case::ERG, Case::IND] {
+                for second_case in [None, Some(Case::ABS)] {
+                    referentials.push(GeneralReferential::Single {
+                        referent: referent.clone(),
+                        first_case,
+                        second_case,
+                        essence,
+                    });
+                }
+
+                for second_case in [None, Some(Case::THM), Some(Case::ABS)] {
+                    for affixes in [
+                        Vec::new(),
+                        vec![RegularAffix::Plain(PlainAffix::new(
+                            "lt",
+                            AffixType::T1,
+                            AffixDegree::D3,
+                        ))],
+                    ] {
+                        referentials.push(GeneralReferential::Combination {
+                            referent: referent.clone(),
+                            first_case,
+                            specification: Specification::CTE,
+                            affixes,
+                            second_case,
+                            essence,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    for referential in referentials {
+        let source = referential.to_string();
+
+        let parsed: GeneralReferential = source.parse()?;
+        assert_eq!(parsed, referential, "source was {source:?}");
+
+        if let Some(specific) = referential.clone().try_as_specific() {
+            let parsed: word::SuppletiveReferential = source.parse()?;
+            assert_eq!(parsed, specific, "source was {source:?}");
+        }
+
+        let parsed: Word = source.parse()?;
+        assert_eq!(
+            parsed,
+            Word::Referential(referential),
+            "source was {source:?}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn general_referent_round_trip() -> Result<(), ParseError> {
+    use category::SuppletiveAdjunctMode;
+    use word::referential::GeneralReferent;
+
+    for (referent, source) in [
+        (GeneralReferent::Normal("l".parse()?), "l"),
+        (GeneralReferent::Normal("sm".parse()?), "sm"),
+        (GeneralReferent::Normal("ňzl".parse()?), "ňzl"),
+        (
+            GeneralReferent::Suppletive(SuppletiveAdjunctMode::CAR),
+            "hl",
+        ),
+        (
+            GeneralReferent::Suppletive(SuppletiveAdjunctMode::QUO),
+            "hm",
+        ),
+        (
+            GeneralReferent::Suppletive(SuppletiveAdjunctMode::NAM),
+            "hn",
+        ),
+        (
+            GeneralReferent::Suppletive(SuppletiveAdjunctMode::PHR),
+            "hň",
+        ),
+    ] {
+        let list = referent.into_tokens(IntoTokensFlags::NONE);
+        assert_eq!(list.tokens.len(), 1, "source was {source:?}");
+        assert_eq!(list.to_string(), source);
+
+        let parsed: GeneralReferent = list.stream().parse_entire(FromTokenFlags::NONE)?;
+        assert_eq!(parsed, referent, "source was {source:?}");
+
+        let parsed = GeneralReferent::parse_str(source, FromTokenFlags::NONE)?;
+        assert_eq!(parsed, referent, "source was {source:?}");
+    }
+
+    assert_eq!(
+        GeneralReferent::parse_str("hw", FromTokenFlags::NONE),
+        Err(ParseError::ExpectedCp)
+    );
+    assert_eq!(
+        GeneralReferent::parse_str("a", FromTokenFlags::NONE),
+        Err(ParseError::ReferentExpected)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn referent_abbrs() -> Result<(), ParseError> {
+    use category::{AffixualReferentList, NormalReferentList, PerspectivelessReferentList};
+
+    let list: NormalReferentList = "tļsmmrr".parse()?;
+    assert_eq!(list.referent_abbrs(), ["2m", "PVS", "Obv"]);
+    assert_eq!(list.gloss(GlossFlags::NONE), "[2m+PVS+Obv.BEN+G]");
+
+    let list: PerspectivelessReferentList = "kthz".parse()?;
+    assert_eq!(list.referent_abbrs(), ["pa", "Rdp", "mi"]);
+
+    let list: AffixualReferentList = "ţ".parse()?;
+    assert_eq!(list.referent_abbrs(), ["mi"]);
+
+    Ok(())
+}
+
+#[test]
+fn dual_referential_round_trip() -> Result<(), ParseError> {
+    use category::{Case, Essence, SuppletiveAdjunctMode};
+    use word::{referential::GeneralReferent, GeneralReferential};
+
+    let referents = [
+        GeneralReferent::Normal("l".parse()?),
+        GeneralReferent::Normal("sm".parse()?),
+        GeneralReferent::Suppletive(SuppletiveAdjunctMode::QUO),
+    ];
+
+    for first_referent in referents {
+        for second_referent in ["s", "ţ", "mm"] {
+            for essence in [Essence::NRM, Essence::RPV] {
+                for (first_case, second_case) in [
+                    (Case::THM, Case::ERG),
+                    (Case::ERG, Case::THM),
+                    (Case::ABS, Case::IND),
+                    (Case::DAT, Case::POS),
+                    (Case::PRN, Case::LOC),
+                    (Case::ALL, Case::CMM),
+                ] {
+                    let referential = GeneralReferential::Dual {
+                        first_referent: first_referent.clone(),
+                        first_case,
+                        second_case,
+                        second_referent: second_referent.parse()?,
+                        essence,
+                    };
+
+                    let source = referential.to_string();
+
+                    let parsed: GeneralReferential = source.parse()?;
+                    assert_eq!(parsed, referential, "source was {source:?}");
+
+                    let parsed: Word = source.parse()?;
+                    assert_eq!(
+                        parsed,
+                        Word::Referential(referential),
+                        "source was {source:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn affix_list_mutation() -> Result<(), ParseError> {
+    use crate::affix::{AffixListError, AppositiveReferentialAffix, PlainAffix};
+    use category::{AffixDegree, AffixType, AppositiveCase};
+
+    let affix = |cs: &str, degree| {
+        RegularAffix::Plain(PlainAffix::new(cs.to_owned(), AffixType::T1, degree))
+    };
+
+    let mut list = AffixList::Normal(Vec::new());
+    assert!(list.is_empty());
+    assert_eq!(list.len(), 0);
+
+    assert_eq!(list.push(affix("rl", AffixDegree::D1)), Ok(()));
+    assert_eq!(
+        list.extend([affix("c", AffixDegree::D2), affix("ks", AffixDegree::D3)]),
+        Ok(())
+    );
+    assert!(!list.is_empty());
+    assert_eq!(list.len(), 3);
+
+    let mut list = AffixList::AppositiveReferential(AppositiveReferentialAffix::new(
+        "l".parse()?,
+        AppositiveCase::POS,
+    ));
+    let original = list.clone();
+    assert!(!list.is_empty());
+    assert_eq!(list.len(), 1);
+
+    assert_eq!(list.push(affix("rl", AffixDegree::D1)), Err(AffixListError));
+    assert_eq!(
+        list.extend([affix("c", AffixDegree::D2)]),
+        Err(AffixListError)
+    );
+    assert_eq!(list.extend([]), Ok(()));
+    assert_eq!(list, original);
+
+    Ok(())
+}
+
+#[test]
+fn affix_vxcs_round_trip() -> Result<(), ParseError> {
+    use crate::{
+        affix::{NumericAffix, PlainAffix},
+        ca,
+        category::{AffixDegree, AffixType, Case, CaseAccessorMode, ThematicCase},
+        romanize::token::NumeralForm,
+    };
+
+    let mut affixes = vec![
+        RegularAffix::ca_stacking(ca!(G, RPV)),
+        RegularAffix::referential("lk".parse()?, ThematicCase::ERG),
+        RegularAffix::referential("ţ".parse()?, ThematicCase::THM),
+    ];
+
+    for r#type in [AffixType::T1, AffixType::T2, AffixType::T3] {
+        for degree in AffixDegree::ALL_ITEMS {
+            affixes.push(RegularAffix::Plain(PlainAffix::new("rl", r#type, degree)));
+            affixes.push(RegularAffix::Numeric(NumericAffix {
+                form: NumeralForm { integer_part: 27 },
+                r#type,
+                degree,
+            }));
+        }
+
+        for mode in [CaseAccessorMode::Normal, CaseAccessorMode::Inverse] {
+            // Cases with glottal stops show them through their Cs form.
+            for case in [Case::THM, Case::CSD, Case::PRN, Case::PLM] {
+                affixes.push(RegularAffix::case_accessor(case, r#type, mode));
+            }
+        }
+    }
+
+    for case in [Case::THM, Case::CSD, Case::PRN, Case::PLM] {
+        affixes.push(RegularAffix::case_stacking(case));
+    }
+
+    for affix in affixes {
+        let (vx, cs) = affix.to_vxcs();
+        assert!(!vx.has_glottal_stop, "{affix:?}");
+        assert_eq!(RegularAffix::from_vxcs(vx, &cs), Ok(affix));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn analyze() -> Result<(), ParseError> {
+    let analysis = crate::analyze("la", GlossFlags::NONE)?;
+    assert_eq!(analysis.kind(), WordKind::Referential);
+    assert_eq!(analysis.gloss, "1m");
+    assert_eq!(analysis.normalized_romanization, "la");
+    assert!(analysis.script.is_some());
+
+    let analysis = crate::analyze("hai", GlossFlags::NONE)?;
+    assert_eq!(analysis.kind(), WordKind::Register);
+    assert_eq!(analysis.script, None);
+
+    assert!(crate::analyze("ü'ü'ü", GlossFlags::NONE).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn explicit_monadic() -> Result<(), ParseError> {
+    fn check(source: &str, normal: &str, explicit: &str) -> Result<(), ParseError> {
+        let word: Word = source.parse()?;
+        assert_eq!(
+            word.gloss(GlossFlags::NONE),
+            normal,
+            "source was {source:?}"
+        );
+        assert_eq!(
+            word.gloss(GlossFlags::EXPLICIT_MONADIC),
+            explicit,
+            "source was {source:?}"
+        );
+        Ok(())
+    }
+
+    check("la", "1m", "[1m+M]-NRM")?;
+    check("lo", "1m-ERG", "[1m+M]-ERG-NRM")?;
+    check("lau", "1m-PRP", "[1m+M]-PRP-NRM")?;
+    check("lá", "1m-RPV", "[1m+M]-RPV")?;
+    check("xla", "[1m+N]", "[1m+N]-NRM")?;
+
+    Ok(())
+}
+
+#[test]
+fn combination_referential_explicit_thm() -> Result<(), ParseError> {
+    fn check(source: &str, gloss: &str) -> Result<(), ParseError> {
+        let word: Word = source.parse()?;
+        assert_eq!(word.gloss(GlossFlags::NONE), gloss, "source was {source:?}");
+        assert_eq!(word.to_string(), source, "source was {source:?}");
+        Ok(())
+    }
+
+    check("laxüa", "1m-BSC-THM")?;
+    check("laxüá", "1m-BSC-THM-RPV")?;
+    check("lexüa", "1m-ABS-BSC-THM")?;
+    check("laxasküa", "1m-BSC-sk/1₁-THM")?;
+    check("ahňaxüa", "[PHR]-BSC-THM")?;
+    check("ahňaxasküa", "[PHR]-BSC-sk/1₁-THM")?;
+
+    assert_eq!("üa".parse::<Word>(), Err(ParseError::WordInitialÜA));
+
+    Ok(())
+}
+
+#[test]
+fn gloss_presets() -> Result<(), ParseError> {
+    fn check(source: &str, reference: &str) -> Result<(), ParseError> {
+        let word: Word = source.parse()?;
+        assert_eq!(
+            word.gloss(GlossFlags::reference_style()),
+            reference,
+            "source was {source:?}"
+        );
+        Ok(())
+    }
+
+    check("la", "[1m.NEU+M]-THM-NRM")?;
+    check("lexüa", "[1m.NEU+M]-ABS-BSC-THM-NRM")?;
+    check("malá", "S1.PRC-m-STA.BSC.EXS-CSL.UPX.DEL.M.NRM-MNO.FAC-OBS")?;
+    check(
+        "ašflaleče",
+        "S1.PRC-šfl-STA.BSC.EXS-CSL.UPX.DEL.M.NRM-č/3₁-MNO.CCN-ABS",
+    )?;
+    check("hai", "DSV_END")?;
+    check("pļļ", "CMD")?;
+
+    Ok(())
+}
+
+#[test]
+fn affixual_adjunct_scopes() -> Result<(), ParseError> {
+    fn check(source: &str, gloss: &str) -> Result<(), ParseError> {
+        let word: Word = source.parse()?;
+        assert!(
+            matches!(word, Word::Affixual(_)),
+            "source {source:?} was not an affixual adjunct"
+        );
+        assert_eq!(word.gloss(GlossFlags::NONE), gloss, "source was {source:?}");
+        Ok(())
+    }
+
+    check("arsa", "rs/1₁")?;
+    check("arsu", "rs/1₁-{v.sub}")?;
+    check("arsi", "rs/1₁-{vii.sub}")?;
+    check("arso", "rs/1₁-{form.}")?;
+
+    check("lahas", "l/1₁-s/1₁")?;
+    check("lahasi", "l/1₁-s/1₁-{vii.sub}")?;
+    check("la'has", "l/1₁-{v.sub}-s/1₁")?;
+    check("ëla'hras", "l/1₁-{vii.sub}-s/1₁")?;
+    check("lahwasu", "l/1₁-{form.}-s/1₁-{v.sub}")?;
+    check("la'hwaso", "l/1₁-{over_adj}-s/1₁-{form.}")?;
+
+    // An explicit Vz of -a- differs from an absent one when the first scope isn't the default.
+    check("la'hasa", "l/1₁-{v.sub}-s/1₁-{v.dom}")?;
+    check("la'hasai", "l/1₁-{v.sub}-s/1₁")?;
+    check("lahasa", "l/1₁-s/1₁")?;
+
+    Ok(())
+}
+
+#[test]
+fn gloss_snapshots() -> Result<(), ParseError> {
+    use crate::romanize::transform::normalize;
+
+    for (index, line) in include_str!("gloss_snapshots.tsv").lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line_number = index + 1;
+
+        let Some((source, expected_gloss)) = line.split_once('\t') else {
+            panic!("snapshot line {line_number} should be a word and a gloss separated by a tab");
+        };
+
+        // Normalizing first keeps the snapshots independent of how its diacritics are encoded.
+        let source = normalize(source.trim());
+
+        let word: Word = source.parse()?;
+        assert_eq!(
+            word.gloss(GlossFlags::NONE),
+            expected_gloss.trim(),
+            "snapshot line {line_number} was {source:?}",
+        );
+
+        let romanized = word.to_string();
+        let reparsed: Word = romanized.parse()?;
+        assert_eq!(reparsed, word, "snapshot line {line_number} was {source:?}");
+        assert_eq!(
+            reparsed.to_string(),
+            romanized,
+            "snapshot line {line_number} was {source:?}",
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn write_ithkuil() -> Result<(), ParseError> {
+    use crate::{
+        category::Stress,
+        romanize::{
+            token_list::TokenList,
+            transform::{add_stress, add_stress_in_place, normalize, tokens_to_string},
+        },
+    };
+
+    let mut words = Vec::new();
+
+    for line in include_str!("gloss_snapshots.tsv").lines() {
+        if let Some((source, _)) = line.split_once('\t') {
+            words.push(normalize(source.trim()).parse::<Word>()?);
+        }
+    }
+
+    let expected = words
+        .iter()
+        .map(|word| word.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut output = String::new();
+    let mut reused_output = String::new();
+    let mut list = TokenList::new();
+
+    for word in &words {
+        if !output.is_empty() {
+            output.push(' ');
+            reused_output.push(' ');
+        }
+
+        word.write_ithkuil(&mut output, IntoTokensFlags::NONE);
+
+        list.clear();
+        word.append_tokens_to(&mut list, IntoTokensFlags::NONE);
+        list.write_to(&mut reused_output);
+    }
+
+    assert_eq!(output, expected);
+    assert_eq!(reused_output, expected);
+
+    // Stressing a word in place matches stressing a copy of it, even after other text.
+    for word in &words {
+        let unstressed = tokens_to_string(&word.into_tokens(IntoTokensFlags::NONE).tokens);
+
+        for stress in [
+            Stress::Monosyllabic,
+            Stress::Ultimate,
+            Stress::Penultimate,
+            Stress::Antepenultimate,
+        ] {
+            let mut in_place = "wâ ".to_owned() + &unstressed;
+            let is_possible = add_stress_in_place(&mut in_place, 4, stress);
+
+            match add_stress(&unstressed, stress) {
+                Some(stressed) => assert_eq!(in_place, "wâ ".to_owned() + &stressed),
+                None => assert!(!is_possible && in_place == "wâ ".to_owned() + &unstressed),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn multiple_affix_adjuncts() -> Result<(), ParseError> {
+    use crate::affix::PlainAffix;
+    use category::{AffixDegree, AffixType, AffixualAdjunctMode, AffixualAdjunctScope};
+    use vec1::Vec1;
+    use word::{AffixualAdjunct, MultipleAffixAdjunct};
+
+    fn check(source: &str, gloss: &str) -> Result<(), ParseError> {
+        let word: Word = source.parse()?;
+        assert!(
+            matches!(word, Word::Affixual(AffixualAdjunct::Multiple(_))),
+            "source {source:?} was not a multiple affix adjunct"
+        );
+        assert_eq!(word.gloss(GlossFlags::NONE), gloss, "source was {source:?}");
+        Ok(())
+    }
+
+    check("lahasokţ", "l/1₁-s/1₁-kţ/7₁")?;
+    check("lahasokţipļ", "l/1₁-s/1₁-kţ/7₁-pļ/4₁")?;
+    check("lahasokţipļumv", "l/1₁-s/1₁-kţ/7₁-pļ/4₁-mv/9₁")?;
+    check("la'hasokţipļu", "l/1₁-{v.sub}-s/1₁-kţ/7₁-pļ/4₁-{v.sub}")?;
+    check("lahasokţipļumvá", "l/1₁-s/1₁-kţ/7₁-pļ/4₁-mv/9₁-{concat.}")?;
+    check(
+        "ëla'hlasokţipļumvai",
+        "l/1₁-{vii.dom}-s/1₁-kţ/7₁-pļ/4₁-mv/9₁",
+    )?;
+
+    let affix = |cs: &str, degree| RegularAffix::Plain(PlainAffix::new(cs, AffixType::T1, degree));
+
+    let pool = [
+        affix("c", AffixDegree::D1),
+        affix("st", AffixDegree::D2),
+        affix("kţ", AffixDegree::D7),
+        affix("pļ", AffixDegree::D4),
+        affix("mv", AffixDegree::D9),
+        affix("lk", AffixDegree::D0),
+    ];
+
+    for count in 2..=5 {
+        for start in 0..pool.len() {
+            let affixes: Vec<_> = (0..count)
+                .map(|index| pool[(start + index) % pool.len()].clone())
+                .collect();
+
+            for first_scope in AffixualAdjunctScope::ALL_ITEMS {
+                for other_scope in [None]
+                    .into_iter()
+                    .chain(AffixualAdjunctScope::ALL_ITEMS.map(Some))
+                {
+                    for mode in AffixualAdjunctMode::ALL_ITEMS {
+                        let adjunct = MultipleAffixAdjunct {
+                            first_affix: affixes[0].clone(),
+                            first_scope,
+                            other_affixes: Vec1::try_from_vec(affixes[1..].to_vec()).unwrap(),
+                            other_scope,
+                            mode,
+                        };
+
+                        for flags in [
+                            IntoTokensFlags::NONE,
+                            IntoTokensFlags::WORD_INITIAL_VOWEL,
+                            IntoTokensFlags::WORD_FINAL_VOWEL,
+                        ] {
+                            let source = adjunct.to_string_with(flags);
+                            let word: Word = source.parse()?;
+                            assert_eq!(
+                                word,
+                                Word::Affixual(AffixualAdjunct::Multiple(adjunct.clone())),
+                                "source was {source:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parsing_adjuncts() -> Result<(), ParseError> {
+    use category::Stress;
+    use word::ParsingAdjunct;
+
+    for (stress, source, short, long) in [
+        (
+            Stress::Monosyllabic,
+            "a'",
+            "{mono.}",
+            "{monosyllabic stress}",
+        ),
+        (Stress::Ultimate, "e'", "{ult.}", "{ultimate stress}"),
+        (Stress::Penultimate, "o'", "{penu.}", "{penultimate stress}"),
+        (
+            Stress::Antepenultimate,
+            "u'",
+            "{ante.}",
+            "{antepenultimate stress}",
+        ),
+    ] {
+        let adjunct = ParsingAdjunct { stress };
+
+        for flags in [
+            IntoTokensFlags::NONE,
+            IntoTokensFlags::WORD_INITIAL_VOWEL,
+            IntoTokensFlags::WORD_FINAL_VOWEL,
+        ] {
+            assert_eq!(adjunct.to_string_with(flags), source);
+        }
+
+        let word: Word = source.parse()?;
+        assert_eq!(word, Word::Parsing(adjunct), "source was {source:?}");
+        assert_eq!(word.gloss(GlossFlags::NONE), short);
+        assert_eq!(word.gloss(GlossFlags::LONG), long);
+    }
+
+    for source in ["ä'", "i'", "ëi'", "ö'", "ü'", "ai'", "ia'"] {
+        assert_eq!(
+            ParsingAdjunct::from_str(source),
+            Err(ParseError::ExpectedVp),
+            "source was {source:?}"
+        );
+    }
+
+    assert_eq!(
+        ParsingAdjunct::from_str("a"),
+        Err(ParseError::ExpectedGlottalStop)
+    );
+    assert!(ParsingAdjunct::from_str("a'a").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn mcs_adjunct_to_modern() -> Result<(), ParseError> {
+    use word::MCSAdjunct;
+
+    for (source, modern) in [
+        ("hra", "aha"),
+        ("hre", "ahla"),
+        ("hri", "ahra"),
+        ("hro", "ahma"),
+        ("hrö", "ahna"),
+        ("hru", "ahňa"),
+        ("hrai", "aha"),
+        ("hrei", "ahla"),
+        ("hriu", "ahra"),
+        ("hroi", "ahma"),
+        ("hrü", "ahna"),
+        ("hrui", "ahňa"),
+    ] {
+        let adjunct = MCSAdjunct::from_str(source)?;
+        let expected = adjunct.to_modern();
+        assert_eq!(expected.to_string(), modern, "source was {source:?}");
+
+        let word: Word = modern.parse()?;
+        assert_eq!(word, Word::Modular(expected), "source was {source:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_all() -> Result<(), ParseError> {
+    use crate::romanize::token_list::TokenList;
+
+    fn parse_all(source: &str) -> Result<Vec<WordKind>, ParseError> {
+        let list: TokenList = source.parse()?;
+        let mut stream = list.stream();
+        let words = Word::parse_all(&mut stream, FromTokenFlags::NONE);
+        if words.is_empty() {
+            assert_eq!(
+                stream.remaining(),
+                list.tokens.len(),
+                "source was {source:?}"
+            );
+        } else {
+            assert!(stream.is_done(), "source was {source:?}");
+            assert_eq!(words[0], Word::from_str(source)?, "source was {source:?}");
+        }
+        Ok(words.iter().map(Word::kind).collect())
+    }
+
+    assert_eq!(
+        parse_all("ca'hläst")?,
+        [WordKind::Formative, WordKind::Affixual]
+    );
+    assert_eq!(parse_all("mala")?, [WordKind::Formative]);
+    assert_eq!(parse_all("o'")?, [WordKind::Parsing]);
+    assert_eq!(parse_all("ëca'hläst")?, [WordKind::Affixual]);
+    assert_eq!(parse_all("mmmm")?, []);
+
+    Ok(())
+}
+
+#[test]
+fn display() -> Result<(), ParseError> {
+    // Words are displayed in their canonical romanization, with stress marked where required.
+    for (source, expected) in [
+        ("malá", "mal"),
+        ("malâ", "malâ"),
+        ("málala", "málala"),
+        ("alasa", "lasa"),
+        ("ırburučpaızya", "irburučpaizya"),
+        ("lo", "lo"),
+    ] {
+        let word: Word = source.parse()?;
+        assert_eq!(format!("{word}"), expected, "{source}");
+        assert_eq!(word.to_string(), word.to_string_with(IntoTokensFlags::NONE));
+    }
+
+    let sentence = ["malâ", "lo", "lasa"]
+        .into_iter()
+        .map(str::parse)
+        .collect::<Result<Vec<Word>, _>>()?;
+    assert_eq!(
+        format!("{} {} {}", sentence[0], sentence[1], sentence[2]),
+        "malâ lo lasa"
+    );
+
+    let formative: word::UncheckedFormative = "hlamalá".parse()?;
+    assert_eq!(format!("<{formative}>"), "<hlamál>");
+
+    Ok(())
+}
+
+#[test]
+fn fuzz_regressions() -> Result<(), ParseError> {
+    use crate::romanize::token::ConsonantForm;
+
+    // Non-geminated penta-consonantal conjuncts used to recurse forever when checked for word-final
+    // validity.
+    assert!(!ConsonantForm::new("mktsļ").is_valid_word_final());
+    assert!(ConsonantForm::new("rrkst").is_valid_word_final());
+
+    for source in ["mktsļös", "mktsļa"] {
+        let word: Word = source.parse()?;
+        let romanized = word.to_string();
+        assert_eq!(romanized.parse::<Word>()?, word, "source was {source:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn carrier_adjuncts() -> Result<(), ParseError> {
+    use crate::word::{CarriedContent, CarriedWord, CarrierAdjunct};
+
+    let name: CarrierAdjunct = "hla Smith".parse()?;
+    assert_eq!(name.content, CarriedContent::Raw("Smith".to_owned()));
+    assert_eq!(name.to_string(), "hla Smith");
+    assert_eq!(name.gloss(GlossFlags::NONE), "[CAR] “Smith”");
+
+    let source = "hma mal hlu Bob hü";
+    let quote: CarrierAdjunct = source.parse()?;
+    let CarriedContent::Phrase(phrase) = &quote.content else {
+        panic!("expected a phrase");
+    };
+    assert!(matches!(phrase[0], CarriedWord::Word(Word::Formative(_))));
+    assert!(matches!(phrase[1], CarriedWord::Carrier(_)));
+    assert_eq!(quote.to_string(), source);
+    assert_eq!(
+        quote.gloss(GlossFlags::NONE),
+        "[QUO] “S1-m-OBS [CAR]-IND “Bob””"
+    );
+
+    let source = "hma mal hma kali hü lal hü";
+    assert_eq!(source.parse::<CarrierAdjunct>()?.to_string(), source);
+
+    assert_eq!(
+        "hna".parse::<CarrierAdjunct>(),
+        Err(ParseError::ExpectedCarriedContent)
+    );
+    assert_eq!(
+        "hma mal".parse::<CarrierAdjunct>(),
+        Err(ParseError::UnterminatedCarriedPhrase)
+    );
+    assert_eq!(
+        "hla Smith hla".parse::<CarrierAdjunct>(),
+        Err(ParseError::TooManyWords)
+    );
+
+    Ok(())
+}
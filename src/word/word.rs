@@ -5,6 +5,7 @@ use crate::{
         token::Token,
     },
 };
+use std::{error::Error, fmt};
 
 /// A general word.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -57,24 +58,44 @@ impl Gloss for Word {
     }
 }
 
-impl FromTokens for Word {
-    fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
-        macro_rules! check_all {
-            ($last:ident, $($variant:ident),+) => {{
-                $(if let Ok(value) = stream.parse_entire(flags) {
-                    return Ok(Self::$variant(value));
-                })+
-
-                let value = stream.parse(flags)?;
-
-                if stream.is_done() {
-                    Ok(Self::$last(value))
-                } else {
-                    Err(ParseError::TooManyTokens)
-                }
-            }};
-        }
+/// The kind of a [`Word`], without any of its data.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum WordKind {
+    /// A formative.
+    Formative,
 
+    /// A referential.
+    Referential,
+
+    /// A affixual adjunct.
+    Affixual,
+
+    /// A modular adjunct.
+    Modular,
+
+    /// A mcs adjunct.
+    MCS,
+
+    /// A parsing adjunct.
+    Parsing,
+
+    /// A register adjunct.
+    Register,
+
+    /// A suppletive adjunct.
+    Suppletive,
+
+    /// A bias adjunct.
+    Bias,
+
+    /// A numeric adjunct.
+    Numeric,
+}
+
+impl WordKind {
+    /// Gets the kinds of words which may start with `token`, in the order they are tried when
+    /// parsing a [`Word`].
+    pub const fn candidates(token: &Token) -> &'static [WordKind] {
         // Formative structure is ((H)V)C... or ((H)V)N...  ... they can start with VCHN
         // Referential structure is (ë)C... or VH...        ... they can start with VC  ë
         // Affixual structure is V... or (ë)C... or (ë)N... ... they can start with VC Në
@@ -86,16 +107,159 @@ impl FromTokens for Word {
         // Bias structure is C                              ... they can start with  C
         // Numeric structure is N                           ... they can start with    N
 
-        match stream.peek() {
-            Some(Token::V(_)) => check_all!(Formative, Parsing, Modular, Affixual, Referential),
-            Some(Token::C(_)) => check_all!(Formative, Bias, Affixual, Referential),
-            Some(Token::H(_)) => check_all!(Formative, Suppletive, Register, MCS, Modular),
-            Some(Token::N(_)) => check_all!(Formative, Numeric, Affixual),
-            Some(Token::Schwa) => check_all!(Referential, Affixual),
-            Some(Token::ÜA) => Err(ParseError::WordInitialÜA),
-            Some(Token::GlottalStop) => Err(ParseError::WordInitialGlottalStop),
-            None => Err(ParseError::WordEmpty),
+        use WordKind::*;
+
+        match token {
+            Token::V(_) => &[Formative, Parsing, Modular, Affixual, Referential],
+            Token::C(_) => &[Formative, Bias, Affixual, Referential],
+            Token::H(_) => &[Formative, Suppletive, Register, MCS, Modular],
+            Token::N(_) => &[Formative, Numeric, Affixual],
+            Token::Schwa => &[Referential, Affixual],
+            Token::ÜA | Token::GlottalStop => &[],
+        }
+    }
+}
+
+/// The error returned when a [`Word`] cannot be parsed as any kind of word.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordParseError {
+    /// The most relevant error, which is the error from the last kind of word attempted.
+    pub error: ParseError,
+
+    /// Each kind of word that was attempted, alongside the error it failed with.
+    pub attempts: Vec<(WordKind, ParseError)>,
+}
+
+impl fmt::Display for WordParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+
+        if let Some(((kind, error), rest)) = self.attempts.split_first() {
+            write!(f, " (tried {kind:?}: {error}")?;
+            for (kind, error) in rest {
+                write!(f, "; {kind:?}: {error}")?;
+            }
+            f.write_str(")")?;
         }
+
+        Ok(())
+    }
+}
+
+impl Error for WordParseError {}
+
+impl From<WordParseError> for ParseError {
+    fn from(value: WordParseError) -> Self {
+        value.error
+    }
+}
+
+impl Word {
+    /// Gets the kind of this word.
+    pub const fn kind(&self) -> WordKind {
+        match self {
+            Self::Formative(_) => WordKind::Formative,
+            Self::Referential(_) => WordKind::Referential,
+            Self::Affixual(_) => WordKind::Affixual,
+            Self::Modular(_) => WordKind::Modular,
+            Self::MCS(_) => WordKind::MCS,
+            Self::Parsing(_) => WordKind::Parsing,
+            Self::Register(_) => WordKind::Register,
+            Self::Suppletive(_) => WordKind::Suppletive,
+            Self::Bias(_) => WordKind::Bias,
+            Self::Numeric(_) => WordKind::Numeric,
+        }
+    }
+
+    /// Parses the entirety of `stream` as a specific kind of word.
+    pub fn parse_as(
+        stream: &mut TokenStream,
+        kind: WordKind,
+        flags: FromTokenFlags,
+    ) -> Result<Self, ParseError> {
+        Ok(match kind {
+            WordKind::Formative => Self::Formative(stream.parse_entire(flags)?),
+            WordKind::Referential => Self::Referential(stream.parse_entire(flags)?),
+            WordKind::Affixual => Self::Affixual(stream.parse_entire(flags)?),
+            WordKind::Modular => Self::Modular(stream.parse_entire(flags)?),
+            WordKind::MCS => Self::MCS(stream.parse_entire(flags)?),
+            WordKind::Parsing => Self::Parsing(stream.parse_entire(flags)?),
+            WordKind::Register => Self::Register(stream.parse_entire(flags)?),
+            WordKind::Suppletive => Self::Suppletive(stream.parse_entire(flags)?),
+            WordKind::Bias => Self::Bias(stream.parse_entire(flags)?),
+            WordKind::Numeric => Self::Numeric(stream.parse_entire(flags)?),
+        })
+    }
+
+    /// Parses the entirety of `stream` as every kind of word which may start with its first token,
+    /// returning each successful interpretation in the order [`Word::parse_any`] tries them, so
+    /// the first interpretation is the one [`Word::parse_any`] returns.
+    ///
+    /// Most words have at most one interpretation. Some romanizations are genuinely ambiguous,
+    /// though; for instance, `ca'hläst` is both a Cn-shortcut formative and an affixual adjunct.
+    ///
+    /// If any interpretation succeeds, `stream` is left at its end. Otherwise, an empty [`Vec`] is
+    /// returned and `stream` is left unchanged.
+    pub fn parse_all(stream: &mut TokenStream, flags: FromTokenFlags) -> Vec<Self> {
+        let candidates = match stream.peek() {
+            Some(token) => WordKind::candidates(token),
+            None => &[],
+        };
+
+        let start = stream.checkpoint();
+        let mut end = None;
+        let mut words = Vec::new();
+
+        for &kind in candidates {
+            if let Ok(word) = Self::parse_as(stream, kind, flags) {
+                words.push(word);
+                end = Some(stream.checkpoint());
+                stream.restore(start);
+            }
+        }
+
+        if let Some(end) = end {
+            stream.restore(end);
+        }
+
+        words
+    }
+
+    /// Parses the entirety of `stream` as any kind of word, trying each kind which may start with
+    /// the first token of `stream` in turn. If no kind matches, the error of every attempted kind
+    /// is returned.
+    pub fn parse_any(
+        stream: &mut TokenStream,
+        flags: FromTokenFlags,
+    ) -> Result<Self, WordParseError> {
+        let (candidates, initial_error) = match stream.peek() {
+            Some(Token::ÜA) => (&[][..], ParseError::WordInitialÜA),
+            Some(Token::GlottalStop) => (&[][..], ParseError::WordInitialGlottalStop),
+            Some(token) => (WordKind::candidates(token), ParseError::WordEmpty),
+            None => (&[][..], ParseError::WordEmpty),
+        };
+
+        let mut attempts = Vec::with_capacity(candidates.len());
+
+        for &kind in candidates {
+            match Self::parse_as(stream, kind, flags) {
+                Ok(value) => return Ok(value),
+                Err(error) => attempts.push((kind, error)),
+            }
+        }
+
+        let error = match attempts.last() {
+            Some(&(_, error)) => error,
+            None => initial_error,
+        };
+
+        Err(WordParseError { error, attempts })
+    }
+}
+
+impl FromTokens for Word {
+    fn parse_volatile(stream: &mut TokenStream, flags: FromTokenFlags) -> Result<Self, ParseError> {
+        Ok(Self::parse_any(stream, flags)?)
     }
 }
 